@@ -249,7 +249,7 @@ macro_rules! impl_gpu_sw_projective {
                                 };
                                 table[i] = res;
 
-                                P::glv_endomorphism_in_place(&mut res.x);
+                                P::glv_endomorphism_on_point(&mut res.x, &mut res.y, Some(&mut res.z));
                                 table[Self::table_size() / 2 + i] =
                                     if k2_neg != k1_neg { res.neg() } else { res };
                             }
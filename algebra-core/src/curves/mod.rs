@@ -1,6 +1,7 @@
 use crate::{
+    biginteger::BigInteger,
     bytes::{FromBytes, ToBytes},
-    fields::{Field, PrimeField, SquareRootField},
+    fields::{Field, FpParameters, PrimeField, SquareRootField},
     groups::Group,
     CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize, UniformRand, Vec,
 };
@@ -211,6 +212,69 @@ pub trait ProjectiveCurve:
         self
     }
 
+    /// Returns `b` if `choice` is set, and `a` otherwise. Implementations
+    /// should avoid branching on `choice`; the default implementation does
+    /// not.
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        if choice {
+            *b
+        } else {
+            *a
+        }
+    }
+
+    /// Swaps `a` and `b` if `choice` is set, via
+    /// [`Self::conditional_select`].
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        let new_a = Self::conditional_select(a, b, choice);
+        *b = Self::conditional_select(b, a, choice);
+        *a = new_a;
+    }
+
+    /// Performs scalar multiplication of this element with a sequence of
+    /// group operations that does not depend on `scalar`.
+    ///
+    /// Unlike [`Self::mul`], this is a Montgomery ladder over a fixed number
+    /// of bits: each step performs one addition and one doubling, and the
+    /// ladder registers are swapped with [`Self::conditional_swap`]. This is
+    /// not constant time, as the group and field operations themselves may
+    /// branch on their inputs. It is slower than `mul`, and assumes that
+    /// `self` lies in the prime order subgroup.
+    #[must_use]
+    fn mul_uniform(&self, scalar: &Self::ScalarField) -> Self {
+        let num_bits = <Self::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        let modulus = <Self::ScalarField as PrimeField>::Params::MODULUS;
+
+        // Replace `k` by `k + r` or `k + 2r`, whichever has bit `num_bits`
+        // set. This fixes the length of the ladder, and keeps its registers
+        // away from the identity.
+        let mut k = scalar.into_repr();
+        let carry = k.add_nocarry(&modulus);
+        let mut k_plus_2r = k;
+        k_plus_2r.add_nocarry(&modulus);
+        let top_bit_set = if num_bits == 64 * k.as_ref().len() {
+            carry
+        } else {
+            k.get_bit(num_bits)
+        };
+        let mask = 0u64.wrapping_sub(!top_bit_set as u64);
+        for (k, k_plus_2r) in k.as_mut().iter_mut().zip(k_plus_2r.as_ref()) {
+            *k ^= mask & (*k ^ k_plus_2r);
+        }
+
+        // Invariant: r1 - r0 = self.
+        let mut r0 = *self;
+        let mut r1 = self.double();
+        for i in (0..num_bits).rev() {
+            let bit = k.get_bit(i);
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+            r1 += &r0;
+            r0.double_in_place();
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+
     fn get_x(&mut self) -> &mut Self::BaseField;
 }
 
@@ -266,6 +330,14 @@ pub trait AffineCurve:
     fn mul<S: Into<<Self::ScalarField as PrimeField>::BigInt>>(&self, other: S)
         -> Self::Projective;
 
+    /// Performs scalar multiplication of this element with a sequence of
+    /// group operations that does not depend on `scalar`; see
+    /// [`ProjectiveCurve::mul_uniform`].
+    #[must_use]
+    fn mul_uniform(&self, scalar: &Self::ScalarField) -> Self::Projective {
+        self.into_projective().mul_uniform(scalar)
+    }
+
     /// Multiply this element by the cofactor and output the
     /// resulting projective element.
     #[must_use]
@@ -281,6 +353,10 @@ pub trait AffineCurve:
     /// `Self::ScalarField`.
     #[must_use]
     fn mul_by_cofactor_inv(&self) -> Self;
+
+    /// Checks that this element, which must be on the curve, lies in the
+    /// prime order subgroup.
+    fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool;
 }
 
 impl<C: ProjectiveCurve> Group for C {
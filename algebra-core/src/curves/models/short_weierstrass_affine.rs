@@ -32,9 +32,10 @@ macro_rules! specialise_affine_to_proj {
                 P::has_glv()
             }
 
+            /// Applies the GLV (or GLS) endomorphism to `self`.
             #[inline(always)]
-            pub fn glv_endomorphism_in_place(elem: &mut <Self as AffineCurve>::BaseField) {
-                P::glv_endomorphism_in_place(elem);
+            pub fn glv_endomorphism_in_place(&mut self) {
+                P::glv_endomorphism_on_point(&mut self.x, &mut self.y, None);
             }
 
             #[inline]
@@ -107,6 +108,10 @@ macro_rules! specialise_affine_to_proj {
             fn mul_by_cofactor_inv(&self) -> Self {
                 self.mul(P::COFACTOR_INV).into()
             }
+
+            fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+                GroupAffine::is_in_correct_subgroup_assuming_on_curve(self)
+            }
         }
 
         impl<P: SWModelParameters> GroupAffine<P> {
@@ -303,7 +308,7 @@ macro_rules! impl_glv_mul {
         let mut wnaf_table_k2 = wnaf_table_k1.clone();
         wnaf_table_k2
             .iter_mut()
-            .for_each(|p| $P::glv_endomorphism_in_place(&mut p.x));
+            .for_each(|p| $P::glv_endomorphism_on_point(&mut p.x, &mut p.y, Some(&mut p.z)));
 
         let k1_ops = wnaf_recoding(&mut k1, k1_neg);
         let k2_ops = wnaf_recoding(&mut k2, k2_neg);
@@ -82,6 +82,18 @@ pub trait SWModelParameters: ModelParameters + Sized {
         unimplemented!()
     }
 
+    /// Applies the GLV endomorphism to a point with the given coordinates,
+    /// where `z` is `None` for affine points. By default, only `x` is changed,
+    /// as for endomorphisms of the form `(x, y) -> (omega * x, y)`.
+    #[inline(always)]
+    fn glv_endomorphism_on_point(
+        x: &mut Self::BaseField,
+        _y: &mut Self::BaseField,
+        _z: Option<&mut Self::BaseField>,
+    ) {
+        Self::glv_endomorphism_in_place(x);
+    }
+
     #[inline(always)]
     fn glv_scalar_decomposition(
         _k: <Self::ScalarField as PrimeField>::BigInt,
@@ -130,9 +142,10 @@ impl<P: SWModelParameters> GroupProjective<P> {
         P::has_glv()
     }
 
+    /// Applies the GLV (or GLS) endomorphism to `self`.
     #[inline(always)]
-    pub fn glv_endomorphism_in_place(elem: &mut <Self as ProjectiveCurve>::BaseField) {
-        P::glv_endomorphism_in_place(elem);
+    pub fn glv_endomorphism_in_place(&mut self) {
+        P::glv_endomorphism_on_point(&mut self.x, &mut self.y, Some(&mut self.z));
     }
 
     #[inline]
@@ -472,6 +485,15 @@ impl<P: SWModelParameters> ProjectiveCurve for GroupProjective<P> {
         }
     }
 
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+
     fn get_x(&mut self) -> &mut Self::BaseField {
         &mut self.x
     }
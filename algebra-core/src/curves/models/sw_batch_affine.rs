@@ -313,7 +313,7 @@ macro_rules! impl_sw_batch_affine {
 
                     if P::has_glv() {
                         if endomorphism >> 1 == 1 {
-                            P::glv_endomorphism_in_place(&mut b.x);
+                            P::glv_endomorphism_on_point(&mut b.x, &mut b.y, None);
                         }
                     }
                     batch_add_loop_1!(a, b, half, inversion_tmp);
@@ -482,7 +482,7 @@ macro_rules! impl_sw_batch_affine {
                         .iter()
                         .map(|&p| {
                             let mut p = p;
-                            P::glv_endomorphism_in_place(&mut p.x);
+                            P::glv_endomorphism_on_point(&mut p.x, &mut p.y, None);
                             p
                         })
                         .collect();
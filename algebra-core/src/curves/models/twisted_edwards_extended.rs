@@ -207,6 +207,10 @@ impl<P: TEModelParameters> AffineCurve for GroupAffine<P> {
     fn mul_by_cofactor_inv(&self) -> Self {
         self.mul(P::COFACTOR_INV).into()
     }
+
+    fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        GroupAffine::is_in_correct_subgroup_assuming_on_curve(self)
+    }
 }
 
 macro_rules! batch_add_loop_1 {
@@ -749,6 +753,16 @@ impl<P: TEModelParameters> ProjectiveCurve for GroupProjective<P> {
         self.z = f * &g;
     }
 
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+
     fn get_x(&mut self) -> &mut Self::BaseField {
         &mut self.x
     }
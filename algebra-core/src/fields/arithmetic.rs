@@ -456,6 +456,53 @@ macro_rules! sqrt_impl {
     }};
 }
 
+macro_rules! sqrt_uniform_impl {
+    ($Self:ident, $P:tt, $self:expr) => {{
+        // Tonelli-Shanks with a fixed sequence of field operations, following
+        // `sqrt_tonelli_shanks` in the `ff` crate. Unlike `sqrt_impl!`, every
+        // iteration of both loops runs to completion regardless of the input,
+        // and the data-dependent updates are applied with
+        // `conditional_select`. The field operations are not constant time.
+        let one = $Self::one();
+        let two_adicity = $P::TWO_ADICITY;
+
+        let w = $self.pow($P::T_MINUS_ONE_DIV_TWO);
+        let mut v = two_adicity;
+        let mut x = w * $self;
+        let mut b = x * &w;
+        let mut z = $Self::qnr_to_t();
+
+        for max_v in (1..=two_adicity).rev() {
+            let mut k = 1u32;
+            let mut tmp = b.square();
+            let mut j_less_than_v = true;
+
+            for j in 2..max_v {
+                let tmp_is_one = tmp.ct_eq(&one);
+                let squared = $Self::conditional_select(&tmp, &z, tmp_is_one).square();
+                tmp = $Self::conditional_select(&squared, &tmp, tmp_is_one);
+                let new_z = $Self::conditional_select(&z, &squared, tmp_is_one);
+                j_less_than_v &= j != v;
+                let mask = 0u32.wrapping_sub(tmp_is_one as u32);
+                k = (k & mask) | (j & !mask);
+                z = $Self::conditional_select(&z, &new_z, j_less_than_v);
+            }
+
+            let result = x * &z;
+            x = $Self::conditional_select(&result, &x, b.ct_eq(&one));
+            z.square_in_place();
+            b *= &z;
+            v = k;
+        }
+
+        if x.square().ct_eq($self) {
+            Some(x)
+        } else {
+            None
+        }
+    }};
+}
+
 // Implements AddAssign on Self by deferring to an implementation on &Self
 #[macro_export]
 macro_rules! impl_additive_ops_from_ref {
@@ -191,6 +191,39 @@ macro_rules! impl_Fp {
                 }
             }
 
+            #[inline]
+            fn inverse_uniform(&self) -> Option<Self> {
+                if self.is_zero() {
+                    None
+                } else {
+                    // Fermat's little theorem: self^(p - 2) = self^(-1). The
+                    // exponent is public, so the sequence of squarings and
+                    // multiplications is fixed.
+                    let mut exp = P::MODULUS;
+                    exp.sub_noborrow(&$BigInteger::from(2));
+                    Some(self.pow(exp))
+                }
+            }
+
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+                let mask = 0u64.wrapping_sub(choice as u64);
+                let mut result = *a;
+                for (r, b) in (result.0).0.iter_mut().zip(&(b.0).0) {
+                    *r ^= mask & (*r ^ b);
+                }
+                result
+            }
+
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> bool {
+                let mut acc = 0u64;
+                for (a, b) in (self.0).0.iter().zip(&(other.0).0) {
+                    acc |= a ^ b;
+                }
+                acc == 0
+            }
+
             #[inline]
             fn frobenius_map(&mut self, _: usize) {
                 // No-op: No effect in a prime field.
@@ -263,6 +296,11 @@ macro_rules! impl_Fp {
                     self
                 })
             }
+
+            #[inline]
+            fn sqrt_uniform(&self) -> Option<Self> {
+                sqrt_uniform_impl!(Self, P, self)
+            }
         }
 
         impl<P: $FpParameters> Ord for $Fp<P> {
@@ -144,6 +144,44 @@ pub trait Field:
     // Sets `self` to `self`'s inverse if it exists. Otherwise it is a no-op.
     fn inverse_in_place(&mut self) -> Option<&mut Self>;
 
+    /// Computes the multiplicative inverse of `self` if `self` is nonzero,
+    /// using a sequence of field operations that does not depend on the value
+    /// of `self`, apart from whether it is zero.
+    ///
+    /// This is not constant time: the field operations themselves may branch
+    /// on their inputs. It is slower than [`Self::inverse`]. The default
+    /// implementation just calls [`Self::inverse`].
+    #[must_use]
+    fn inverse_uniform(&self) -> Option<Self> {
+        self.inverse()
+    }
+
+    /// Returns `b` if `choice` is set, and `a` otherwise. Implementations
+    /// should avoid branching on `choice`; the default implementation does
+    /// not.
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        if choice {
+            *b
+        } else {
+            *a
+        }
+    }
+
+    /// Swaps `a` and `b` if `choice` is set, via
+    /// [`Self::conditional_select`].
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        let new_a = Self::conditional_select(a, b, choice);
+        *b = Self::conditional_select(b, a, choice);
+        *a = new_a;
+    }
+
+    /// Checks whether `self == other`. Implementations should not
+    /// short-circuit on the first limb that differs; the default
+    /// implementation, which uses `==`, may.
+    fn ct_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+
     /// Exponentiates this element by a power of the base prime modulus via
     /// the Frobenius automorphism.
     fn frobenius_map(&mut self, power: usize);
@@ -364,6 +402,18 @@ pub trait SquareRootField: Field {
 
     /// Sets `self` to be the square root of `self`, if it exists.
     fn sqrt_in_place(&mut self) -> Option<&mut Self>;
+
+    /// Returns the square root of self, if it exists, using a sequence of
+    /// field operations that does not depend on the value of `self`, apart
+    /// from whether it is a square.
+    ///
+    /// This is not constant time: the field operations themselves may branch
+    /// on their inputs. It is slower than [`Self::sqrt`]. The default
+    /// implementation just calls [`Self::sqrt`].
+    #[must_use]
+    fn sqrt_uniform(&self) -> Option<Self> {
+        self.sqrt()
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -104,6 +104,56 @@ impl<P: CubicExtParameters> CubicExtField<P> {
         assert!(self_to_p.c1.is_zero() && self_to_p.c2.is_zero());
         self_to_p.c0
     }
+
+    /// Computes the inverse of `self`, using `invert` to invert the single
+    /// base field element that the algorithm reduces to.
+    fn inverse_with(&self, invert: impl Fn(&P::BaseField) -> Option<P::BaseField>) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // From "High-Speed Software Implementation of the Optimal Ate AbstractPairing
+            // over
+            // Barreto-Naehrig Curves"; Algorithm 17
+            let t0 = self.c0.square();
+            let t1 = self.c1.square();
+            let t2 = self.c2.square();
+            let mut t3 = self.c0.clone();
+            t3.mul_assign(&self.c1);
+            let mut t4 = self.c0.clone();
+            t4.mul_assign(&self.c2);
+            let mut t5 = self.c1.clone();
+            t5.mul_assign(&self.c2);
+            let n5 = P::mul_base_field_by_nonresidue(&t5);
+
+            let mut s0 = t0.clone();
+            s0.sub_assign(&n5);
+            let mut s1 = P::mul_base_field_by_nonresidue(&t2);
+            s1.sub_assign(&t3);
+            let mut s2 = t1.clone();
+            s2.sub_assign(&t4); // typo in paper referenced above. should be "-" as per Scott, but is "*"
+
+            let mut a1 = self.c2.clone();
+            a1.mul_assign(&s1);
+            let mut a2 = self.c1.clone();
+            a2.mul_assign(&s2);
+            let mut a3 = a1.clone();
+            a3.add_assign(&a2);
+            a3 = P::mul_base_field_by_nonresidue(&a3);
+            let mut t6 = self.c0.clone();
+            t6.mul_assign(&s0);
+            t6.add_assign(&a3);
+            let t6 = invert(&t6)?;
+
+            let mut c0 = t6.clone();
+            c0.mul_assign(&s0);
+            let mut c1 = t6.clone();
+            c1.mul_assign(&s1);
+            let mut c2 = t6.clone();
+            c2.mul_assign(&s2);
+
+            Some(Self::new(c0, c1, c2))
+        }
+    }
 }
 
 impl<P: CubicExtParameters> Zero for CubicExtField<P> {
@@ -205,51 +255,11 @@ impl<P: CubicExtParameters> Field for CubicExtField<P> {
     }
 
     fn inverse(&self) -> Option<Self> {
-        if self.is_zero() {
-            None
-        } else {
-            // From "High-Speed Software Implementation of the Optimal Ate AbstractPairing
-            // over
-            // Barreto-Naehrig Curves"; Algorithm 17
-            let t0 = self.c0.square();
-            let t1 = self.c1.square();
-            let t2 = self.c2.square();
-            let mut t3 = self.c0.clone();
-            t3.mul_assign(&self.c1);
-            let mut t4 = self.c0.clone();
-            t4.mul_assign(&self.c2);
-            let mut t5 = self.c1.clone();
-            t5.mul_assign(&self.c2);
-            let n5 = P::mul_base_field_by_nonresidue(&t5);
-
-            let mut s0 = t0.clone();
-            s0.sub_assign(&n5);
-            let mut s1 = P::mul_base_field_by_nonresidue(&t2);
-            s1.sub_assign(&t3);
-            let mut s2 = t1.clone();
-            s2.sub_assign(&t4); // typo in paper referenced above. should be "-" as per Scott, but is "*"
-
-            let mut a1 = self.c2.clone();
-            a1.mul_assign(&s1);
-            let mut a2 = self.c1.clone();
-            a2.mul_assign(&s2);
-            let mut a3 = a1.clone();
-            a3.add_assign(&a2);
-            a3 = P::mul_base_field_by_nonresidue(&a3);
-            let mut t6 = self.c0.clone();
-            t6.mul_assign(&s0);
-            t6.add_assign(&a3);
-            t6.inverse_in_place();
-
-            let mut c0 = t6.clone();
-            c0.mul_assign(&s0);
-            let mut c1 = t6.clone();
-            c1.mul_assign(&s1);
-            let mut c2 = t6.clone();
-            c2.mul_assign(&s2);
+        self.inverse_with(P::BaseField::inverse)
+    }
 
-            Some(Self::new(c0, c1, c2))
-        }
+    fn inverse_uniform(&self) -> Option<Self> {
+        self.inverse_with(P::BaseField::inverse_uniform)
     }
 
     fn inverse_in_place(&mut self) -> Option<&mut Self> {
@@ -261,6 +271,18 @@ impl<P: CubicExtParameters> Field for CubicExtField<P> {
         }
     }
 
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+            P::BaseField::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+
+    fn ct_eq(&self, other: &Self) -> bool {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+
     fn frobenius_map(&mut self, power: usize) {
         self.c0.frobenius_map(power);
         self.c1.frobenius_map(power);
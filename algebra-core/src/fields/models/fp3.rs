@@ -89,4 +89,8 @@ impl<P: Fp3Parameters> SquareRootField for Fp3<P> {
             self
         })
     }
+
+    fn sqrt_uniform(&self) -> Option<Self> {
+        sqrt_uniform_impl!(Self, P, self)
+    }
 }
@@ -251,6 +251,33 @@ impl<P: QuadExtParameters> Field for QuadExtField<P> {
         }
     }
 
+    fn inverse_uniform(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Same as `inverse`, but inverting the norm with `inverse_uniform`.
+            let mut v0 = self.c0.square();
+            let v1 = self.c1.square();
+            v0 -= &P::mul_base_field_by_nonresidue(&v1);
+            v0.inverse_uniform().map(|v1| {
+                let c0 = self.c0 * &v1;
+                let c1 = -(self.c1 * &v1);
+                Self::new(c0, c1)
+            })
+        }
+    }
+
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+
+    fn ct_eq(&self, other: &Self) -> bool {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+
     fn frobenius_map(&mut self, power: usize) {
         self.c0.frobenius_map(power);
         self.c1.frobenius_map(power);
@@ -292,7 +319,7 @@ where
                 let c0 = delta.sqrt().expect("Delta must have a square root");
                 let c0_inv = c0.inverse().expect("c0 must have an inverse");
                 Some(Self::new(c0, self.c1 * &two_inv * &c0_inv))
-            }
+            },
         }
     }
 
@@ -302,6 +329,52 @@ where
             self
         })
     }
+
+    fn sqrt_uniform(&self) -> Option<Self> {
+        // The complex method used by `sqrt`, with every candidate computed
+        // and the right one chosen via `conditional_select`.
+        let zero = P::BaseField::zero();
+        let two_inv = P::BaseField::one()
+            .double()
+            .inverse()
+            .expect("Two should always have an inverse");
+
+        // General case: c1 != 0.
+        let alpha = self.norm().sqrt_uniform().unwrap_or(zero);
+        let delta = (alpha + &self.c0) * &two_inv;
+        let (delta_sqrt, delta_is_qr) = sqrt_uniform_or_zero(&delta);
+        let (other_sqrt, _) = sqrt_uniform_or_zero(&(delta - &alpha));
+        let c0 = P::BaseField::conditional_select(&other_sqrt, &delta_sqrt, delta_is_qr);
+        let c0_inv = c0.inverse_uniform().unwrap_or(zero);
+        let general = Self::new(c0, self.c1 * &two_inv * &c0_inv);
+
+        // Special case: c1 == 0, so the root lies in either the base field or
+        // in `u * BaseField`, where `u^2 = NONRESIDUE`.
+        let nonresidue_inv = P::NONRESIDUE
+            .inverse()
+            .expect("The nonresidue should always have an inverse");
+        let (base_sqrt, c0_is_qr) = sqrt_uniform_or_zero(&self.c0);
+        let (twisted_sqrt, _) = sqrt_uniform_or_zero(&(self.c0 * &nonresidue_inv));
+        let special = Self::conditional_select(
+            &Self::new(zero, twisted_sqrt),
+            &Self::new(base_sqrt, zero),
+            c0_is_qr,
+        );
+
+        let result = Self::conditional_select(&general, &special, self.c1.ct_eq(&zero));
+        if result.square().ct_eq(self) {
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns `f.sqrt_uniform()`, or zero if there is none,
+/// together with whether it exists.
+fn sqrt_uniform_or_zero<F: SquareRootField>(f: &F) -> (F, bool) {
+    let sqrt = f.sqrt_uniform();
+    (sqrt.unwrap_or_else(F::zero), sqrt.is_some())
 }
 
 /// `QuadExtField` elements are ordered lexicographically.
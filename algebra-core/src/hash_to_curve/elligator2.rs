@@ -0,0 +1,66 @@
+use crate::{
+    curves::models::{
+        twisted_edwards_extended::GroupAffine, MontgomeryModelParameters, TEModelParameters,
+    },
+    fields::{Field, SquareRootField},
+    hash_to_curve::{HashToField, MapToCurve},
+    One, Zero,
+};
+
+/// Parameters for hashing to a twisted Edwards curve with Elligator 2.
+///
+/// The map is evaluated on the birationally equivalent Montgomery curve
+/// `K * t^2 = s^3 + J * s^2 + s` given by `Self::MontgomeryModelParameters`,
+/// and needs `(J^2 - 4) / K^2 = a * d` to be a non-square.
+pub trait Elligator2Parameters: TEModelParameters {
+    /// The non-square `Z` of the Elligator 2 map.
+    const Z: Self::BaseField;
+}
+
+impl<P: Elligator2Parameters> MapToCurve for GroupAffine<P>
+where
+    P::BaseField: HashToField,
+{
+    fn map_to_curve(u: P::BaseField) -> Self {
+        let j = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_A;
+        let k = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_B;
+        let k_inv = k.inverse().unwrap();
+        let j_on_k = j * &k_inv;
+        let k_inv_sq = k_inv.square();
+        let g = |x: P::BaseField| ((x + &j_on_k) * &x + &k_inv_sq) * &x;
+
+        // x1 = -(J / K) / (1 + Z * u^2), or -(J / K) if that is zero or
+        // undefined.
+        let x1 = (P::BaseField::one() + &(P::Z * &u.square()))
+            .inverse()
+            .map(|inv| -j_on_k * &inv)
+            .filter(|x1| !x1.is_zero())
+            .unwrap_or(-j_on_k);
+
+        // Exactly one of g(x1) and g(-x1 - J / K) is a square.
+        let (x, y) = match g(x1).sqrt() {
+            Some(y) => (x1, if y.sgn0() { y } else { -y }),
+            None => {
+                let x2 = -x1 - &j_on_k;
+                let y = g(x2).sqrt().unwrap();
+                (x2, if y.sgn0() { -y } else { y })
+            },
+        };
+        let (s, t) = (x * &k, y * &k);
+
+        // The birational map from the Montgomery curve; its exceptional points
+        // are sent to the identity.
+        let s_plus_one = s + &P::BaseField::one();
+        match (t * &s_plus_one).inverse() {
+            Some(den_inv) => Self::new(
+                s * &s_plus_one * &den_inv,
+                (s - &P::BaseField::one()) * &t * &den_inv,
+            ),
+            None => Self::zero(),
+        }
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        self.scale_by_cofactor().into()
+    }
+}
@@ -0,0 +1,263 @@
+//! Hashing to elliptic curves, following the IETF hash-to-curve draft
+//! (<https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/>).
+//!
+//! A message is first hashed to one or two field elements with
+//! [`hash_to_field`], which is built on `expand_message_xmd`. Each field
+//! element is then sent to the curve by a deterministic map: the simplified
+//! SWU map composed with an isogeny for short Weierstrass curves
+//! ([`SWUParameters`]), and Elligator 2 for twisted Edwards curves
+//! ([`Elligator2Parameters`]). Unlike `AffineCurve::from_random_bytes`, these
+//! maps never retry. They are not constant time, though: the square roots and
+//! inversions they use take time that depends on the input, so they should
+//! not be applied to secret messages.
+use crate::{
+    biginteger::BigInteger,
+    curves::{AffineCurve, ProjectiveCurve},
+    fields::{Field, PrimeField, QuadExtField, QuadExtParameters},
+    Vec, Zero,
+};
+use core::fmt;
+use digest::{generic_array::typenum::Unsigned, BlockInput, Digest};
+
+mod elligator2;
+pub use self::elligator2::*;
+
+mod swu;
+pub use self::swu::*;
+
+/// The security level `k`, in bits, targeted by [`hash_to_field`].
+const SECURITY_BITS: usize = 128;
+
+/// This is an error that could occur while hashing to a field or a curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashToCurveError {
+    /// `expand_message_xmd` was asked for more than 255 digests or more than
+    /// 65535 bytes of output.
+    OutputTooLong,
+}
+
+impl crate::Error for HashToCurveError {}
+
+impl fmt::Display for HashToCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            HashToCurveError::OutputTooLong => {
+                write!(f, "the requested output of expand_message_xmd is too long")
+            },
+        }
+    }
+}
+
+/// Fields that messages can be hashed to.
+pub trait HashToField: Field {
+    /// Reduces `okm`, a string of `L * Self::extension_degree()` uniformly
+    /// random bytes, to a field element. Each consecutive chunk of `L` bytes
+    /// is read as a big-endian integer and reduced modulo the characteristic
+    /// to give one coefficient over the base prime field.
+    fn from_okm(okm: &[u8]) -> Self;
+
+    /// The `sgn0` function of the draft, used to choose between the two
+    /// square roots of an element.
+    fn sgn0(&self) -> bool;
+}
+
+impl<F: PrimeField> HashToField for F {
+    fn from_okm(okm: &[u8]) -> Self {
+        let to_u64 = |bytes: &[u8]| bytes.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+        let two_to_64 = F::from(1u128 << 64);
+
+        let (head, tail) = okm.split_at(okm.len() % 8);
+        let mut result = F::from(to_u64(head));
+        for chunk in tail.chunks(8) {
+            result *= &two_to_64;
+            result += &F::from(to_u64(chunk));
+        }
+        result
+    }
+
+    fn sgn0(&self) -> bool {
+        self.into_repr().is_odd()
+    }
+}
+
+impl<P: QuadExtParameters> HashToField for QuadExtField<P>
+where
+    P::BaseField: HashToField,
+{
+    fn from_okm(okm: &[u8]) -> Self {
+        let (c0, c1) = okm.split_at(okm.len() / 2);
+        Self::new(P::BaseField::from_okm(c0), P::BaseField::from_okm(c1))
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+}
+
+/// Curves with a deterministic map from the base field to the curve.
+pub trait MapToCurve: AffineCurve {
+    /// Maps `u` to a point on the curve. The point is not necessarily in the
+    /// prime order subgroup.
+    fn map_to_curve(u: Self::BaseField) -> Self;
+
+    /// Maps a point on the curve to the prime order subgroup.
+    fn clear_cofactor(&self) -> Self;
+}
+
+/// Implements `expand_message_xmd`, which stretches `msg` to `len_in_bytes`
+/// uniformly random bytes using the hash function `D`, domain-separated by
+/// `dst`.
+pub fn expand_message_xmd<D: Digest + BlockInput>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToCurveError> {
+    let b_in_bytes = D::output_size();
+    let r_in_bytes = D::BlockSize::to_usize();
+    let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
+    if ell > 255 || len_in_bytes > 65535 {
+        return Err(HashToCurveError::OutputTooLong);
+    }
+
+    // Tags longer than 255 bytes are replaced by their hash.
+    let long_dst;
+    let dst = if dst.len() > 255 {
+        long_dst = D::new().chain(b"H2C-OVERSIZE-DST-").chain(dst).result();
+        long_dst.as_slice()
+    } else {
+        dst
+    };
+    let with_dst_prime = |h: D| h.chain(dst).chain([dst.len() as u8]);
+
+    let b_0 = with_dst_prime(
+        D::new()
+            .chain(vec![0u8; r_in_bytes])
+            .chain(msg)
+            .chain((len_in_bytes as u16).to_be_bytes())
+            .chain([0u8]),
+    )
+    .result();
+    let mut b_i = with_dst_prime(D::new().chain(&b_0).chain([1u8])).result();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let b_0_xor_b_i: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = with_dst_prime(D::new().chain(b_0_xor_b_i).chain([i as u8])).result();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// Hashes `msg` to `count` elements of `F`, using `expand_message_xmd` with
+/// the hash function `D` and the domain separation tag `dst`.
+pub fn hash_to_field<F: HashToField, D: Digest + BlockInput>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<F>, HashToCurveError> {
+    let l = (F::BasePrimeField::size_in_bits() + SECURITY_BITS + 7) / 8;
+    let len_per_elem = l * F::extension_degree();
+    let uniform_bytes = expand_message_xmd::<D>(msg, dst, count * len_per_elem)?;
+    Ok(uniform_bytes
+        .chunks(len_per_elem)
+        .map(F::from_okm)
+        .collect())
+}
+
+/// Hashes `msg` to a point in the prime order subgroup of `G`. This is the
+/// draft's random oracle construction (the `_RO_` suites): it maps two field
+/// elements and adds the results.
+pub fn hash_to_curve<G, D>(msg: &[u8], dst: &[u8]) -> Result<G, HashToCurveError>
+where
+    G: MapToCurve,
+    G::BaseField: HashToField,
+    D: Digest + BlockInput,
+{
+    let u = hash_to_field::<G::BaseField, D>(msg, dst, 2)?;
+    let mut point = G::map_to_curve(u[0]).into_projective();
+    point.add_assign_mixed(&G::map_to_curve(u[1]));
+    Ok(point.into_affine().clear_cofactor())
+}
+
+/// Encodes `msg` as a point in the prime order subgroup of `G`. This is the
+/// draft's nonuniform construction (the `_NU_` suites): it is cheaper than
+/// [`hash_to_curve`], but its output is not uniformly distributed.
+pub fn encode_to_curve<G, D>(msg: &[u8], dst: &[u8]) -> Result<G, HashToCurveError>
+where
+    G: MapToCurve,
+    G::BaseField: HashToField,
+    D: Digest + BlockInput,
+{
+    let u = hash_to_field::<G::BaseField, D>(msg, dst, 1)?;
+    Ok(G::map_to_curve(u[0]).clear_cofactor())
+}
+
+/// Evaluates the polynomial with coefficients `coeffs`, in ascending order of
+/// degree, at `x`.
+fn evaluate_polynomial<F: Field>(coeffs: &[F], x: &F) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * x + coeff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::String;
+    use sha2::Sha256;
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn expand_message_xmd_sha256() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let cases: [(&[u8], usize, &str); 3] = [
+            (
+                b"",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                0x80,
+                "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4bc95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be14cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df",
+            ),
+        ];
+        for (msg, len, expected) in cases.iter() {
+            let uniform_bytes = expand_message_xmd::<Sha256>(msg, dst, *len).unwrap();
+            assert_eq!(to_hex(&uniform_bytes), *expected);
+        }
+    }
+
+    #[test]
+    fn expand_message_xmd_rejects_long_output() {
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"", b"DST", 255 * 32 + 1),
+            Err(HashToCurveError::OutputTooLong)
+        );
+        assert!(expand_message_xmd::<Sha256>(b"", b"DST", 255 * 32).is_ok());
+    }
+
+    #[test]
+    fn expand_message_xmd_long_dst() {
+        let long_dst = [b'x'; 300];
+        let hashed_dst = Sha256::new()
+            .chain(b"H2C-OVERSIZE-DST-")
+            .chain(&long_dst[..])
+            .result();
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abc", &long_dst, 64).unwrap(),
+            expand_message_xmd::<Sha256>(b"abc", &hashed_dst, 64).unwrap()
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    curves::models::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+    fields::{BitIteratorBE, Field, SquareRootField},
+    hash_to_curve::{evaluate_polynomial, HashToField, MapToCurve},
+    One, Zero,
+};
+
+/// Parameters for hashing to a short Weierstrass curve `E` with the
+/// simplified SWU map.
+///
+/// The map needs `a * b != 0`, which fails for the pairing-friendly curves
+/// `y^2 = x^3 + b`. It is therefore evaluated on an isogenous curve
+/// `E': y^2 = x^3 + a' * x + b'` with `a' * b' != 0`, and the result is
+/// carried to `E` by the isogeny
+/// `(x', y') -> (x_num(x') / x_den(x'), y' * y_num(x') / y_den(x'))`.
+pub trait SWUParameters: SWModelParameters {
+    /// The coefficient `a'` of the isogenous curve `E'`.
+    const ISOGENOUS_COEFF_A: Self::BaseField;
+
+    /// The coefficient `b'` of the isogenous curve `E'`.
+    const ISOGENOUS_COEFF_B: Self::BaseField;
+
+    /// The non-square `Z` of the simplified SWU map on `E'`.
+    const Z: Self::BaseField;
+
+    /// Coefficients of `x_num`, in ascending order of degree.
+    const ISOGENY_X_NUM: &'static [Self::BaseField];
+
+    /// Coefficients of `x_den`, in ascending order of degree.
+    const ISOGENY_X_DEN: &'static [Self::BaseField];
+
+    /// Coefficients of `y_num`, in ascending order of degree.
+    const ISOGENY_Y_NUM: &'static [Self::BaseField];
+
+    /// Coefficients of `y_den`, in ascending order of degree.
+    const ISOGENY_Y_DEN: &'static [Self::BaseField];
+
+    /// The scalar `h_eff` by which hashed points are multiplied to clear the
+    /// cofactor.
+    const H_EFF: &'static [u64];
+}
+
+/// Maps `u` to a point on the isogenous curve `E'` with the simplified SWU
+/// map.
+fn simplified_swu<P: SWUParameters>(u: P::BaseField) -> (P::BaseField, P::BaseField)
+where
+    P::BaseField: HashToField,
+{
+    let a = P::ISOGENOUS_COEFF_A;
+    let b = P::ISOGENOUS_COEFF_B;
+    let g = |x: P::BaseField| (x.square() + &a) * &x + &b;
+
+    // x1 = -b / a * (1 + 1 / (Z^2 * u^4 + Z * u^2)), or b / (Z * a) if the
+    // denominator vanishes.
+    let z_u2 = P::Z * &u.square();
+    let x1 = match (z_u2.square() + &z_u2).inverse() {
+        Some(tv) => -(b / &a) * &(P::BaseField::one() + &tv),
+        None => b / &(P::Z * &a),
+    };
+
+    // Exactly one of g(x1) and g(Z * u^2 * x1) is a square.
+    let (x, y) = match g(x1).sqrt() {
+        Some(y) => (x1, y),
+        None => {
+            let x2 = z_u2 * &x1;
+            (x2, g(x2).sqrt().unwrap())
+        },
+    };
+    let y = if u.sgn0() == y.sgn0() { y } else { -y };
+    (x, y)
+}
+
+impl<P: SWUParameters> MapToCurve for GroupAffine<P>
+where
+    P::BaseField: HashToField,
+{
+    fn map_to_curve(u: P::BaseField) -> Self {
+        let (x, y) = simplified_swu::<P>(u);
+
+        let x_den = evaluate_polynomial(P::ISOGENY_X_DEN, &x);
+        let y_den = evaluate_polynomial(P::ISOGENY_Y_DEN, &x);
+        match (x_den * &y_den).inverse() {
+            Some(den_inv) => {
+                let x_num = evaluate_polynomial(P::ISOGENY_X_NUM, &x);
+                let y_num = evaluate_polynomial(P::ISOGENY_Y_NUM, &x);
+                Self::new(
+                    x_num * &y_den * &den_inv,
+                    y * &y_num * &x_den * &den_inv,
+                    false,
+                )
+            },
+            // The kernel of the isogeny is sent to the identity.
+            None => Self::zero(),
+        }
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        self.mul_bits(BitIteratorBE::new(P::H_EFF)).into()
+    }
+}
@@ -82,6 +82,9 @@ pub use self::curves::*;
 pub mod groups;
 pub use self::groups::*;
 
+pub mod hash_to_curve;
+pub use self::hash_to_curve::*;
+
 mod rand;
 pub use self::rand::*;
 
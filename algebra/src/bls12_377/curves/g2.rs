@@ -5,8 +5,9 @@ use algebra_core::{
         models::{ModelParameters, SWModelParameters},
         GLVParameters,
     },
-    field_new, impl_glv_for_sw, impl_scalar_mul_kernel_glv, impl_scalar_mul_parameters, PrimeField,
-    Zero,
+    field_new,
+    hash_to_curve::SWUParameters,
+    impl_glv_for_sw, impl_scalar_mul_kernel_glv, impl_scalar_mul_parameters, PrimeField, Zero,
 };
 
 use crate::{bls12_377, bls12_377::*};
@@ -126,6 +127,517 @@ impl SWModelParameters for Parameters {
     impl_glv_for_sw!();
 }
 
+impl SWUParameters for Parameters {
+    /// ISOGENOUS_COEFF_A = [
+    /// 203356303776336476847321286177546132047307642878426827615291986074268532163150339251641782743212963124033190094252,
+    /// 175010726796459260979609697463726129865449065968053178886770739031237127658526303773794795118951859151866786102568]
+    #[rustfmt::skip]
+    const ISOGENOUS_COEFF_A: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger384([5900653029474263788, 11638707301791996271, 3180600281810735412, 2521940706647083515, 8579423943272702094, 91032452537350439])),
+        field_new!(Fq, BigInteger384([3302956352149617427, 219147011789681192, 13720764782425789426, 10743761660306600704, 1036705219179402097, 117242285476906983])),
+    );
+
+    /// ISOGENOUS_COEFF_B = [
+    /// 240820903982536718097684362909692926119342050659564933755990617504470329880018753877294753834755294405835007267210,
+    /// 178556220617533172658793888105025913774154597692789555945405824358240117767129520715176641501973248794025606470332]
+    #[rustfmt::skip]
+    const ISOGENOUS_COEFF_B: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger384([8526570159423194833, 17010289162527503030, 1991209271310330346, 6462394278213273778, 9899803183843545073, 100368662687924524])),
+        field_new!(Fq, BigInteger384([18021525250441661346, 952377008404760420, 12941310906957066140, 17019369970144866905, 7252047905908583358, 77959813062719564])),
+    );
+
+    /// Z = u
+    #[rustfmt::skip]
+    const Z: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        field_new!(Fq, BigInteger384([202099033278250856, 5854854902718660529, 11492539364873682930, 8885205928937022213, 5545221690922665192, 39800542322357402])),
+    );
+
+    /// A 23-isogeny from the curve `y^2 = x^3 + ISOGENOUS_COEFF_A * x +
+    /// ISOGENOUS_COEFF_B` to G2. G2 has no rational isogeny of smaller degree
+    /// to a curve with `a * b != 0`. The draft does not define a suite for
+    /// BLS12-377, so this isogeny and `Z` were chosen here, by
+    /// `scripts/hash_to_curve/bls12_377_g2_isogeny.py`.
+    #[rustfmt::skip]
+    const ISOGENY_X_NUM: &'static [Self::BaseField] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([6816772332825436735, 8741483139960115003, 8167434320974386572, 13022081903917752565, 4664670627954043630, 110726365745180199])),
+            field_new!(Fq, BigInteger384([11045858864476645289, 11834903998612888924, 10065724748491632964, 13826804524835764345, 9751772237830476371, 66238551601310226])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([7484489830752669204, 15728638499146088977, 17334867440262798131, 8888621642187179361, 3130297623563940589, 115159117688397900])),
+            field_new!(Fq, BigInteger384([4575189844210379576, 11206661841194329717, 15295610775382069913, 11925875132094947633, 3084270195862013, 67464495669176732])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([6451079882640985654, 17118054026368974408, 7247477434074533296, 16208844396287459428, 7323856323040658762, 114996175483046627])),
+            field_new!(Fq, BigInteger384([10882859653661486384, 14002395301434445484, 13841721683936921020, 12872769376740774345, 10659925497624284554, 68902458685381243])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11847966843721996933, 1619022698861380820, 12760504725279024805, 2591148895312325635, 2770730114236287893, 25500032127402912])),
+            field_new!(Fq, BigInteger384([16211839052791367881, 6400469699683714863, 1121640511673034794, 926331015105013306, 16597452162368319344, 5911351447663531])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8421591561531535613, 14291410450869434003, 8399503706124511409, 16053848037618560008, 17104389586623852546, 49626801831175904])),
+            field_new!(Fq, BigInteger384([15742490655717555517, 3761450046788091596, 4696088140156744734, 12274090038807268083, 9322035319113703371, 64573421341835102])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([3796386083151351298, 6015683655073296523, 4341399614257949989, 11556059712526170229, 16900901180261120318, 33224503779017925])),
+            field_new!(Fq, BigInteger384([15459287974017147898, 4671081715602611516, 11425935275518657033, 15839510029146284998, 8254011528324538713, 12838361513283653])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16376320524784321792, 9922483526060475812, 8061870216670674822, 1754171523026152670, 6211961169391977594, 82432942451464228])),
+            field_new!(Fq, BigInteger384([16827460978485422350, 15210487004928557892, 9749778396337408499, 287923344338031719, 11030855280824942822, 4884590972022263])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([2776487122108221165, 16578532271036188320, 6878985865636898381, 1801398159043826857, 7103432885476086686, 37909691441686408])),
+            field_new!(Fq, BigInteger384([14695243533657324195, 5899966107565907897, 2715278770590852783, 17898124138980661656, 4519847711705150166, 66882566803271745])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([1924538126647895664, 12201175724467066597, 3722508543911483837, 9868779046990903960, 13270003482756024659, 29230137665352795])),
+            field_new!(Fq, BigInteger384([5706265722894923654, 13646966061790090423, 11272165016855175867, 5474915400771719348, 4940890500551787710, 279972574155309])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([7780942950906697335, 13522828165577953874, 9345854487564728069, 17665551414919874192, 14412857033801656795, 29889862225527118])),
+            field_new!(Fq, BigInteger384([578305430099698140, 6977812692290065650, 13263605455334621885, 15549982393144032224, 5979028503689601277, 85337610240838328])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8346256673564798013, 4733122182276450257, 12845221376884740299, 13477866792938475088, 9525626684258904398, 62000400523752342])),
+            field_new!(Fq, BigInteger384([5211743620437160387, 7536037088179410213, 13111305804032967122, 8783803879529559274, 14102638670474729691, 378072164040368])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8208995715419581545, 13213430748544835592, 15830587736744782532, 17883235629401977697, 17576381121978311753, 96410109094148164])),
+            field_new!(Fq, BigInteger384([9345542204847315401, 3012078494599619722, 16588762873589811347, 881309845582588205, 6371724353760893906, 29149018231179246])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11678160623678532223, 14222566846252603377, 2320929666176206696, 10169843624890764608, 3828998457357698920, 52011755358152616])),
+            field_new!(Fq, BigInteger384([4743031601895684417, 16249570100840074867, 13041776185911332111, 7240309845900668336, 12132734712457556848, 115775172125895452])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([17887659245689398743, 16674916645080851597, 17837753594091749846, 14410012548233288643, 15899749597634922032, 36424684748152130])),
+            field_new!(Fq, BigInteger384([6882102240252431442, 589212551504006315, 8618656390664410885, 3156874779003415225, 17479817944511827884, 29632008501836329])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([7875529997850774751, 10796003167495288242, 13783502036501160422, 2361144121108389170, 3919372370705571025, 104315237546453513])),
+            field_new!(Fq, BigInteger384([18293382669846149741, 13833862872801381037, 15036868246911066985, 16175803442085323386, 14997374746091536733, 6022913929147951])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([17154617949956868487, 17525823900488637153, 18325726326373800667, 9884930593739645118, 10952833288655297524, 77667819573055934])),
+            field_new!(Fq, BigInteger384([12754563141471218005, 18239449885645328241, 13664054261267181835, 724699357883150785, 9973547393029940045, 80256922992973456])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([660467314131663024, 10317721044594963544, 6473807712867722894, 10771407041515882109, 17577110604160443078, 43810639115794075])),
+            field_new!(Fq, BigInteger384([9783309159914967120, 2694138886425590604, 1753092587794572989, 14488972740039396163, 14027871702593801604, 80307069377940735])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([2427241160379214537, 9246467072066255784, 16786062894231228182, 12524775235185169156, 11475592374235173666, 13466555447896637])),
+            field_new!(Fq, BigInteger384([10991477100133203129, 12445941751494975677, 789695926162979361, 4603376976407652531, 10906085623905112835, 7462721937668089])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([14151900758682613471, 11079313771047378473, 10031482538412300493, 4427561867553287746, 3374994504918221347, 49074620333663001])),
+            field_new!(Fq, BigInteger384([8068579648717389574, 6975374823610713850, 17103803213972787302, 15237596507415860596, 6054186279395331169, 50913829881864974])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([14084056326190797078, 5698984881736852173, 6825337546348239902, 6063429783999712834, 5538454039256031085, 97602332950046290])),
+            field_new!(Fq, BigInteger384([10468866931730852539, 3150072985103592935, 1287403706384019475, 2839788729375701232, 8731895381152226068, 68668373401550880])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([10005325257509349535, 6765356474849958993, 552440951978569109, 9497754618159380072, 17107580155282982616, 104032088540424366])),
+            field_new!(Fq, BigInteger384([17617586004559609912, 6768018291734122268, 13115756908155920733, 11052502604092932717, 5987506168645290109, 3046214173656431])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([4968267060287580989, 8740608503214882019, 17264353410731318684, 4680327692877579404, 12075753217391224784, 118699338371059931])),
+            field_new!(Fq, BigInteger384([17670646523742653756, 6802969866643267166, 824965850555426511, 4765957036281012422, 3314185309602458564, 106037465364505109])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([10018750779677247396, 3878541125548270330, 7354402435929922507, 3196951457942755172, 3370214278348133326, 81702610441445685])),
+            field_new!(Fq, BigInteger384([8683958169661114467, 11851460641358379942, 13235673539734945920, 6521701738750337456, 8540695514822192378, 60953079701612300])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([3621702609341817994, 1179514540952803843, 9695126383219869545, 4861853798003230532, 15648444733987506481, 103088924877589738])),
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_X_DEN: &'static [Self::BaseField] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([4575496398786998309, 14426192645179281655, 6453521895529600235, 4986705247998200988, 7401060381367228932, 81819649767557163])),
+            field_new!(Fq, BigInteger384([1377756666020522601, 4710091080446160278, 14418749751078730834, 9458091603481828740, 11670207176815618120, 79447679166261411])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16728049374751966350, 9982380324550507740, 3330073508492251650, 6254284620069521931, 8827204568816519948, 48445190784204154])),
+            field_new!(Fq, BigInteger384([12231736248858826646, 2271202909727972294, 5608011356053628500, 15976026831429536244, 5781971105339100481, 84207079457446289])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11575320613351780352, 18438903623643677126, 9691987108259775530, 15170462084533227989, 12901332116620997356, 85960580926919754])),
+            field_new!(Fq, BigInteger384([9262203717289678576, 15266140873312585417, 6637996760427396866, 8512129053791483497, 15390898757733574979, 118957251601719985])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([12881556802804616447, 5491153606401907986, 17191273913267516157, 5491099844626644184, 12512480979824598067, 7471357061253109])),
+            field_new!(Fq, BigInteger384([15676621866020397026, 17414290423146518118, 10224599483718067272, 14252882909357370472, 13831228651786606900, 3433281785390373])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([5964730248281226393, 4289237628987300844, 15171181531253931521, 18332515163692809306, 1486738405541634596, 50687907059177867])),
+            field_new!(Fq, BigInteger384([4214572241395912966, 14795088909044220442, 1128949633220070674, 7342034643189252416, 4433988449733445243, 23054813384261803])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16905388903876863249, 11606683988977136863, 3021957144449915513, 13920870926854356753, 11236319403452506238, 109101342279572005])),
+            field_new!(Fq, BigInteger384([3326164664515719557, 15794428221205858229, 16340276702658004357, 9312252549906630229, 8347588632418435984, 81985728883057119])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([17040124814249244828, 8356406142042769941, 17894628324113019155, 16203036496393077486, 14684887834185774141, 115889870357940793])),
+            field_new!(Fq, BigInteger384([11916078857787678550, 4393704141679203366, 13259952534678042968, 6657927480010067926, 16787162801297967608, 78707110317392051])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([4921997196413087161, 440373440992211008, 3291108971097475123, 12017750952601016639, 18058706318061661607, 106149142877883690])),
+            field_new!(Fq, BigInteger384([1777525306836139896, 5159427052404916109, 7287725723496879544, 1141572460388897156, 18326942410956943814, 15392408003596000])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([392321647283045669, 9595680887095921504, 3684155547142907497, 16305629460728646621, 13525565832748062208, 72671942527285974])),
+            field_new!(Fq, BigInteger384([11947520021115023355, 13852023280662248713, 1019926923772536673, 5830940766596374411, 697428142345265869, 95303680802300221])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([5849816752267805794, 2794908013025666832, 6172627579019014078, 12546952317727039039, 9472540902231082122, 5794814022701478])),
+            field_new!(Fq, BigInteger384([7802577404416667027, 15558162318231227453, 16326452305555951506, 6780069321117211658, 6992611614753425995, 74563340598189863])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16916897101310895787, 6761247572296087314, 7394495756278350302, 1122981353515945755, 17732299236321844157, 109233111433868197])),
+            field_new!(Fq, BigInteger384([12537377747614095422, 3240441152886485845, 8875108481760821139, 10432866878477923796, 17068273855909148982, 25927090909290959])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([17848900120884092997, 13206008283249725870, 12835229805384576554, 4160964892273298188, 11047680637286056025, 25475047586149001])),
+            field_new!(Fq, BigInteger384([6663668136553096634, 15053915093386719245, 8364558219954351803, 3865711061627884200, 1428466179308880883, 20138246888575656])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8738371589550044783, 10613294971595409537, 1575973748333676055, 6556652421055154344, 87055208738026352, 95053360657671858])),
+            field_new!(Fq, BigInteger384([9927537372094882588, 904256180589236963, 16319249132082562898, 939924845623492872, 1955155728020376240, 10300713488764724])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16633654486715800436, 10761911607691223515, 11320845368653837373, 16807675830535490625, 8422642643771194532, 108500059431064913])),
+            field_new!(Fq, BigInteger384([6495220281414615738, 11242288231975902615, 1420224947240359180, 13810009375553704370, 15790372430891901853, 110198613303654443])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([4476002774156065366, 1053349202185401786, 15575744850025839298, 10357565922481765754, 8064019905335751946, 46846164054648639])),
+            field_new!(Fq, BigInteger384([5160489257901671646, 9065753061955971329, 15113220707879105280, 10161446109921201594, 16728276530633154816, 31024021825903056])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8638467154945101197, 12902613187951115327, 15429531494577155020, 8977617339908608518, 14917034679779820564, 37240839425466920])),
+            field_new!(Fq, BigInteger384([12777956324256642228, 10813830237895828701, 12119230370295463676, 17719884298885286875, 9571342061096522301, 42400993983667855])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([17680698421654353573, 9407878572973404451, 11040858225016552111, 9719100915218617361, 16255590545869795387, 96890592566611192])),
+            field_new!(Fq, BigInteger384([15835432590306744137, 5930140935168677736, 10852492249438808016, 3888799026836542476, 2800758683730126973, 51901873162617229])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([2523853120685230002, 13781004992283544184, 16630764154100381616, 5141294942084849744, 1080389890638385395, 10149024886361023])),
+            field_new!(Fq, BigInteger384([11524429785022273709, 5939714841253985077, 12098288961296303037, 13796736243316593132, 12237808182163296155, 95033178296642317])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([17393155249429168184, 8844612859006238469, 17036323159628110401, 10131792316483322851, 6141598763358857081, 116205351416626112])),
+            field_new!(Fq, BigInteger384([5043875339741925496, 17893529113832575907, 17924520841612329539, 13579906429952913586, 15749446074907227804, 100709730509624924])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([6175891004487282114, 10903996969108466539, 16080645087756128506, 6650117717937838805, 1275299706038672258, 60114052760932411])),
+            field_new!(Fq, BigInteger384([8583252712087155665, 7866714194640292897, 8433199157830717850, 1118422672878865321, 8237729604422870256, 106342215958856303])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([7992421831636527525, 17278179789895499441, 16222871328314300803, 17470914627719901967, 14900006697957460000, 93256850901577671])),
+            field_new!(Fq, BigInteger384([2047687772224110224, 8217245715323683853, 14388345423491479950, 9753720633328496797, 7772176562099037404, 50291262703373706])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([5691509870624000128, 3309130710397781760, 15909253950878781130, 6159022637507765103, 18147246208471455449, 109681600012654697])),
+            field_new!(Fq, BigInteger384([14763328760570303369, 16999062333947871860, 7408812380258335163, 15988188390882151285, 17503139278069062123, 32027982225317070])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([202099033278250856, 5854854902718660529, 11492539364873682930, 8885205928937022213, 5545221690922665192, 39800542322357402])),
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_Y_NUM: &'static [Self::BaseField] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([7530714559354180249, 15145988760459027194, 8412036026042915741, 4235079004250114563, 7681160215268292567, 24346576714485067])),
+            field_new!(Fq, BigInteger384([12557037864348861903, 7985431831122950278, 15512485257519594075, 7135425453261182925, 12898362858844720162, 71154072879490271])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([9859684922628828820, 15964246106621628844, 11675823480876329088, 12121292942659029854, 1705845282699547399, 98856714874905407])),
+            field_new!(Fq, BigInteger384([11309441988220687354, 4974231537084114712, 14446026400479432040, 8546592666540539435, 5377239494044897699, 48053838840718103])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8966237665320302037, 1592798466178361658, 16352162290333333890, 16110059991219466484, 17590238414367642171, 5376319776054844])),
+            field_new!(Fq, BigInteger384([1454735583210144154, 9009085657466735771, 6165252472716912940, 11036934191642955809, 16010924554281738628, 71428831514864536])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([2561665913489503321, 350974942255308913, 9172155571409497576, 14923682435575983250, 16887916648811852864, 66642998122387860])),
+            field_new!(Fq, BigInteger384([4768120978029060923, 17587270612979279438, 593307490719307882, 13242047039715475395, 6844117095086014362, 56283115639588434])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([10607360743429982496, 8508844340764628097, 14573655308901858970, 7831086627849362539, 13141772150094224215, 59516414267619618])),
+            field_new!(Fq, BigInteger384([61268351439260175, 12858075752941419903, 12618869518002856070, 13954600334337204081, 13078645726280285730, 71318627463735315])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8717462446213589958, 10741020699727406908, 9966248892757117880, 64281814665249956, 923230674232871874, 18298318637257755])),
+            field_new!(Fq, BigInteger384([3579846070957117664, 10962670541013857520, 15688571526249100708, 15197614246308383803, 2969177406447974105, 88371650288584349])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([1753155380661340622, 13112714453441463810, 310828129304939610, 14820497693508576016, 11661524460505165997, 101011094052230248])),
+            field_new!(Fq, BigInteger384([12061893930917220902, 7427380618722171385, 499568273583176111, 5798825584648800778, 12239607128905211712, 4894005731991243])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([5993445873061202698, 12370666907592043776, 9605557675629803941, 324023665382755887, 17522074950754137123, 68977097780879511])),
+            field_new!(Fq, BigInteger384([11553710835718011540, 1578458960958139329, 17013420626165342145, 2203407758628187059, 9763708911149071603, 61357571190034852])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([306899826404361497, 14518580235646242914, 10473696998103244938, 6979528696502611617, 15978259422176681328, 8435138442512580])),
+            field_new!(Fq, BigInteger384([18304467551349137959, 18157076240386381340, 1595192289926627019, 12639916782618428734, 11958117041684768793, 77593928470973844])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([12994233598522421498, 8333233101811982129, 3087535054061804077, 199470899484318043, 11271446863556922124, 94191421485595836])),
+            field_new!(Fq, BigInteger384([1694578470582264570, 13869921486500077949, 14367540053108174394, 1031150964951784387, 6464623737897163274, 26810905053312727])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([3017928859476430482, 16715717310927581277, 9632768337392806052, 2889458120891201334, 1869633166798873913, 26559208762462801])),
+            field_new!(Fq, BigInteger384([16468147685534587211, 5659323636214354120, 12895260372173129762, 4276991802484241858, 259345544761612780, 50877805829757272])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([9912431137170628672, 5198179158665751826, 13699831282382882797, 1461870103417747837, 3836444488385910698, 11192388129403880])),
+            field_new!(Fq, BigInteger384([2983159250678616948, 13733169677975397415, 14058464050793943664, 11101172777042705006, 14465980926972370809, 13319261991394696])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([12291351228868947945, 8789660017445901365, 10255728632007970271, 5499317899848965254, 2618912585910924395, 106101332078147061])),
+            field_new!(Fq, BigInteger384([6172741371947799377, 18379522418209655465, 10828683997222775813, 2722367355844696468, 2562930623844302819, 106055060452969044])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16902971670413667252, 4669849824080616142, 2945269775138734139, 12781114539269557043, 5808314523070810020, 43459352424321764])),
+            field_new!(Fq, BigInteger384([15085870227544280723, 10656503069979850954, 7366756445075295015, 16347805104810671785, 1022795982589788061, 73193209836418318])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([3229194122041204381, 18445729664289960648, 6842507822141900849, 12968919696141758039, 6448269929840762896, 65257593253380129])),
+            field_new!(Fq, BigInteger384([4627459168039409559, 13360353056987032589, 7009516442974402724, 16678437939873396674, 16728179571148081611, 55156563031299931])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([10351169494056857692, 13903135453039124382, 6656932832038311136, 18346239508212830280, 10439850247945998942, 91712833862613052])),
+            field_new!(Fq, BigInteger384([6652231012736787003, 3327401133942782485, 12290797593806001076, 2139072099558019049, 13477821832128476720, 49709590491757314])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([17656315300301575456, 2323722120015611862, 6859608163262269138, 15813585433396460220, 589008457349388735, 72914495192486329])),
+            field_new!(Fq, BigInteger384([1083208667616290668, 13263970387385185136, 14092068345339799954, 12137883721038190536, 2331424575209233421, 19611539426340036])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([458062052977651978, 18160111915106013396, 4640398405879319188, 4687098663536493532, 10430921409779694146, 4563430082201994])),
+            field_new!(Fq, BigInteger384([1334767020527592096, 5993077524953991102, 16913781394079534673, 26486505102392277, 413684449109466124, 99047076153415425])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([14248414671160727633, 10687711749751970164, 4903732401524766751, 1686847166910703333, 10095453529619390046, 23985081725192885])),
+            field_new!(Fq, BigInteger384([11568909385086058944, 13493820015075043621, 3792411106336042137, 11400326004961765136, 1290415184983170653, 3664963785473310])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([9887832317967990619, 11294789136001698344, 4971907180893225669, 12537370045049794842, 10374092738018418183, 61092282014797942])),
+            field_new!(Fq, BigInteger384([10085216120333493389, 161328956411822775, 2417556149319726459, 15286089344709685317, 7008946804151267591, 31667381623393085])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11312854840210528489, 17339331321028512600, 17988818175274264204, 17782410756144357168, 5839882210116680539, 118936479224329265])),
+            field_new!(Fq, BigInteger384([10859096711405056791, 11496337053420857729, 10915026051515254947, 7434412106431781859, 18169541716645330053, 91337766324899098])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11828759308210095129, 8967697415451850198, 1916367307793658794, 1012548693410771747, 16016402169596437792, 54829014329015516])),
+            field_new!(Fq, BigInteger384([4916325011859900387, 4574219223874080833, 7160178920893623662, 8240719706222156112, 7082631143603357591, 16149442392385747])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([270727492870392832, 14437969648407449352, 12853036647929506205, 1219200495785496025, 12386417990950961629, 3511449767303025])),
+            field_new!(Fq, BigInteger384([10303698859918107289, 2958890847240293123, 3677300962556913681, 15582975756021014896, 12390447247323992428, 108071115914073001])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([14456679943500364697, 8513756145231292361, 12155601148687628631, 15194315217215541047, 14095721029394404156, 36300587367906260])),
+            field_new!(Fq, BigInteger384([16975367032315048186, 5716771748691845999, 581904765749331200, 14453582650716590599, 1208193968013787768, 25312236515631398])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11577340509705081032, 2724813243619368332, 7095177685332178414, 14117265515709106134, 1093095594563608100, 12022538373390267])),
+            field_new!(Fq, BigInteger384([11475244789110538136, 11130505772590891747, 11763911624570069239, 6114761999532244163, 8161514351503754459, 60032481486109832])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([18023489074259791109, 85449869311936039, 16814602695218189906, 11244009955864205581, 7821709998719817730, 41777315732444594])),
+            field_new!(Fq, BigInteger384([10447689252827617342, 12995348058371814714, 6182788546900391955, 430411679388329743, 9752361688763538898, 115866128538627476])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([3061026925630477463, 6871796603308912674, 14286286203685533432, 2248728056822260160, 8909534607655909437, 78164426542003441])),
+            field_new!(Fq, BigInteger384([7441904247212717514, 9034858969183061979, 5849740004999443473, 7127329456424494796, 15329033879175333481, 9432435089696798])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([6455535000292758615, 12855289992122250034, 318819873156286034, 11967581665490959587, 16254813816274466240, 48987029215628242])),
+            field_new!(Fq, BigInteger384([17507869155842992541, 5115169386247400251, 4226542677632138651, 949688811509826494, 1203603115567080179, 17641548535941707])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([12757244581469864011, 4945767544459657906, 5970056643928461839, 16025950645419023734, 16375429409270352623, 80536693184568463])),
+            field_new!(Fq, BigInteger384([11557060489483310142, 9918885673120410022, 7516123477644534466, 2123244761367887772, 17830614702304727130, 70808221197720516])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([6502000988003388612, 4534350798687169466, 5778777862380372797, 16426358595004474169, 16022312807982889207, 67004399754499777])),
+            field_new!(Fq, BigInteger384([5944183787316105322, 10183601166108731398, 14551218771936110908, 4468244826568684539, 4286340241378100511, 61959963573784019])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16263052224910013999, 11443384337819856953, 11075012176720280613, 7224907553849025024, 9603774433942084174, 22124535658965265])),
+            field_new!(Fq, BigInteger384([772066814837977769, 8829202777014142579, 11206159916273629864, 16689659273894130845, 3443701772242258598, 86769789472703004])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([955528692351513622, 17357109549446802791, 10430746358025054578, 13736908088777476686, 2299881528946556462, 60022802036345213])),
+            field_new!(Fq, BigInteger384([9162386484392388236, 12635693819652862330, 12430403851109975282, 2180303906200445784, 7407012095618192118, 32127555074620442])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11181105403927773057, 7502883373652039719, 12811417479155794257, 3262078219524031122, 16275117909972976977, 63118441283576632])),
+            field_new!(Fq, BigInteger384([3967646178057328768, 7925401570438748500, 10347993373430974945, 50223023281655968, 12976436896871819996, 103960251779596646])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([9782358426082172166, 12621103095312757693, 11944227414219911263, 1426273514791711965, 2116128944543411149, 100820753445752358])),
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_Y_DEN: &'static [Self::BaseField] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([13106886222690700540, 5265530951402530259, 16856048605321683028, 4622542427609810563, 7456733633720537335, 90365350657320738])),
+            field_new!(Fq, BigInteger384([2497951881921022772, 9136988140764530989, 18368349826282854314, 14425345997528921464, 7618240039570123027, 6801827755947086])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([2580496015135914862, 8910463434125474307, 11965184186190003407, 13649841619838920442, 12073827955690284165, 112640132596271543])),
+            field_new!(Fq, BigInteger384([515716471868260160, 17581180558430650627, 9689799818273046584, 15081784338718634984, 14989994486467426296, 66713008287524799])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16437676085806243056, 5386307340098675384, 13376132942254902736, 2445550236233444110, 3435563355447839624, 19296372548264685])),
+            field_new!(Fq, BigInteger384([6279383317794359900, 10038271197212014494, 13821089950282434682, 10845002268991866726, 6980997187849503017, 69795903445325798])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([1448983576697578985, 6895319681120306656, 14811572873949947040, 17664450080014624788, 3469100552277411586, 106285921577055465])),
+            field_new!(Fq, BigInteger384([10766557778531925233, 11600336925831535855, 2933131590514764093, 3399999127564885565, 16593029516619876918, 3785945802301077])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([2583907353848831994, 5778109237876107305, 5856295770155973318, 10006725855016228133, 17108307348099424661, 57130683317568852])),
+            field_new!(Fq, BigInteger384([12091120760501632954, 415417761644054562, 2373316173195924766, 14805252819864971635, 817033701600303442, 25675689709975580])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([9919407169854580950, 4040971021105949100, 18188407381665976705, 17034174645107108625, 832730521983265364, 67185172804229021])),
+            field_new!(Fq, BigInteger384([4025382568184800246, 5662321696219670390, 9124102915595902526, 12576583528779831359, 16182921196961381993, 18746343932236942])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([13796793762950832857, 13144846088953208775, 1687853234827863940, 7953432768645174382, 7574107213572521340, 66638458354965480])),
+            field_new!(Fq, BigInteger384([14338893208052179966, 8263153432413553877, 1493509682647702084, 10401225031476037710, 5317307827591078224, 60489434153762531])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([17405468760663014427, 5673345520190476064, 9907831251812072467, 8665452030584396489, 2897294773411991661, 50792778549303326])),
+            field_new!(Fq, BigInteger384([7580580308391245596, 11538675938908220076, 16672555648414577292, 1323752683612603008, 8647025021792351005, 78699208386187575])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([1328122055202593092, 8854023281929323178, 7132418617538477984, 1698265723050426422, 9739745728959352345, 22578386346598136])),
+            field_new!(Fq, BigInteger384([8013829082911207928, 16425412182127687567, 7113798594236056035, 17936556677593034852, 6498963335712606710, 37319680383506114])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([15306072416412285407, 3156881803844435006, 3218289299026073504, 2853121228687042791, 13742175637070600077, 39818681047491670])),
+            field_new!(Fq, BigInteger384([18247192944007866941, 8752489685287053638, 12703500538637975572, 1393501868490192428, 12040047881288907455, 26023405871693578])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([692311160291445453, 164367075337171700, 10977908154509993576, 1455384450552577273, 13832963589532861843, 24706033362791037])),
+            field_new!(Fq, BigInteger384([1696481067888023280, 6939420813478845619, 12947413069506889594, 1255068709883686839, 15519283629547627085, 83464019496961781])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16014688705369469043, 11996024272279148596, 5642025099923065583, 10484098871418702550, 17125338676523717880, 10643311878231589])),
+            field_new!(Fq, BigInteger384([3541533329885186581, 572696187088684723, 14475158057406438525, 7928532288069960510, 16972362653252961153, 71217334575072762])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([5795547286413048467, 10909229945897232834, 17398738601203093682, 5637815889430007187, 8555231719796586882, 77006758169006095])),
+            field_new!(Fq, BigInteger384([1070910132319358196, 5766594073867593451, 7729754336737644462, 5845206129025306035, 6368876044037423288, 16796638330880378])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11012102297543928654, 7822326108829226377, 2838614891263578987, 4166204611043451877, 12822960736102502437, 102467149619654933])),
+            field_new!(Fq, BigInteger384([1805978728928991644, 3129106123251904279, 6839749432764682276, 4250142527343141428, 7262058735447163004, 79184993889575223])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11253491409835157774, 1091769871011428597, 8453231855794767874, 2320020836005024094, 6735578197028585080, 52235029635055939])),
+            field_new!(Fq, BigInteger384([7700498412664225290, 3151099780266856962, 4897894943060109311, 2733377205681446209, 1934622107736042030, 4736386913823379])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([4750579601229215271, 7949540009365260163, 16972183475169285951, 7101490867491981058, 7361253516408313493, 108017767907332834])),
+            field_new!(Fq, BigInteger384([8792430942258531258, 4568878328943578321, 5659062582859211068, 6466220938579835770, 9192569474769747205, 37790643025147558])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([4362111670372893598, 28312807249581901, 8568204258282491349, 9658631275522224451, 16546718822976306512, 25852768878489703])),
+            field_new!(Fq, BigInteger384([11885628094287925897, 15554391090719335135, 17449971901974051397, 16915454766370716346, 11335016177426035026, 76371768688398095])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([7612383109522354086, 4891872373650490927, 13495821804667324053, 9778711454926511056, 2419690415321800038, 57441928688635939])),
+            field_new!(Fq, BigInteger384([10263911532355189223, 16020813420000508870, 5822433998048727456, 14568950427358189949, 6451087564116314187, 34967518733476990])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([12465190805174592790, 18008656771342038096, 15377895229018876472, 9015203700947686167, 6826548331868730567, 107076360690434165])),
+            field_new!(Fq, BigInteger384([12877178763157617227, 13139818714985197404, 2617718642338643191, 7931277274840392982, 10916636371598869385, 10128625758202148])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([12806585166919578259, 16080910094983647651, 9569762246182954527, 9481796833945624048, 1636032177989329511, 70409565278722136])),
+            field_new!(Fq, BigInteger384([3945411381957879251, 11291300608526855865, 8724543363536043864, 16152111162554801229, 11043072738665776757, 68282707976475485])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([13104806288206015950, 3053931737693782339, 16393318164710743372, 14823784053468349189, 6928440959086164216, 25924661199125803])),
+            field_new!(Fq, BigInteger384([6203475933911374795, 16002775676932535789, 11301040156761011351, 18281867951563271769, 284221748551424315, 49471014604120159])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([5927786699998893642, 3006184976263736718, 9311396286755436056, 10146203869253746621, 13563909143453099039, 90127965683766102])),
+            field_new!(Fq, BigInteger384([16081507923166656811, 9395268655987445352, 13086341360373329347, 6493371739473294829, 16059590377982856498, 109283297995860048])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([13349729078279111720, 13893895581155311561, 17886489738823705941, 9546733156467970602, 3256245575963179176, 110873071449617915])),
+            field_new!(Fq, BigInteger384([14924628432040664570, 19467706229812130, 11128603832548955986, 5302587536826441821, 4678024923587777316, 57020861478368129])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([12905554673725913490, 6341233135675185778, 10197604349664511138, 5897056043525087330, 15245282412396789395, 57851565778372169])),
+            field_new!(Fq, BigInteger384([17900222664904964862, 4833293867710982655, 15483232318412597782, 4195902258606869401, 17719673079165205365, 62047960897362605])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8548043669375607336, 3452332966357112474, 9068154697208487378, 13548852749624801068, 15921332201314617694, 114586666075024201])),
+            field_new!(Fq, BigInteger384([17502126947820390557, 7532247837899730617, 10945843340284007456, 13406804205855130699, 6916056222539447890, 98815007652636381])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([15513129507278361781, 721845684514855064, 16917940757090339651, 6446188904550088101, 16283745650886846105, 73506570261299723])),
+            field_new!(Fq, BigInteger384([7723964624437926015, 18402057958790951803, 4001226490782535244, 7294789556761626785, 6969076407188921588, 43083457743487530])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([16554346857710644440, 18003760018755417177, 8266213813595240911, 9795513441483908063, 2771271292824260069, 68935491631142171])),
+            field_new!(Fq, BigInteger384([1086469878093102089, 2416644268244593856, 15007623527240284064, 785475680324642888, 17059127331422089285, 59402027170021123])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([4330864584333322387, 10116035295964881759, 3559547732583741817, 1162256931479636801, 17274638591027572145, 44429409251001431])),
+            field_new!(Fq, BigInteger384([17735537456249474664, 15158170371548029926, 12632725053073910596, 4551151199131450142, 2472396128635947777, 95579123930595481])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([2601457679650561094, 13078017233635919785, 16316474643196964548, 13199263939156047785, 9062379183440837421, 31349474881221636])),
+            field_new!(Fq, BigInteger384([17634209515823883462, 14340303109644265103, 3148336038265751596, 10077149810929528264, 5164880217779885317, 1672627961362825])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([18101686115741370699, 6890037540730028239, 13178401980500535184, 8562897701428073186, 14220661774478036599, 57866789676312914])),
+            field_new!(Fq, BigInteger384([3113472115454594258, 10377213025951547887, 11793813449630570094, 4876702288012446647, 16558032024946651999, 3385015206358973])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([12732880657517126820, 10998470225296485152, 8415205471154158440, 571741452389953300, 14985425602883976472, 10833198661771069])),
+            field_new!(Fq, BigInteger384([12203711576749120025, 4736117779322240333, 9485750868070904587, 3142586852444566819, 7623430072696331710, 89902937513461995])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([13134953954396685143, 12519348694086353241, 16386377859940182764, 11148664852664798538, 8734026228877125460, 16258722082192428])),
+            field_new!(Fq, BigInteger384([13834647503306579302, 8332417042350354135, 9489749664584499136, 8586683467049234833, 17778411298350356716, 105498268377243159])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([17397885966554918079, 3303172630536047231, 12410274691860713007, 16578598761205793176, 3713480308702378250, 43424087312487348])),
+            field_new!(Fq, BigInteger384([8491310523691220302, 17105483181597344687, 12228335669188844185, 6477192128568990455, 14949973326969056127, 108591129691222955])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([202099033278250856, 5854854902718660529, 11492539364873682930, 8885205928937022213, 5545221690922665192, 39800542322357402])),
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        ),
+    ];
+
+    /// H_EFF = COFACTOR
+    const H_EFF: &'static [u64] = Self::COFACTOR;
+}
+
 #[rustfmt::skip]
 pub const G2_GENERATOR_X: Fq2 = field_new!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 #[rustfmt::skip]
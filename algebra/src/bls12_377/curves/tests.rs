@@ -37,3 +37,72 @@ fn test_g1_generator_raw() {
         x.add_assign(&Fq::one());
     }
 }
+
+#[test]
+#[cfg(feature = "hash_to_curve")]
+fn test_hash_to_curve() {
+    use algebra_core::hash_to_curve;
+    use core::str::FromStr;
+    use sha2::Sha256;
+
+    crate::tests::curves::sw_hash_to_curve_test::<g1::Parameters>();
+    crate::tests::curves::sw_hash_to_curve_test::<g2::Parameters>();
+
+    // The draft has no suite for BLS12-377, so these are regression vectors,
+    // not test vectors from the draft. They were cross-checked against the
+    // independent implementation in `scripts/hash_to_curve/reference.py`.
+    let dst = b"ZEXE-REGRESSION-BLS12377G1_XMD:SHA-256_SSWU_RO_";
+    let cases: [(&[u8], &str, &str); 2] = [
+        (
+            b"",
+            "7668740572164403671199656349029304420561273373364022881228213958828551346817779328158440315822487370727271856466",
+            "132473053208621835032611380977250748952861188453306737911798860920641215630855154731282607578414548376254599891968",
+        ),
+        (
+            b"abc",
+            "204237776905862663566098744624671955696805387517611506130668170827591135974839593741864060542268741355641810374361",
+            "228415939692151569180871080431818446860299869579661393207467701569856456093425997285979889437103679496940246577616",
+        ),
+    ];
+    for (msg, x, y) in cases.iter() {
+        let expected = G1Affine::new(Fq::from_str(x).unwrap(), Fq::from_str(y).unwrap(), false);
+        assert_eq!(
+            hash_to_curve::<G1Affine, Sha256>(msg, dst).unwrap(),
+            expected
+        );
+    }
+
+    let dst = b"ZEXE-REGRESSION-BLS12377G2_XMD:SHA-256_SSWU_RO_";
+    let cases: [(&[u8], [&str; 4]); 2] = [
+        (
+            b"",
+            [
+                "234920522218398041360663182865513406171428981437452429366087670430516226266371103836916413593921692208847541272470",
+                "194812246509548877355001574189225365777910739777050115289862870229561784843377715038031836062947646569492008075749",
+                "38236163379781159821022085232205842628522071909998261802002645106317661223747917974170364866970031273836949421280",
+                "67872612367902636534542896956645033930704347661223921344040079902889744592095724247984532680286014035991186346239",
+            ],
+        ),
+        (
+            b"abc",
+            [
+                "3571839094357629956614818103396834520744697802060706252869336036017105653953377570097728839474126103435790131487",
+                "7497625214558196221461357078005932837716889331255368819921544397097138043423137307932530761382834533671285430536",
+                "223211857827177127999671930797840009637486911685653553169825708377148574322326570476541196942419564644732117480340",
+                "24256762930845390285392652377347312089702204840948386273368124449139310603906716148362204787763219245356000870571",
+            ],
+        ),
+    ];
+    for (msg, coords) in cases.iter() {
+        let fq = |s: &str| Fq::from_str(s).unwrap();
+        let expected = G2Affine::new(
+            Fq2::new(fq(coords[0]), fq(coords[1])),
+            Fq2::new(fq(coords[2]), fq(coords[3])),
+            false,
+        );
+        assert_eq!(
+            hash_to_curve::<G2Affine, Sha256>(msg, dst).unwrap(),
+            expected
+        );
+    }
+}
@@ -7,8 +7,9 @@ use crate::{
         models::{ModelParameters, SWModelParameters},
         GLVParameters,
     },
-    field_new, impl_glv_for_sw, impl_scalar_mul_kernel_glv, impl_scalar_mul_parameters, PrimeField,
-    Zero,
+    field_new,
+    hash_to_curve::SWUParameters,
+    impl_glv_for_sw, impl_scalar_mul_kernel_glv, impl_scalar_mul_parameters, PrimeField, Zero,
 };
 
 pub type G2Affine = bls12::G2Affine<bls12_381::Parameters>;
@@ -107,6 +108,123 @@ impl SWModelParameters for Parameters {
     impl_glv_for_sw!();
 }
 
+impl SWUParameters for Parameters {
+    /// ISOGENOUS_COEFF_A = 240 * u
+    #[rustfmt::skip]
+    const ISOGENOUS_COEFF_A: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        field_new!(Fq, BigInteger384([16517514583386313282, 74322656156451461, 16683759486841714365, 815493829203396097, 204518332920448171, 1306242806803223655])),
+    );
+
+    /// ISOGENOUS_COEFF_B = 1012 * (1 + u)
+    #[rustfmt::skip]
+    const ISOGENOUS_COEFF_B: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger384([2515823342057463218, 7982686274772798116, 7934098172177393262, 8484566552980779962, 4455086327883106868, 1323173589274087377])),
+        field_new!(Fq, BigInteger384([2515823342057463218, 7982686274772798116, 7934098172177393262, 8484566552980779962, 4455086327883106868, 1323173589274087377])),
+    );
+
+    /// Z = -(2 + u)
+    #[rustfmt::skip]
+    const Z: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger384([9794203289623549276, 7309342082925068282, 1139538881605221074, 15659550692327388916, 16008355200866287827, 582484205531694093])),
+        field_new!(Fq, BigInteger384([4897101644811774638, 3654671041462534141, 569769440802610537, 17053147383018470266, 17227549637287919721, 291242102765847046])),
+    );
+
+    /// The 3-isogeny from the curve `y^2 = x^3 + ISOGENOUS_COEFF_A * x +
+    /// ISOGENOUS_COEFF_B` to G2, as specified in the BLS12-381 suites of the
+    /// IETF hash-to-curve draft.
+    #[rustfmt::skip]
+    const ISOGENY_X_NUM: &'static [Self::BaseField] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([5185457120960601698, 494647221959407934, 8971396042087821730, 324544954362548322, 14214792730224113654, 1405280679127738945])),
+            field_new!(Fq, BigInteger384([5185457120960601698, 494647221959407934, 8971396042087821730, 324544954362548322, 14214792730224113654, 1405280679127738945])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+            field_new!(Fq, BigInteger384([6910023028261548496, 9745789443900091043, 7668299866710145304, 2432656849393633605, 2897729527445498821, 776645607375592125])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([724047465092313539, 15783990863276714670, 12824896677063784855, 15246381572572671516, 13186611051602728692, 1485475813959743803])),
+            field_new!(Fq, BigInteger384([12678383550985550056, 4872894721950045521, 13057521970209848460, 10439700461551592610, 10672236800577525218, 388322803687796062])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([4659755689450087917, 1804066951354704782, 15570919779568036803, 15592734958806855601, 7597208057374167129, 1841438384006890194])),
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_X_DEN: &'static [Self::BaseField] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+            field_new!(Fq, BigInteger384([2250392438786206615, 17463829474098544446, 14571211649711714824, 4495761442775821336, 258811604141191305, 357646605018048850])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([4933130441833534766, 15904462746612662304, 8034115857496836953, 12755092135412849606, 7007796720291435703, 252692002104915169])),
+            field_new!(Fq, BigInteger384([8469300574244328829, 4752422838614097887, 17848302789776796362, 12930989898711414520, 16851051131888818207, 1621106615542624696])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8505329371266088957, 17002214543764226050, 6865905132761471162, 8632934651105793861, 6631298214892334189, 1582556514881692819])),
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_Y_NUM: &'static [Self::BaseField] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([10869708750642247614, 13056187057366814946, 1750362034917495549, 6326189602300757217, 1140223926335695785, 632761649765668291])),
+            field_new!(Fq, BigInteger384([10869708750642247614, 13056187057366814946, 1750362034917495549, 6326189602300757217, 1140223926335695785, 632761649765668291])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+            field_new!(Fq, BigInteger384([13765940311003083782, 5579209876153186557, 11349908400803699438, 11707848830955952341, 199199289641242246, 899896674917908607])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([15562563812347550836, 2436447360975022760, 6528760985104924230, 5219850230775796305, 5336118400288762609, 194161401843898031])),
+            field_new!(Fq, BigInteger384([16286611277439864375, 18220438224251737430, 906913588459157469, 2019487729638916206, 75985378181939686, 1679637215803641835])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([11849179119594500956, 13906615243538674725, 14543197362847770509, 2041759640812427310, 2879701092679313252, 1259985822978576468])),
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_Y_DEN: &'static [Self::BaseField] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([99923616639376095, 10339114964526300021, 6204619029868000785, 1288486622530663893, 14587509920085997152, 272081012460753233])),
+            field_new!(Fq, BigInteger384([99923616639376095, 10339114964526300021, 6204619029868000785, 1288486622530663893, 14587509920085997152, 272081012460753233])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+            field_new!(Fq, BigInteger384([6751177316358619845, 15498000274876530106, 6820146801716041242, 13487284328327464010, 776434812423573915, 1072939815054146550])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([7399695662750302149, 14633322083064217648, 12051173786245255430, 9909266166264498601, 1288323043582377747, 379038003157372754])),
+            field_new!(Fq, BigInteger384([6002735353327561446, 6023563502162542543, 13831244861028377885, 15776815867859765525, 4123780734888324547, 1494760614490167112])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([8505329371266088957, 17002214543764226050, 6865905132761471162, 8632934651105793861, 6631298214892334189, 1582556514881692819])),
+            field_new!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        ),
+    ];
+
+    /// H_EFF, the effective cofactor of G2 given by the draft,
+    /// = 0xbc69f08f2ee75b3584c6a0ea91b352888e2a8e9145ad7689986ff031508ffe1329c2f178731db956d82bf015d1212b02ec0ec69d7477c1ae954cbc06689f6a359894c0adebbf6b4e8020005aaa95551
+    const H_EFF: &'static [u64] = &[
+        0xe8020005aaa95551,
+        0x59894c0adebbf6b4,
+        0xe954cbc06689f6a3,
+        0x2ec0ec69d7477c1a,
+        0x6d82bf015d1212b0,
+        0x329c2f178731db95,
+        0x9986ff031508ffe1,
+        0x88e2a8e9145ad768,
+        0x584c6a0ea91b3528,
+        0x0bc69f08f2ee75b3,
+    ];
+}
+
 pub const G2_GENERATOR_X: Fq2 = field_new!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field_new!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
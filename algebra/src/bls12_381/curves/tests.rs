@@ -37,3 +37,71 @@ fn test_g1_generator_raw() {
         x.add_assign(&Fq::one());
     }
 }
+
+#[test]
+#[cfg(feature = "hash_to_curve")]
+fn test_hash_to_curve() {
+    use algebra_core::hash_to_curve;
+    use core::str::FromStr;
+    use sha2::Sha256;
+
+    crate::tests::curves::sw_hash_to_curve_test::<g1::Parameters>();
+    crate::tests::curves::sw_hash_to_curve_test::<g2::Parameters>();
+
+    // Test vectors for the BLS12381G1_XMD:SHA-256_SSWU_RO_ suite.
+    let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    let cases: [(&[u8], &str, &str); 2] = [
+        (
+            b"",
+            "794311575721400831362957049303781044852006323422624111893352859557450008308620925451441746926395141598720928151969",
+            "1343412193624222137939591894701031123123641958980729764240763391191550653712890272928110356903136085217047453540965",
+        ),
+        (
+            b"abc",
+            "513738460217615943921285247703448567647875874745567372796164155472383127756567780059136521508428662765965997467907",
+            "1786897908129645780825838873875416513994655004408749907941296449131605892957529391590865627492442562626458913769565",
+        ),
+    ];
+    for (msg, x, y) in cases.iter() {
+        let expected = G1Affine::new(Fq::from_str(x).unwrap(), Fq::from_str(y).unwrap(), false);
+        assert_eq!(
+            hash_to_curve::<G1Affine, Sha256>(msg, dst).unwrap(),
+            expected
+        );
+    }
+
+    // Test vectors for the BLS12381G2_XMD:SHA-256_SSWU_RO_ suite.
+    let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+    let cases: [(&[u8], [&str; 4]); 2] = [
+        (
+            b"",
+            [
+                "193548053368451749411421515628510806626565736652086807419354395577367693778571452628423727082668900187036482254730",
+                "891930009643099423308102777951250899694559203647724988361022851024990473423938537113948850338098230396747396259901",
+                "771717272055834152378281705972671257005357145478800908373659404991537354153455452961747174765859335819766715637138",
+                "2810310118582126634041133454180705304393079139103252956502404531123692847658283858246402311867775854528543237781718",
+            ],
+        ),
+        (
+            b"abc",
+            [
+                "424958340463073975547762735517193206833255107941790909009827635556634414746056077714431786321247871628515967727334",
+                "3018679803970127877262826393814472528557413504329194740495363852840690589001358162447917674089074634504498585239512",
+                "3621308185128395459888995526527127556614768604472132176060423302734876099689739385100475320409412954617897892887112",
+                "102447784096837908713257069727879782642075240724579670654226801345708452018676587771714457671432122751958633012502",
+            ],
+        ),
+    ];
+    for (msg, coords) in cases.iter() {
+        let fq = |s: &str| Fq::from_str(s).unwrap();
+        let expected = G2Affine::new(
+            Fq2::new(fq(coords[0]), fq(coords[1])),
+            Fq2::new(fq(coords[2]), fq(coords[3])),
+            false,
+        );
+        assert_eq!(
+            hash_to_curve::<G2Affine, Sha256>(msg, dst).unwrap(),
+            expected
+        );
+    }
+}
@@ -1,3 +1,25 @@
 #![allow(unused_imports)]
 use crate::bn254::*;
 std_curve_tests!(Bn254, Fq12);
+
+#[test]
+#[cfg(feature = "pairing")]
+fn test_bilinearity_on_generators() {
+    use algebra_core::{
+        test_rng, Field, One, PairingEngine, PrimeField, ProjectiveCurve, UniformRand,
+    };
+
+    let mut rng = test_rng();
+    let g1 = G1Projective::prime_subgroup_generator();
+    let g2 = G2Projective::prime_subgroup_generator();
+    let a = Fr::rand(&mut rng);
+    let b = Fr::rand(&mut rng);
+
+    let e = Bn254::pairing(g1, g2);
+    assert_ne!(e, Fq12::one());
+    assert_eq!(
+        Bn254::pairing(g1.mul(a), g2.mul(b)),
+        e.pow((a * &b).into_repr())
+    );
+    assert_eq!(Bn254::pairing(g1.mul(a), g2), Bn254::pairing(g1, g2.mul(a)));
+}
@@ -442,6 +442,27 @@ macro_rules! extension_field_tests_bn254 {
             assert_eq!(QuadraticNonResidue, m1.legendre());
         }
 
+        #[test]
+        fn test_fq6_nonresidue() {
+            // NONRESIDUE = 9 + u, which `mul_fp2_by_nonresidue` and the
+            // Frobenius coefficients assume.
+            let nonresidue = Fq2::new(Fq::from(9u64), Fq::one());
+            assert_eq!(Fq6Parameters::NONRESIDUE, nonresidue);
+
+            // v^3 = NONRESIDUE in Fq6.
+            let v = Fq6::new(Fq2::zero(), Fq2::one(), Fq2::zero());
+            assert_eq!(v * &v * &v, Fq6::new(nonresidue, Fq2::zero(), Fq2::zero()));
+
+            let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+            for _ in 0..1000 {
+                let a = Fq2::rand(&mut rng);
+                assert_eq!(
+                    Fq6Parameters::mul_fp2_by_nonresidue(&a),
+                    Fq6Parameters::NONRESIDUE * &a
+                );
+            }
+        }
+
         #[test]
         fn test_fq6_mul_by_1() {
             let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -5,7 +5,9 @@ use algebra_core::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new,
+    hash_to_curve::Elligator2Parameters,
+    impl_scalar_mul_kernel, impl_scalar_mul_parameters,
 };
 
 impl_scalar_mul_kernel!(ed_on_bls12_377, "ed_on_bls12_377", proj, EdwardsProjective);
@@ -92,6 +94,17 @@ impl MontgomeryModelParameters for EdwardsParameters {
     type TEModelParameters = EdwardsParameters;
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 11
+    #[rustfmt::skip]
+    const Z: Fq = field_new!(Fq, BigInteger256([
+        0x19beffffffffff6a,
+        0x761e46b21fffff64,
+        0x565ad035f75edf35,
+        0xf929a91a9d71f63,
+    ]));
+}
+
 /// GENERATOR_X =
 /// 7810607721416582242904415504650443951498042435501746664987470571546413371306
 #[rustfmt::skip]
@@ -5,7 +5,9 @@ use algebra_core::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new,
+    hash_to_curve::Elligator2Parameters,
+    impl_scalar_mul_kernel, impl_scalar_mul_parameters,
 };
 
 #[cfg(test)]
@@ -126,3 +128,14 @@ impl MontgomeryModelParameters for EdwardsParameters {
 
     type TEModelParameters = EdwardsParameters;
 }
+
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 5
+    #[rustfmt::skip]
+    const Z: Fq = field_new!(Fq, BigInteger256([
+        47244640245,
+        7411222800919301131,
+        14736824336091166661,
+        345034914438815205,
+    ]));
+}
@@ -5,7 +5,9 @@ use algebra_core::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new,
+    hash_to_curve::Elligator2Parameters,
+    impl_scalar_mul_kernel, impl_scalar_mul_parameters,
 };
 
 #[cfg(test)]
@@ -111,3 +113,14 @@ impl MontgomeryModelParameters for EdwardsParameters {
 
     type TEModelParameters = EdwardsParameters;
 }
+
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 5
+    #[rustfmt::skip]
+    const Z: Fq = field_new!(Fq, BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ]));
+}
@@ -4,7 +4,9 @@ use crate::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new,
+    hash_to_curve::Elligator2Parameters,
+    impl_scalar_mul_kernel, impl_scalar_mul_parameters,
 };
 
 use crate::ed_on_cp6_782::{fq::Fq, fr::Fr};
@@ -102,6 +104,19 @@ impl MontgomeryModelParameters for EdwardsParameters {
     type TEModelParameters = EdwardsParameters;
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 5
+    #[rustfmt::skip]
+    const Z: Fq = field_new!(Fq, BigInteger([
+        0x88fd3ffffffffd07,
+        0x7f37c04d4ffffe74,
+        0xfe81201ffa68f7bb,
+        0x4e661ca22778db8c,
+        0xba8be6fd148d4f4f,
+        0x114c5a35730b618,
+    ]));
+}
+
 /// GENERATOR_X =
 /// 174701772324485506941690903512423551998294352968833659960042362742684869862495746426366187462669992073196420267127
 #[rustfmt::skip]
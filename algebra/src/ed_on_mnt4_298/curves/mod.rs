@@ -5,7 +5,9 @@ use algebra_core::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new,
+    hash_to_curve::Elligator2Parameters,
+    impl_scalar_mul_kernel, impl_scalar_mul_parameters,
 };
 
 #[cfg(test)]
@@ -116,6 +118,18 @@ impl MontgomeryModelParameters for EdwardsParameters {
     type TEModelParameters = EdwardsParameters;
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 2
+    #[rustfmt::skip]
+    const Z: Fq = field_new!(Fq, BigInteger320([
+        9668935792423536824,
+        3963605303268486929,
+        17923184921962235981,
+        5922375556528893153,
+        3858029504390,
+    ]));
+}
+
 /// GENERATOR_X =
 /// 282406820114868156776872298252698015906762052916420164316497572033519876761239463633892227
 /// Needs to be in the Montgomery residue form in Fq
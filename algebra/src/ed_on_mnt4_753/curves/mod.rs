@@ -5,7 +5,9 @@ use algebra_core::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new,
+    hash_to_curve::Elligator2Parameters,
+    impl_scalar_mul_kernel, impl_scalar_mul_parameters,
 };
 
 #[cfg(test)]
@@ -150,6 +152,25 @@ impl MontgomeryModelParameters for EdwardsParameters {
     type TEModelParameters = EdwardsParameters;
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 11
+    #[rustfmt::skip]
+    const Z: Fq = field_new!(Fq, BigInteger768([
+        5145524327033718740u64,
+        14149824967095184544u64,
+        5159730833497260295u64,
+        3902941467692815387u64,
+        15830098551216085679u64,
+        8665641533746801158u64,
+        17502192300007146323u64,
+        14483698255198590748u64,
+        546300946688995976u64,
+        4331975528992054828u64,
+        5311428878520309260u64,
+        495362057711802u64,
+    ]));
+}
+
 /// GENERATOR_X =
 /// 41126137307536311801428235632419266329480236393691483739251051053325519918069469184425962602019877935619960143044210127218431046103600632347238890180171944971817510488009355627861577881883236134824745174469522277738875418206826
 /// Needs to be in the Montgomery residue form in Fq
@@ -1,12 +1,13 @@
 use crate::mnt4_298::{self, Fq, Fq2, Fr, FQ_ZERO, G1_COEFF_A_NON_RESIDUE};
 use algebra_core::{
-    biginteger::BigInteger320,
+    biginteger::{BigInteger320, BigInteger640},
     curves::{
         mnt4,
         mnt4::MNT4Parameters,
         models::{ModelParameters, SWModelParameters},
+        GLSParameters,
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new, impl_gls_for_sw, impl_scalar_mul_kernel_glv, impl_scalar_mul_parameters, PrimeField,
 };
 
 pub type G2Affine = mnt4::G2Affine<mnt4_298::Parameters>;
@@ -29,7 +30,64 @@ pub const MUL_BY_A_C0: Fq = G1_COEFF_A_NON_RESIDUE;
 #[rustfmt::skip]
 pub const MUL_BY_A_C1: Fq = G1_COEFF_A_NON_RESIDUE;
 
-impl_scalar_mul_kernel!(mnt4_298, "mnt4_298", g2, G2Projective);
+impl_scalar_mul_kernel_glv!(mnt4_298, "mnt4_298", g2, G2Projective);
+
+impl GLSParameters for Parameters {
+    type WideBigInt = BigInteger640;
+    const PSI_COEFF_X: Self::BaseField = field_new!(
+        Fq2,
+        field_new!(
+            Fq,
+            BigInteger320([
+                12702890790846888869,
+                6326265861366186013,
+                364584707886187945,
+                8740893163049517815,
+                2181130330288,
+            ])
+        ),
+        FQ_ZERO,
+    );
+    const PSI_COEFF_Y: Self::BaseField = field_new!(
+        Fq2,
+        field_new!(
+            Fq,
+            BigInteger320([
+                16439849825752526567,
+                14772594681319164557,
+                16175669228740845684,
+                4590896976404796446,
+                3810243174413,
+            ])
+        ),
+        FQ_ZERO,
+    );
+    const LAMBDA: Self::ScalarField = field_new!(
+        Fr,
+        BigInteger320([
+            15808794158912764279,
+            3031486187149225138,
+            13128696291593251034,
+            17477220644030112350,
+            361565494378,
+        ])
+    );
+    /// |round(B1 * R / n)|
+    const Q2: <Self::ScalarField as PrimeField>::BigInt = BigInteger320([
+        11094861449719973513,
+        13041128042425938967,
+        9098947232225,
+        0,
+        0,
+    ]);
+    const B1: <Self::ScalarField as PrimeField>::BigInt =
+        BigInteger320([993502997770534912, 5071219579242586943, 2027349, 0, 0]);
+    const B1_IS_NEG: bool = false;
+    /// |round(B2 * R / n)|
+    const Q1: <Self::ScalarField as PrimeField>::BigInt = BigInteger320([4488100, 0, 0, 0, 0]);
+    const B2: <Self::ScalarField as PrimeField>::BigInt = BigInteger320([1, 0, 0, 0, 0]);
+    const R_BITS: u32 = 320;
+}
 
 impl SWModelParameters for Parameters {
     const COEFF_A: Fq2 = mnt4_298::Parameters::TWIST_COEFF_A;
@@ -86,6 +144,7 @@ impl SWModelParameters for Parameters {
     }
 
     impl_scalar_mul_parameters!(G2Projective);
+    impl_gls_for_sw!();
 }
 
 const G2_GENERATOR_X: Fq2 = field_new!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
@@ -1,3 +1,23 @@
 #![allow(unused_imports)]
 use crate::mnt4_298::*;
 std_curve_tests!(MNT4_298, Fq4);
+
+#[test]
+#[cfg(feature = "curve")]
+fn test_g2_gls_endomorphism() {
+    use algebra_core::curves::GLSParameters;
+
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let a = G2Projective::rand(&mut rng);
+        let expected = a.mul(g2::Parameters::LAMBDA);
+
+        let mut b = a;
+        b.glv_endomorphism_in_place();
+        assert_eq!(b, expected);
+
+        let mut c = a.into_affine();
+        c.glv_endomorphism_in_place();
+        assert_eq!(c, expected.into_affine());
+    }
+}
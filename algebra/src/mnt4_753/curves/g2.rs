@@ -1,12 +1,13 @@
 use crate::mnt4_753::{self, Fq, Fq2, Fr, FQ_ZERO, G1_COEFF_A_NON_RESIDUE};
 use algebra_core::{
-    biginteger::BigInteger768,
+    biginteger::{BigInteger1536, BigInteger768},
     curves::{
         mnt4,
         mnt4::MNT4Parameters,
         models::{ModelParameters, SWModelParameters},
+        GLSParameters,
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new, impl_gls_for_sw, impl_scalar_mul_kernel_glv, impl_scalar_mul_parameters, PrimeField,
 };
 
 pub type G2Affine = mnt4::G2Affine<mnt4_753::Parameters>;
@@ -29,7 +30,106 @@ pub const MUL_BY_A_C0: Fq = G1_COEFF_A_NON_RESIDUE;
 #[rustfmt::skip]
 pub const MUL_BY_A_C1: Fq = G1_COEFF_A_NON_RESIDUE;
 
-impl_scalar_mul_kernel!(mnt4_753, "mnt4_753", g2, G2Projective);
+impl_scalar_mul_kernel_glv!(mnt4_753, "mnt4_753", g2, G2Projective);
+
+impl GLSParameters for Parameters {
+    type WideBigInt = BigInteger1536;
+    const PSI_COEFF_X: Self::BaseField = field_new!(
+        Fq2,
+        field_new!(
+            Fq,
+            BigInteger768([
+                14260497802974073023,
+                5895249896161266456,
+                14682908860938702530,
+                17222385991615618722,
+                14621060510943733448,
+                10594887362868996148,
+                7477357615964975684,
+                12570239403004322603,
+                2180620924574446161,
+                12129628062772479841,
+                8853285699251153944,
+                362282887012814,
+            ])
+        ),
+        FQ_ZERO,
+    );
+    const PSI_COEFF_Y: Self::BaseField = field_new!(
+        Fq2,
+        field_new!(
+            Fq,
+            BigInteger768([
+                2732208433323581659,
+                2172983777736624684,
+                14351170316343013496,
+                6345300643186282385,
+                3197292113538174065,
+                1887663496013421009,
+                16627860175048929982,
+                1842296636815120666,
+                13463717484107308085,
+                721000253033730237,
+                1214767992212094798,
+                163570781165682,
+            ])
+        ),
+        FQ_ZERO,
+    );
+    const LAMBDA: Self::ScalarField = field_new!(
+        Fr,
+        BigInteger768([
+            11879818216415201197,
+            8258852386285936719,
+            2600876381594229500,
+            16910904206231778368,
+            7308594640700111380,
+            1173401117692716593,
+            14902018140612646474,
+            12373740089461563237,
+            17515336182437903918,
+            13916403009236522572,
+            4494326412057551129,
+            341089701464972,
+        ])
+    );
+    /// |round(B1 * R / n)|
+    const Q2: <Self::ScalarField as PrimeField>::BigInt = BigInteger768([
+        13542307675171489708,
+        17126659623516049883,
+        14046920163867796784,
+        17042276215728762240,
+        11321560137318465379,
+        9128610879405689937,
+        192,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+    const B1: <Self::ScalarField as PrimeField>::BigInt = BigInteger768([
+        8824542903220142080,
+        7711082599397206192,
+        8303354903384568230,
+        5874150271971943936,
+        9717849827920685054,
+        95829799234282493,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+    const B1_IS_NEG: bool = true;
+    /// |round(B2 * R / n)|
+    const Q1: <Self::ScalarField as PrimeField>::BigInt =
+        BigInteger768([37054, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    const B2: <Self::ScalarField as PrimeField>::BigInt =
+        BigInteger768([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    const R_BITS: u32 = 768;
+}
 
 impl SWModelParameters for Parameters {
     const COEFF_A: Fq2 = mnt4_753::Parameters::TWIST_COEFF_A;
@@ -107,6 +207,7 @@ impl SWModelParameters for Parameters {
     }
 
     impl_scalar_mul_parameters!(G2Projective);
+    impl_gls_for_sw!();
 }
 
 const G2_GENERATOR_X: Fq2 = field_new!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
@@ -1,12 +1,13 @@
-use crate::mnt6_298::{self, g1, Fq, Fq3, Fr};
+use crate::mnt6_298::{self, g1, Fq, Fq3, Fr, FQ_ZERO};
 use algebra_core::{
-    biginteger::BigInteger320,
+    biginteger::{BigInteger320, BigInteger640},
     curves::{
         mnt6,
         mnt6::MNT6Parameters,
         models::{ModelParameters, SWModelParameters},
+        GLSParameters,
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new, impl_gls_for_sw, impl_scalar_mul_kernel_glv, impl_scalar_mul_parameters, PrimeField,
 };
 
 pub type G2Affine = mnt6::G2Affine<mnt6_298::Parameters>;
@@ -44,7 +45,66 @@ pub const MUL_BY_A_C1: Fq = field_new!(Fq, BigInteger320([
 /// MUL_BY_A_C2 = COEFF_A
 pub const MUL_BY_A_C2: Fq = g1::Parameters::COEFF_A;
 
-impl_scalar_mul_kernel!(mnt6_298, "mnt6_298", g2, G2Projective);
+impl_scalar_mul_kernel_glv!(mnt6_298, "mnt6_298", g2, G2Projective);
+
+impl GLSParameters for Parameters {
+    type WideBigInt = BigInteger640;
+    const PSI_COEFF_X: Self::BaseField = field_new!(
+        Fq3,
+        field_new!(
+            Fq,
+            BigInteger320([
+                15858298261740462139,
+                4154327013322069391,
+                16379268061169908578,
+                8032216682864025148,
+                1611284836009,
+            ])
+        ),
+        FQ_ZERO,
+        FQ_ZERO,
+    );
+    const PSI_COEFF_Y: Self::BaseField = field_new!(
+        Fq3,
+        field_new!(
+            Fq,
+            BigInteger320([
+                17882590928154426277,
+                6901912683734848330,
+                364575608937879866,
+                8740893163049517815,
+                2181130330288,
+            ])
+        ),
+        FQ_ZERO,
+        FQ_ZERO,
+    );
+    const LAMBDA: Self::ScalarField = field_new!(
+        Fr,
+        BigInteger320([
+            14570789831487884005,
+            14591288915988791843,
+            1129565469239445950,
+            3930711207940550457,
+            1567449257816,
+        ])
+    );
+    /// |round(B1 * R / n)|
+    const Q2: <Self::ScalarField as PrimeField>::BigInt = BigInteger320([
+        11094861449719973513,
+        13041128042425938967,
+        9098947232225,
+        0,
+        0,
+    ]);
+    const B1: <Self::ScalarField as PrimeField>::BigInt =
+        BigInteger320([993502997770534913, 5071219579242586943, 2027349, 0, 0]);
+    const B1_IS_NEG: bool = true;
+    /// |round(B2 * R / n)|
+    const Q1: <Self::ScalarField as PrimeField>::BigInt = BigInteger320([4488100, 0, 0, 0, 0]);
+    const B2: <Self::ScalarField as PrimeField>::BigInt = BigInteger320([1, 0, 0, 0, 0]);
+    const R_BITS: u32 = 320;
+}
 
 impl SWModelParameters for Parameters {
     const COEFF_A: Fq3 = mnt6_298::Parameters::TWIST_COEFF_A;
@@ -103,6 +163,7 @@ impl SWModelParameters for Parameters {
     }
 
     impl_scalar_mul_parameters!(G2Projective);
+    impl_gls_for_sw!();
 }
 
 const G2_GENERATOR_X: Fq3 =
@@ -1,12 +1,13 @@
 use crate::mnt6_753::{self, g1, Fq, Fq3, Fr, FQ_ZERO};
 use algebra_core::{
-    biginteger::BigInteger768,
+    biginteger::{BigInteger1536, BigInteger768},
     curves::{
         mnt6,
         mnt6::MNT6Parameters,
         models::{ModelParameters, SWModelParameters},
+        GLSParameters,
     },
-    field_new, impl_scalar_mul_kernel, impl_scalar_mul_parameters,
+    field_new, impl_gls_for_sw, impl_scalar_mul_kernel_glv, impl_scalar_mul_parameters, PrimeField,
 };
 
 pub type G2Affine = mnt6::G2Affine<mnt6_753::Parameters>;
@@ -58,7 +59,108 @@ pub const MUL_BY_A_C1: Fq = field_new!(Fq, BigInteger768([
 /// MUL_BY_A_C2 = COEFF_A
 pub const MUL_BY_A_C2: Fq = g1::Parameters::COEFF_A;
 
-impl_scalar_mul_kernel!(mnt6_753, "mnt6_753", g2, G2Projective);
+impl_scalar_mul_kernel_glv!(mnt6_753, "mnt6_753", g2, G2Projective);
+
+impl GLSParameters for Parameters {
+    type WideBigInt = BigInteger1536;
+    const PSI_COEFF_X: Self::BaseField = field_new!(
+        Fq3,
+        field_new!(
+            Fq,
+            BigInteger768([
+                12973180669431253567,
+                17038664486452692616,
+                11034024317238370177,
+                7712681843988565810,
+                4725787734130647531,
+                2175028350442404679,
+                9323639551697167751,
+                14465264105466053583,
+                8569442212929419360,
+                17553812953652473294,
+                13991744086792172309,
+                48577617831792,
+            ])
+        ),
+        FQ_ZERO,
+        FQ_ZERO,
+    );
+    const PSI_COEFF_Y: Self::BaseField = field_new!(
+        Fq3,
+        field_new!(
+            Fq,
+            BigInteger768([
+                2265581976117350591,
+                18442012872391748519,
+                3807704300793525789,
+                12280644139289115082,
+                10655371227771325282,
+                1346491763263331896,
+                7477357615964975877,
+                12570239403004322603,
+                2180620924574446161,
+                12129628062772479841,
+                8853285699251153944,
+                362282887012814,
+            ])
+        ),
+        FQ_ZERO,
+        FQ_ZERO,
+    );
+    const LAMBDA: Self::ScalarField = field_new!(
+        Fr,
+        BigInteger768([
+            1459423698187052950,
+            1508149919909448791,
+            11515360939504616774,
+            11889719674708737694,
+            459028569778126465,
+            13819214669251002102,
+            18234729667270125228,
+            13101148454635378825,
+            12401146803236536421,
+            467555627053171244,
+            7424432229453123113,
+            292288259267271,
+        ])
+    );
+    /// |round(B1 * R / n)|
+    const Q2: <Self::ScalarField as PrimeField>::BigInt = BigInteger768([
+        13542307675171489708,
+        17126659623516049883,
+        14046920163867796784,
+        17042276215728762240,
+        11321560137318465379,
+        9128610879405689937,
+        192,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+    const B1: <Self::ScalarField as PrimeField>::BigInt = BigInteger768([
+        8824542903220142079,
+        7711082599397206192,
+        8303354903384568230,
+        5874150271971943936,
+        9717849827920685054,
+        95829799234282493,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+    const B1_IS_NEG: bool = false;
+    /// |round(B2 * R / n)|
+    const Q1: <Self::ScalarField as PrimeField>::BigInt =
+        BigInteger768([37054, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    const B2: <Self::ScalarField as PrimeField>::BigInt =
+        BigInteger768([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    const R_BITS: u32 = 768;
+}
 
 impl SWModelParameters for Parameters {
     const COEFF_A: Fq3 = mnt6_753::Parameters::TWIST_COEFF_A;
@@ -156,6 +258,7 @@ impl SWModelParameters for Parameters {
     }
 
     impl_scalar_mul_parameters!(G2Projective);
+    impl_gls_for_sw!();
 }
 
 const G2_GENERATOR_X: Fq3 =
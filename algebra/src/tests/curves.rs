@@ -3,16 +3,19 @@ use algebra_core::{
     batch_bucketed_add, batch_verify_in_subgroup,
     biginteger::BigInteger64,
     curves::{AffineCurve, BatchGroupArithmeticSlice, ProjectiveCurve},
+    encode_to_curve, hash_to_curve,
     io::Cursor,
-    BucketPosition, CanonicalDeserialize, CanonicalSerialize, Field, MontgomeryModelParameters,
-    One, PrimeField, SWFlags, SWModelParameters, SerializationError, TEModelParameters,
-    UniformRand, Vec, VerificationError, Zero,
+    BucketPosition, CanonicalDeserialize, CanonicalSerialize, Elligator2Parameters, Field,
+    HashToField, MapToCurve, MontgomeryModelParameters, One, PrimeField, SWFlags,
+    SWModelParameters, SWUParameters, SerializationError, TEModelParameters, UniformRand, Vec,
+    VerificationError, Zero,
 };
 use rand::{
     distributions::{Distribution, Uniform},
     SeedableRng,
 };
 use rand_xorshift::XorShiftRng;
+use sha2::Sha256;
 
 use std::ops::Neg;
 
@@ -156,6 +159,25 @@ fn glv_multiplication_test<G: ProjectiveCurve>() {
         }
 
         assert_eq!(a, res);
+        assert_eq!(b.into_affine().mul(s), res);
+    }
+}
+
+fn uniform_multiplication_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let a = G::rand(&mut rng);
+    assert!(a.mul_uniform(&G::ScalarField::zero()).is_zero());
+    assert_eq!(a.mul_uniform(&G::ScalarField::one()), a);
+    assert_eq!(a.mul_uniform(&-G::ScalarField::one()), -a);
+
+    for _ in 0..ITERATIONS {
+        let a = G::rand(&mut rng);
+        let s = G::ScalarField::rand(&mut rng);
+
+        let expected = a.mul(s.into_repr());
+        assert_eq!(a.mul_uniform(&s), expected);
+        assert_eq!(a.into_affine().mul_uniform(&s), expected);
     }
 }
 
@@ -679,6 +701,7 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     random_negation_test::<G>();
     random_transformation_test::<G>();
     glv_multiplication_test::<G>();
+    uniform_multiplication_test::<G>();
 }
 
 pub fn batch_affine_test<G: ProjectiveCurve>() {
@@ -948,3 +971,58 @@ pub fn edwards_curve_serialization_test<P: TEModelParameters>() {
         }
     }
 }
+
+fn hash_to_curve_test<G: MapToCurve>(
+    is_on_curve: impl Fn(&G) -> bool,
+    is_in_correct_subgroup: impl Fn(&G) -> bool,
+) where
+    G::BaseField: HashToField,
+{
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let point = G::map_to_curve(G::BaseField::rand(&mut rng));
+        assert!(is_on_curve(&point));
+        let point = point.clear_cofactor();
+        assert!(is_on_curve(&point) && is_in_correct_subgroup(&point));
+    }
+
+    let dst = b"ZEXE-V01-CS02-with-hash-to-curve-test";
+    for msg in [&b""[..], b"abc", b"abcdef0123456789"].iter() {
+        let point = hash_to_curve::<G, Sha256>(msg, dst).unwrap();
+        assert!(!point.is_zero());
+        assert!(is_on_curve(&point) && is_in_correct_subgroup(&point));
+        assert_eq!(point, hash_to_curve::<G, Sha256>(msg, dst).unwrap());
+        assert_ne!(
+            point,
+            hash_to_curve::<G, Sha256>(msg, b"another dst").unwrap()
+        );
+
+        let point = encode_to_curve::<G, Sha256>(msg, dst).unwrap();
+        assert!(is_on_curve(&point) && is_in_correct_subgroup(&point));
+    }
+}
+
+pub fn sw_hash_to_curve_test<P: SWUParameters>()
+where
+    P::BaseField: HashToField,
+{
+    use algebra_core::curves::models::short_weierstrass_jacobian::GroupAffine;
+
+    hash_to_curve_test::<GroupAffine<P>>(
+        |p| p.is_on_curve(),
+        |p| p.is_in_correct_subgroup_assuming_on_curve(),
+    );
+}
+
+pub fn edwards_hash_to_curve_test<P: Elligator2Parameters>()
+where
+    P::BaseField: HashToField,
+{
+    use algebra_core::curves::models::twisted_edwards_extended::GroupAffine;
+
+    hash_to_curve_test::<GroupAffine<P>>(
+        |p| p.is_on_curve(),
+        |p| p.is_in_correct_subgroup_assuming_on_curve(),
+    );
+}
@@ -79,16 +79,36 @@ fn random_multiplication_tests<F: Field, R: Rng>(rng: &mut R) {
 
 fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     assert!(F::zero().inverse().is_none());
+    assert!(F::zero().inverse_uniform().is_none());
 
     for _ in 0..ITERATIONS {
         let mut a = F::rand(rng);
         let b = a.inverse().unwrap(); // probablistically nonzero
+        assert_eq!(a.inverse_uniform().unwrap(), b);
         a *= &b;
 
         assert_eq!(a, F::one());
     }
 }
 
+fn random_conditional_select_tests<F: Field, R: Rng>(rng: &mut R) {
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+
+        assert_eq!(F::conditional_select(&a, &b, false), a);
+        assert_eq!(F::conditional_select(&a, &b, true), b);
+        assert!(a.ct_eq(&a));
+        assert_eq!(a.ct_eq(&b), a == b);
+
+        let (mut c, mut d) = (a, b);
+        F::conditional_swap(&mut c, &mut d, false);
+        assert_eq!((c, d), (a, b));
+        F::conditional_swap(&mut c, &mut d, true);
+        assert_eq!((c, d), (b, a));
+    }
+}
+
 fn random_doubling_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         let mut a = F::rand(rng);
@@ -164,6 +184,7 @@ fn random_field_tests<F: Field>() {
     random_subtraction_tests::<F, _>(&mut rng);
     random_multiplication_tests::<F, _>(&mut rng);
     random_inversion_tests::<F, _>(&mut rng);
+    random_conditional_select_tests::<F, _>(&mut rng);
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
     random_expansion_tests::<F, _>(&mut rng);
@@ -199,10 +220,23 @@ fn random_sqrt_tests<F: SquareRootField>() {
         let b = a.square();
         assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
 
+        let c = b.sqrt_uniform().unwrap();
+        assert!(a == c || a == -c);
+
         let b = b.sqrt().unwrap();
         assert!(a == b || a == -b);
     }
 
+    assert_eq!(F::zero().sqrt_uniform(), Some(F::zero()));
+    let mut c = F::one();
+    for _ in 0..ITERATIONS {
+        match c.sqrt_uniform() {
+            Some(root) => assert_eq!(root.square(), c),
+            None => assert!(c.legendre().is_qnr()),
+        }
+        c += &F::one();
+    }
+
     let mut c = F::one();
     for _ in 0..ITERATIONS {
         let mut b = c.square();
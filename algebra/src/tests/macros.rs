@@ -253,5 +253,11 @@ macro_rules! edwards_curve_tests {
         fn test_montgomery_conversion() {
             montgomery_conversion_test::<EdwardsParameters>();
         }
+
+        #[test]
+        #[cfg(feature = "hash_to_curve")]
+        fn test_hash_to_curve() {
+            edwards_hash_to_curve_test::<EdwardsParameters>();
+        }
     };
 }
@@ -16,9 +16,9 @@ pub use constraints::*;
 use crate::Error;
 
 pub trait CommitmentScheme {
-    type Output: ToBytes + Clone + Default + Eq + Hash + Debug;
-    type Parameters: Clone;
-    type Randomness: Clone + ToBytes + Default + Eq + UniformRand + Debug;
+    type Output: ToBytes + Clone + Default + Eq + Hash + Debug + Sync;
+    type Parameters: Clone + Sync;
+    type Randomness: Clone + ToBytes + Default + Eq + UniformRand + Debug + Sync;
 
     fn setup<R: Rng>(r: &mut R) -> Result<Self::Parameters, Error>;
 
@@ -14,7 +14,7 @@ use algebra_core::curves::{
 pub mod constraints;
 
 pub trait InjectiveMap<C: ProjectiveCurve> {
-    type Output: ToBytes + Clone + Eq + Hash + Default + Debug;
+    type Output: ToBytes + Clone + Eq + Hash + Default + Debug + Sync;
 
     fn injective_map(ge: &C::Affine) -> Result<Self::Output, CryptoError>;
 }
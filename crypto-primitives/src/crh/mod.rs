@@ -5,6 +5,8 @@ use rand::Rng;
 pub mod bowe_hopwood;
 pub mod injective_map;
 pub mod pedersen;
+pub mod poseidon;
+pub mod sha256;
 
 use crate::Error;
 
@@ -16,8 +18,8 @@ pub use constraints::*;
 pub trait FixedLengthCRH {
     const INPUT_SIZE_BITS: usize;
 
-    type Output: ToBytes + Clone + Eq + core::fmt::Debug + Hash + Default;
-    type Parameters: Clone + Default;
+    type Output: ToBytes + Clone + Eq + core::fmt::Debug + Hash + Default + Sync;
+    type Parameters: Clone + Default + Sync;
 
     fn setup<R: Rng>(r: &mut R) -> Result<Self::Parameters, Error>;
     fn evaluate(parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error>;
@@ -0,0 +1,310 @@
+use crate::{
+    crh::{
+        poseidon::{bytes_per_field_element, Parameters, PoseidonDigest, PoseidonRoundParams, CRH},
+        FixedLengthCRHGadget,
+    },
+    Vec,
+};
+use algebra_core::PrimeField;
+use r1cs_core::{Namespace, SynthesisError};
+use r1cs_std::{fields::fp::FpVar, prelude::*};
+
+use core::{borrow::Borrow, marker::PhantomData};
+
+/// The Poseidon parameters inside a circuit. Round constants and the MDS
+/// matrix are always constants, so this does not allocate any variables.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "F: PrimeField"))]
+pub struct CRHParametersVar<F: PrimeField> {
+    parameters: Parameters<F>,
+}
+
+impl<F: PrimeField> CRHParametersVar<F> {
+    /// Applies the Poseidon permutation to `state` in place.
+    #[tracing::instrument(target = "r1cs", skip(self, state))]
+    pub fn permute(&self, state: &mut [FpVar<F>]) -> Result<(), SynthesisError> {
+        let parameters = &self.parameters;
+        assert_eq!(state.len(), parameters.width());
+        for (round, constants) in parameters.round_constants.iter().enumerate() {
+            for (s, c) in state.iter_mut().zip(constants) {
+                *s += *c;
+            }
+
+            if parameters.is_full_round(round) {
+                for s in state.iter_mut() {
+                    *s = s.pow_by_constant([parameters.alpha])?;
+                }
+            } else {
+                state[0] = state[0].pow_by_constant([parameters.alpha])?;
+            }
+
+            let new_state = parameters
+                .mds
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(state.iter())
+                        .fold(FpVar::zero(), |acc, (m, s)| acc + s * *m)
+                })
+                .collect::<Vec<_>>();
+            state.clone_from_slice(&new_state);
+        }
+        Ok(())
+    }
+}
+
+/// The in-circuit counterpart of `PoseidonSponge`.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "F: PrimeField"))]
+pub struct PoseidonSpongeVar<F: PrimeField> {
+    parameters: CRHParametersVar<F>,
+    state: Vec<FpVar<F>>,
+    absorbed: usize,
+}
+
+impl<F: PrimeField> PoseidonSpongeVar<F> {
+    pub fn new(parameters: &CRHParametersVar<F>) -> Self {
+        Self {
+            parameters: parameters.clone(),
+            state: vec![FpVar::zero(); parameters.parameters.width()],
+            absorbed: 0,
+        }
+    }
+
+    #[tracing::instrument(target = "r1cs", skip(self, input))]
+    pub fn absorb(&mut self, input: &[FpVar<F>]) -> Result<(), SynthesisError> {
+        let rate = self.parameters.parameters.rate();
+        for element in input {
+            if self.absorbed == rate {
+                self.parameters.permute(&mut self.state)?;
+                self.absorbed = 0;
+            }
+            self.state[1 + self.absorbed] += element;
+            self.absorbed += 1;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(target = "r1cs", skip(self))]
+    pub fn squeeze(&mut self, num_elements: usize) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        let rate = self.parameters.parameters.rate();
+        let mut output = Vec::with_capacity(num_elements);
+        while output.len() < num_elements {
+            self.parameters.permute(&mut self.state)?;
+            self.absorbed = 0;
+            let remaining = core::cmp::min(rate, num_elements - output.len());
+            output.extend_from_slice(&self.state[1..(1 + remaining)]);
+        }
+        Ok(output)
+    }
+}
+
+pub struct CRHGadget<F: PrimeField, P: PoseidonRoundParams> {
+    #[doc(hidden)]
+    _field: PhantomData<*const F>,
+    #[doc(hidden)]
+    _params: PhantomData<*const P>,
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> CRHGadget<F, P> {
+    /// Hashes a variable-length sequence of field element variables,
+    /// matching `CRH::evaluate_field_elements`.
+    ///
+    /// Unlike `FixedLengthCRHGadget::evaluate`, this does not need to
+    /// decompose the input into bits.
+    #[tracing::instrument(target = "r1cs", skip(parameters, input))]
+    pub fn evaluate_field_elements(
+        parameters: &CRHParametersVar<F>,
+        input: &[FpVar<F>],
+    ) -> Result<FpVar<F>, SynthesisError> {
+        let mut sponge = PoseidonSpongeVar::new(parameters);
+        sponge.state[0] = FpVar::Constant(F::from(input.len() as u64));
+        sponge.absorb(input)?;
+        Ok(sponge.squeeze(1)?.remove(0))
+    }
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> FixedLengthCRHGadget<CRH<F, P>, F> for CRHGadget<F, P> {
+    type OutputVar = FpVar<F>;
+    type ParametersVar = CRHParametersVar<F>;
+
+    #[tracing::instrument(target = "r1cs", skip(parameters, input))]
+    fn evaluate(
+        parameters: &Self::ParametersVar,
+        input: &[UInt8<F>],
+    ) -> Result<Self::OutputVar, SynthesisError> {
+        assert!(input.len() * 8 <= P::INPUT_SIZE_BITS);
+        let mut padded_input = input.to_vec();
+        // Pad the input if it is not the current length.
+        padded_input.resize(P::INPUT_SIZE_BITS / 8, UInt8::constant(0u8));
+
+        Self::evaluate_field_elements(parameters, &pack_bytes(&padded_input)?)
+    }
+}
+
+/// Packs bytes into field elements in the same way as
+/// `ToConstraintField<F> for [u8]`. This is a linear combination of the
+/// input bits, and so costs no constraints.
+fn pack_bytes<F: PrimeField>(input: &[UInt8<F>]) -> Result<Vec<FpVar<F>>, SynthesisError> {
+    input
+        .chunks(bytes_per_field_element::<F>())
+        .map(|chunk| {
+            let mut element = FpVar::zero();
+            let mut coeff = F::one();
+            for bit in chunk.to_bits_le()? {
+                element += FpVar::from(bit) * coeff;
+                coeff.double_in_place();
+            }
+            Ok(element)
+        })
+        .collect()
+}
+
+/// Computes `PoseidonDigest` inside a circuit.
+pub struct PoseidonDigestGadget<F: PrimeField, P: PoseidonRoundParams> {
+    #[doc(hidden)]
+    _field: PhantomData<*const F>,
+    #[doc(hidden)]
+    _params: PhantomData<*const P>,
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> PoseidonDigestGadget<F, P> {
+    /// Returns the 32 little-endian bytes of `PoseidonDigest::digest(input)`.
+    #[tracing::instrument(target = "r1cs", skip(input))]
+    pub fn digest(input: &[UInt8<F>]) -> Result<Vec<UInt8<F>>, SynthesisError> {
+        let parameters = CRHParametersVar {
+            parameters: PoseidonDigest::<F, P>::parameters(),
+        };
+        let mut sponge = PoseidonSpongeVar::new(&parameters);
+        sponge.state[0] = FpVar::Constant(F::from(input.len() as u64));
+        sponge.absorb(&pack_bytes(input)?)?;
+
+        let mut output = sponge.squeeze(1)?.remove(0).to_bytes()?;
+        output.resize(32, UInt8::constant(0u8));
+        Ok(output)
+    }
+}
+
+impl<F: PrimeField> AllocVar<Parameters<F>, F> for CRHParametersVar<F> {
+    #[tracing::instrument(target = "r1cs", skip(_cs, f))]
+    fn new_variable<T: Borrow<Parameters<F>>>(
+        _cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        _mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let parameters = f()?.borrow().clone();
+        Ok(CRHParametersVar { parameters })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::{poseidon, poseidon::constraints::*, FixedLengthCRH, FixedLengthCRHGadget},
+        merkle_tree::{constraints::PathVar, Config, MerkleTree},
+    };
+    use algebra::{ed_on_bls12_381::Fq as Fr, test_rng, UniformRand};
+    use r1cs_core::ConstraintSystem;
+    use rand::RngCore;
+
+    #[derive(Clone)]
+    pub(super) struct PoseidonParams;
+    impl poseidon::PoseidonRoundParams for PoseidonParams {
+        const WIDTH: usize = 3;
+        const FULL_ROUNDS: usize = 8;
+        const PARTIAL_ROUNDS: usize = 57;
+        const ALPHA: u64 = 5;
+        const INPUT_SIZE_BITS: usize = 512;
+    }
+
+    type TestCRH = poseidon::CRH<Fr, PoseidonParams>;
+    type TestCRHGadget = CRHGadget<Fr, PoseidonParams>;
+
+    #[test]
+    fn test_native_equality() {
+        let rng = &mut test_rng();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+
+        let mut input = [0u8; 64];
+        rng.fill_bytes(&mut input);
+        let input_var = UInt8::new_witness_vec(cs.clone(), &input).unwrap();
+
+        let parameters = TestCRH::setup(rng).unwrap();
+        let primitive_result = TestCRH::evaluate(&parameters, &input).unwrap();
+
+        let parameters_var =
+            CRHParametersVar::new_constant(r1cs_core::ns!(cs, "CRH Parameters"), &parameters)
+                .unwrap();
+
+        let result_var = TestCRHGadget::evaluate(&parameters_var, &input_var).unwrap();
+
+        assert_eq!(primitive_result, result_var.value().unwrap());
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_field_element_equality() {
+        let rng = &mut test_rng();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+
+        let input: Vec<Fr> = (0..7).map(|_| Fr::rand(rng)).collect();
+        let input_var = input
+            .iter()
+            .map(|x| FpVar::new_witness(cs.clone(), || Ok(x)).unwrap())
+            .collect::<Vec<_>>();
+
+        let parameters = TestCRH::setup(rng).unwrap();
+        let primitive_result = TestCRH::evaluate_field_elements(&parameters, &input);
+
+        let parameters_var =
+            CRHParametersVar::new_constant(r1cs_core::ns!(cs, "CRH Parameters"), &parameters)
+                .unwrap();
+        let result_var =
+            TestCRHGadget::evaluate_field_elements(&parameters_var, &input_var).unwrap();
+
+        assert_eq!(primitive_result, result_var.value().unwrap());
+        assert!(cs.is_satisfied().unwrap());
+        // 7 elements at rate 2 need 4 permutations, each with 3 constraints
+        // per S-box. The very first S-box acts on the constant capacity
+        // element, and so is free.
+        let sboxes = 3 * 8 + 57;
+        assert_eq!(cs.num_constraints(), 4 * 3 * sboxes - 3);
+    }
+
+    #[test]
+    fn test_merkle_tree_membership() {
+        struct PoseidonMerkleTreeParams;
+        impl Config for PoseidonMerkleTreeParams {
+            const HEIGHT: usize = 8;
+            type H = TestCRH;
+        }
+
+        let rng = &mut test_rng();
+        let parameters = TestCRH::setup(rng).unwrap();
+        let leaves: Vec<[u8; 30]> = (0..10u8).map(|i| [i; 30]).collect();
+        let tree =
+            MerkleTree::<PoseidonMerkleTreeParams>::new(parameters.clone(), &leaves).unwrap();
+        let root = tree.root();
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let parameters_var =
+            CRHParametersVar::new_constant(r1cs_core::ns!(cs, "CRH Parameters"), &parameters)
+                .unwrap();
+        let root_var = FpVar::new_witness(r1cs_core::ns!(cs, "root"), || Ok(root)).unwrap();
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = tree.generate_proof(i, &leaf).unwrap();
+            let path_var =
+                PathVar::<_, TestCRHGadget, _>::new_witness(r1cs_core::ns!(cs, "path"), || {
+                    Ok(&proof)
+                })
+                .unwrap();
+            let leaf_var = UInt8::constant_vec(leaf);
+            path_var
+                .check_membership(&parameters_var, &root_var, leaf_var.as_slice())
+                .unwrap()
+                .enforce_equal(&Boolean::TRUE)
+                .unwrap();
+        }
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
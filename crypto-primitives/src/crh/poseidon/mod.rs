@@ -0,0 +1,449 @@
+use crate::{CryptoError, Error, Vec};
+use core::marker::PhantomData;
+use rand::Rng;
+
+use crate::crh::FixedLengthCRH;
+use algebra_core::{
+    fields::{FpParameters, PrimeField},
+    BigInteger, ToConstraintField,
+};
+use blake2::Blake2s;
+use digest::{
+    generic_array::{typenum::U32, GenericArray},
+    Digest, FixedOutput, Input, Reset,
+};
+
+#[cfg(feature = "r1cs")]
+pub mod constraints;
+
+/// Describes the shape of a Poseidon permutation.
+///
+/// The sponge built on top of the permutation has a capacity of one field
+/// element, so its rate is `WIDTH - 1`.
+pub trait PoseidonRoundParams: Clone {
+    /// Number of field elements in the permutation state.
+    const WIDTH: usize;
+    /// Number of rounds in which the S-box is applied to the entire state.
+    /// These are split evenly before and after the partial rounds.
+    const FULL_ROUNDS: usize;
+    /// Number of rounds in which the S-box is applied to the first state
+    /// element only.
+    const PARTIAL_ROUNDS: usize;
+    /// Exponent of the S-box `x ↦ x^ALPHA`; must be coprime to `p - 1`.
+    const ALPHA: u64;
+    /// Length of the byte inputs accepted by `FixedLengthCRH::evaluate`.
+    const INPUT_SIZE_BITS: usize;
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct Parameters<F: PrimeField> {
+    pub full_rounds: usize,
+    pub partial_rounds: usize,
+    pub alpha: u64,
+    /// Round constants, one vector of `width` elements per round.
+    pub round_constants: Vec<Vec<F>>,
+    /// The `width × width` MDS matrix applied at the end of every round.
+    pub mds: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> Parameters<F> {
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.mds.len()
+    }
+
+    #[inline]
+    pub fn rate(&self) -> usize {
+        self.width() - 1
+    }
+
+    #[inline]
+    fn is_full_round(&self, round: usize) -> bool {
+        let half_full_rounds = self.full_rounds / 2;
+        round < half_full_rounds || round >= half_full_rounds + self.partial_rounds
+    }
+
+    /// Applies the Poseidon permutation to `state` in place.
+    pub fn permute(&self, state: &mut [F]) {
+        assert_eq!(state.len(), self.width());
+        for (round, constants) in self.round_constants.iter().enumerate() {
+            for (s, c) in state.iter_mut().zip(constants) {
+                *s += c;
+            }
+
+            if self.is_full_round(round) {
+                for s in state.iter_mut() {
+                    *s = s.pow([self.alpha]);
+                }
+            } else {
+                state[0] = state[0].pow([self.alpha]);
+            }
+
+            let new_state = self
+                .mds
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(state.iter())
+                        .fold(F::zero(), |acc, (m, s)| acc + *m * s)
+                })
+                .collect::<Vec<_>>();
+            state.copy_from_slice(&new_state);
+        }
+    }
+}
+
+/// A duplex sponge over the Poseidon permutation, with a capacity of one
+/// field element.
+///
+/// Elements are absorbed into the rate portion of the state, and the state is
+/// permuted whenever the rate is full or output is requested.
+#[derive(Clone, Debug)]
+pub struct PoseidonSponge<F: PrimeField> {
+    parameters: Parameters<F>,
+    state: Vec<F>,
+    absorbed: usize,
+}
+
+impl<F: PrimeField> PoseidonSponge<F> {
+    pub fn new(parameters: &Parameters<F>) -> Self {
+        Self {
+            parameters: parameters.clone(),
+            state: vec![F::zero(); parameters.width()],
+            absorbed: 0,
+        }
+    }
+
+    pub fn absorb(&mut self, input: &[F]) {
+        let rate = self.parameters.rate();
+        for element in input {
+            if self.absorbed == rate {
+                self.parameters.permute(&mut self.state);
+                self.absorbed = 0;
+            }
+            self.state[1 + self.absorbed] += element;
+            self.absorbed += 1;
+        }
+    }
+
+    pub fn squeeze(&mut self, num_elements: usize) -> Vec<F> {
+        let rate = self.parameters.rate();
+        let mut output = Vec::with_capacity(num_elements);
+        while output.len() < num_elements {
+            self.parameters.permute(&mut self.state);
+            self.absorbed = 0;
+            let remaining = core::cmp::min(rate, num_elements - output.len());
+            output.extend_from_slice(&self.state[1..(1 + remaining)]);
+        }
+        output
+    }
+}
+
+pub struct CRH<F: PrimeField, P: PoseidonRoundParams> {
+    field: PhantomData<F>,
+    params: PhantomData<P>,
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> CRH<F, P> {
+    pub fn create_round_constants<R: Rng>(rng: &mut R) -> Vec<Vec<F>> {
+        (0..(P::FULL_ROUNDS + P::PARTIAL_ROUNDS))
+            .map(|_| (0..P::WIDTH).map(|_| F::rand(rng)).collect())
+            .collect()
+    }
+
+    /// Creates the Cauchy matrix `M[i][j] = 1 / (x_i + y_j)` with
+    /// `x_i = i` and `y_j = WIDTH + j`, which is MDS since all `x_i` are
+    /// distinct, all `y_j` are distinct, and no `x_i + y_j` is zero.
+    pub fn create_mds() -> Vec<Vec<F>> {
+        (0..P::WIDTH)
+            .map(|i| {
+                (0..P::WIDTH)
+                    .map(|j| F::from((i + j + P::WIDTH) as u64).inverse().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Hashes a variable-length sequence of field elements.
+    ///
+    /// The input length is written into the capacity element before
+    /// absorbing, so that inputs of different lengths are domain-separated.
+    pub fn evaluate_field_elements(parameters: &Parameters<F>, input: &[F]) -> F {
+        let mut sponge = PoseidonSponge::new(parameters);
+        sponge.state[0] = F::from(input.len() as u64);
+        sponge.absorb(input);
+        sponge.squeeze(1)[0]
+    }
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> FixedLengthCRH for CRH<F, P> {
+    const INPUT_SIZE_BITS: usize = P::INPUT_SIZE_BITS;
+    type Output = F;
+    type Parameters = Parameters<F>;
+
+    fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, Error> {
+        if P::WIDTH < 2 || P::FULL_ROUNDS % 2 != 0 {
+            return Err(format!(
+                "Poseidon requires a width of at least 2 and an even number of full rounds, \
+                 got width {} and {} full rounds",
+                P::WIDTH,
+                P::FULL_ROUNDS
+            )
+            .into());
+        }
+        if !alpha_is_valid::<F>(P::ALPHA) {
+            return Err(format!(
+                "Poseidon S-box exponent {} is not a permutation of the field",
+                P::ALPHA
+            )
+            .into());
+        }
+
+        let time = start_timer!(|| format!(
+            "PoseidonCRH::Setup: width {}; {} full and {} partial rounds; x^{}",
+            P::WIDTH,
+            P::FULL_ROUNDS,
+            P::PARTIAL_ROUNDS,
+            P::ALPHA
+        ));
+        let round_constants = Self::create_round_constants(rng);
+        let mds = Self::create_mds();
+        end_timer!(time);
+        Ok(Self::Parameters {
+            full_rounds: P::FULL_ROUNDS,
+            partial_rounds: P::PARTIAL_ROUNDS,
+            alpha: P::ALPHA,
+            round_constants,
+            mds,
+        })
+    }
+
+    fn evaluate(parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
+        let eval_time = start_timer!(|| "PoseidonCRH::Eval");
+
+        if (input.len() * 8) > P::INPUT_SIZE_BITS {
+            return Err(CryptoError::IncorrectInputLength(input.len()).into());
+        }
+
+        // Pad the input to the full length, so that the number of absorbed
+        // field elements is the same for every input.
+        let mut padded_input = input.to_vec();
+        padded_input.resize(P::INPUT_SIZE_BITS / 8, 0u8);
+        let elements: Vec<F> = padded_input.as_slice().to_field_elements()?;
+
+        let result = Self::evaluate_field_elements(parameters, &elements);
+        end_timer!(eval_time);
+        Ok(result)
+    }
+}
+
+/// A `Digest` built on the Poseidon sponge, so that `Schnorr` signatures
+/// whose challenge is derived with it can be verified cheaply in circuits
+/// over `F`.
+///
+/// Since `Digest` requires `Default`, the round constants are derived from
+/// Blake2s instead of being sampled in `setup`. The input bytes are packed
+/// into field elements as by `ToConstraintField<F> for [u8]`, with the byte
+/// length of the input written into the capacity element. The output is the
+/// little-endian encoding of a single squeezed element, so `F` must fit in
+/// 32 bytes.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "F: PrimeField"), Debug(bound = "F: PrimeField"))]
+pub struct PoseidonDigest<F: PrimeField, P: PoseidonRoundParams> {
+    parameters: Parameters<F>,
+    input: Vec<u8>,
+    #[doc(hidden)]
+    _params: PhantomData<P>,
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> PoseidonDigest<F, P> {
+    const PERSONALIZATION: &'static [u8] = b"ZEXE-PoseidonDigest";
+
+    /// Returns the parameters used by the digest. The round constants are
+    /// `Blake2s(PERSONALIZATION || counter)` for consecutive counters,
+    /// skipping outputs that do not encode an element of `F`.
+    pub fn parameters() -> Parameters<F> {
+        assert!(
+            F::BigInt::NUM_LIMBS * 8 <= 32,
+            "PoseidonDigest only supports fields that fit in 32 bytes"
+        );
+        assert!(P::WIDTH >= 2 && P::FULL_ROUNDS % 2 == 0 && alpha_is_valid::<F>(P::ALPHA));
+
+        let mut counter = 0u64;
+        let mut next_constant = || loop {
+            let mut h = Blake2s::new();
+            Digest::input(&mut h, Self::PERSONALIZATION);
+            Digest::input(&mut h, counter.to_le_bytes());
+            let bytes = h.result();
+            counter += 1;
+            if let Some(constant) = F::from_random_bytes(&bytes) {
+                break constant;
+            }
+        };
+        let round_constants = (0..(P::FULL_ROUNDS + P::PARTIAL_ROUNDS))
+            .map(|_| (0..P::WIDTH).map(|_| next_constant()).collect())
+            .collect();
+        Parameters {
+            full_rounds: P::FULL_ROUNDS,
+            partial_rounds: P::PARTIAL_ROUNDS,
+            alpha: P::ALPHA,
+            round_constants,
+            mds: CRH::<F, P>::create_mds(),
+        }
+    }
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> Default for PoseidonDigest<F, P> {
+    fn default() -> Self {
+        Self {
+            parameters: Self::parameters(),
+            input: Vec::new(),
+            _params: PhantomData,
+        }
+    }
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> Input for PoseidonDigest<F, P> {
+    fn input<B: AsRef<[u8]>>(&mut self, data: B) {
+        self.input.extend_from_slice(data.as_ref());
+    }
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> FixedOutput for PoseidonDigest<F, P> {
+    type OutputSize = U32;
+
+    fn fixed_result(self) -> GenericArray<u8, U32> {
+        let elements: Vec<F> = self.input.as_slice().to_field_elements().unwrap();
+        let mut sponge = PoseidonSponge::new(&self.parameters);
+        sponge.state[0] = F::from(self.input.len() as u64);
+        sponge.absorb(&elements);
+
+        let mut output = GenericArray::default();
+        sponge.squeeze(1)[0].write(&mut output[..]).unwrap();
+        output
+    }
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> Reset for PoseidonDigest<F, P> {
+    fn reset(&mut self) {
+        self.input.clear();
+    }
+}
+
+/// Returns the number of bytes packed into each field element by
+/// `ToConstraintField<F> for [u8]`.
+#[inline]
+pub(crate) fn bytes_per_field_element<F: PrimeField>() -> usize {
+    (F::Params::CAPACITY / 8) as usize
+}
+
+/// Checks that `gcd(alpha, p - 1) = 1`, i.e. that `x ↦ x^alpha` is a
+/// bijection on `F`.
+fn alpha_is_valid<F: PrimeField>(alpha: u64) -> bool {
+    fn gcd(mut a: u64, mut b: u64) -> u64 {
+        while b != 0 {
+            let t = a % b;
+            a = b;
+            b = t;
+        }
+        a
+    }
+
+    if alpha < 3 {
+        return false;
+    }
+    // Compute (p - 1) mod alpha from the little-endian limbs of p; since p is
+    // odd, p - 1 only differs from p in its lowest bit.
+    let p_mod_alpha = F::characteristic().iter().rev().fold(0u128, |acc, limb| {
+        ((acc << 64) + *limb as u128) % alpha as u128
+    });
+    let p_minus_one_mod_alpha = (p_mod_alpha + alpha as u128 - 1) % alpha as u128;
+    gcd(alpha, p_minus_one_mod_alpha as u64) == 1
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::{poseidon, FixedLengthCRH},
+        merkle_tree::*,
+    };
+    use algebra::{ed_on_bls12_381::Fq, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[derive(Clone)]
+    pub(super) struct PoseidonParams;
+    impl poseidon::PoseidonRoundParams for PoseidonParams {
+        const WIDTH: usize = 3;
+        const FULL_ROUNDS: usize = 8;
+        const PARTIAL_ROUNDS: usize = 57;
+        const ALPHA: u64 = 5;
+        const INPUT_SIZE_BITS: usize = 512;
+    }
+
+    type H = poseidon::CRH<Fq, PoseidonParams>;
+
+    struct PoseidonMerkleTreeParams;
+    impl Config for PoseidonMerkleTreeParams {
+        const HEIGHT: usize = 8;
+        type H = H;
+    }
+    type PoseidonMerkleTree = MerkleTree<PoseidonMerkleTreeParams>;
+
+    #[test]
+    fn sponge_matches_crh() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let parameters = H::setup(&mut rng).unwrap();
+        let input: Vec<Fq> = (0..5).map(|_| Fq::rand(&mut rng)).collect();
+
+        let mut sponge = poseidon::PoseidonSponge::new(&parameters);
+        sponge.state[0] = Fq::from(input.len() as u64);
+        sponge.absorb(&input[..2]);
+        sponge.absorb(&input[2..]);
+        assert_eq!(
+            sponge.squeeze(1)[0],
+            H::evaluate_field_elements(&parameters, &input)
+        );
+
+        // Hashes of a prefix differ thanks to the length in the capacity.
+        assert_ne!(
+            H::evaluate_field_elements(&parameters, &input[..4]),
+            H::evaluate_field_elements(&parameters, &input)
+        );
+    }
+
+    #[test]
+    fn invalid_alpha_test() {
+        #[derive(Clone)]
+        struct BadParams;
+        impl poseidon::PoseidonRoundParams for BadParams {
+            const WIDTH: usize = 3;
+            const FULL_ROUNDS: usize = 8;
+            const PARTIAL_ROUNDS: usize = 57;
+            // 3 divides p - 1 for the BLS12-381 scalar field.
+            const ALPHA: u64 = 3;
+            const INPUT_SIZE_BITS: usize = 512;
+        }
+
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        assert!(poseidon::CRH::<Fq, BadParams>::setup(&mut rng).is_err());
+    }
+
+    #[test]
+    fn merkle_tree_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let parameters = H::setup(&mut rng).unwrap();
+
+        let mut leaves = Vec::new();
+        for i in 0..20u8 {
+            leaves.push([i; 30]);
+        }
+        let tree = PoseidonMerkleTree::new(parameters.clone(), &leaves).unwrap();
+        let root = tree.root();
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = tree.generate_proof(i, &leaf).unwrap();
+            assert!(proof.verify(&parameters, &root, &leaf).unwrap());
+            assert!(!proof.verify(&parameters, &root, &[255u8; 30]).unwrap());
+        }
+    }
+}
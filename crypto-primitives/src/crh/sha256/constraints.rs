@@ -0,0 +1,78 @@
+use crate::{
+    crh::{sha256::CRH, FixedLengthCRH, FixedLengthCRHGadget},
+    prf::sha256::constraints::{evaluate_sha256, OutputVar},
+};
+use algebra_core::{Field, PrimeField};
+use r1cs_core::{Namespace, SynthesisError};
+use r1cs_std::prelude::*;
+
+use core::borrow::Borrow;
+
+#[derive(Clone)]
+pub struct CRHParametersVar;
+
+pub struct CRHGadget;
+
+impl<F: PrimeField> FixedLengthCRHGadget<CRH, F> for CRHGadget {
+    type OutputVar = OutputVar<F>;
+    type ParametersVar = CRHParametersVar;
+
+    #[tracing::instrument(target = "r1cs", skip(_parameters, input))]
+    fn evaluate(
+        _parameters: &Self::ParametersVar,
+        input: &[UInt8<F>],
+    ) -> Result<Self::OutputVar, SynthesisError> {
+        assert!(input.len() * 8 <= CRH::INPUT_SIZE_BITS);
+        // Pad the input if it is not the current length.
+        let mut padded_input = input.to_vec();
+        padded_input.resize(CRH::INPUT_SIZE_BITS / 8, UInt8::constant(0u8));
+        Ok(OutputVar(evaluate_sha256(&padded_input)?))
+    }
+}
+
+impl<ConstraintF: Field> AllocVar<(), ConstraintF> for CRHParametersVar {
+    #[tracing::instrument(target = "r1cs", skip(_cs, _f))]
+    fn new_variable<T: Borrow<()>>(
+        _cs: impl Into<Namespace<ConstraintF>>,
+        _f: impl FnOnce() -> Result<T, SynthesisError>,
+        _mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        Ok(CRHParametersVar)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::crh::{
+        sha256::{constraints::CRHGadget, CRH},
+        FixedLengthCRH, FixedLengthCRHGadget,
+    };
+    use algebra::{ed_on_bls12_381::Fq as Fr, test_rng};
+    use r1cs_core::ConstraintSystem;
+    use r1cs_std::prelude::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_native_equality() {
+        let rng = &mut test_rng();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+
+        for input_len in [0usize, 20, 32, 63, 64].iter() {
+            let input: Vec<u8> = (0..*input_len).map(|_| rng.gen()).collect();
+            CRH::setup(rng).unwrap();
+            let primitive_result = CRH::evaluate(&(), &input).unwrap();
+
+            let input_var = UInt8::new_witness_vec(r1cs_core::ns!(cs, "input"), &input).unwrap();
+            let parameters_var =
+                <CRHGadget as FixedLengthCRHGadget<CRH, Fr>>::ParametersVar::new_witness(
+                    r1cs_core::ns!(cs, "parameters"),
+                    || Ok(()),
+                )
+                .unwrap();
+            let result_var = CRHGadget::evaluate(&parameters_var, &input_var).unwrap();
+            assert_eq!(primitive_result, result_var.value().unwrap());
+        }
+        assert!(cs.is_satisfied().unwrap());
+        assert!(CRH::evaluate(&(), &[0u8; 65]).is_err());
+    }
+}
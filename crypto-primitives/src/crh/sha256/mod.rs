@@ -0,0 +1,36 @@
+use crate::{crh::FixedLengthCRH, CryptoError, Error, Vec};
+use digest::Digest;
+use rand::Rng;
+use sha2::Sha256;
+
+#[cfg(feature = "r1cs")]
+pub mod constraints;
+
+/// SHA-256 applied to inputs of `INPUT_SIZE_BITS` bits. Shorter inputs are
+/// padded with zero bytes before hashing.
+pub struct CRH;
+
+impl FixedLengthCRH for CRH {
+    const INPUT_SIZE_BITS: usize = 512;
+    type Output = [u8; 32];
+    type Parameters = ();
+
+    fn setup<R: Rng>(_: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(())
+    }
+
+    fn evaluate(_: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
+        let eval_time = start_timer!(|| "Sha256CRH::Eval");
+        if input.len() * 8 > Self::INPUT_SIZE_BITS {
+            return Err(CryptoError::IncorrectInputLength(input.len()).into());
+        }
+        let mut padded_input = Vec::with_capacity(Self::INPUT_SIZE_BITS / 8);
+        padded_input.extend_from_slice(input);
+        padded_input.resize(Self::INPUT_SIZE_BITS / 8, 0u8);
+
+        let mut result = [0u8; 32];
+        result.copy_from_slice(&Sha256::digest(&padded_input));
+        end_timer!(eval_time);
+        Ok(result)
+    }
+}
@@ -34,8 +34,12 @@ pub use self::{
 #[cfg(feature = "r1cs")]
 #[cfg(feature = "r1cs")]
 pub use self::{
-    commitment::CommitmentGadget, crh::FixedLengthCRHGadget, merkle_tree::constraints::PathVar,
-    nizk::NIZKVerifierGadget, prf::PRFGadget, signature::SigRandomizePkGadget,
+    commitment::CommitmentGadget,
+    crh::FixedLengthCRHGadget,
+    merkle_tree::constraints::PathVar,
+    nizk::NIZKVerifierGadget,
+    prf::PRFGadget,
+    signature::{SigRandomizePkGadget, SigVerifyGadget},
 };
 
 pub type Error = Box<dyn algebra_core::Error>;
@@ -0,0 +1,315 @@
+use crate::{
+    merkle_tree::{
+        hash_empty, hash_inner_node, hash_leaf, Config, Digest, Error, Parameters, Path,
+    },
+    Vec,
+};
+use algebra_core::bytes::ToBytes;
+use alloc::collections::BTreeMap;
+
+/// The authentication data of a leaf that an `IncrementalMerkleTree` was
+/// told to witness.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "P: Config"))]
+struct Witness<P: Config> {
+    leaf_hash: Digest<P>,
+    /// The siblings of the nodes on the path from the leaf to the root,
+    /// starting with the sibling of the leaf.
+    siblings: Vec<Digest<P>>,
+}
+
+/// An append-only Merkle tree of height `P::HEIGHT`, padded in the same way
+/// as a `MerkleTree`: the leaves are stored in the smallest subtree that can
+/// hold them, in which empty leaves are filled with the hash of an all-zero
+/// input, and every node above that subtree is hashed together with the hash
+/// of an empty leaf.
+///
+/// Instead of storing every node, the tree only keeps its frontier, i.e. the
+/// rightmost node on every level that is a left child. This suffices to
+/// append a leaf and compute the new root in `O(P::HEIGHT)` hashes. To be
+/// able to prove membership of a leaf, it must be witnessed when it is
+/// appended; the tree then updates its authentication path as further
+/// leaves are appended.
+///
+/// After every append, the root is the same as that of a `MerkleTree` over
+/// the leaves appended so far.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "P: Config"))]
+pub struct IncrementalMerkleTree<P: Config> {
+    parameters: Parameters<P>,
+    /// `empty_hashes[i]` is the root of an empty subtree of height `i + 1`.
+    empty_hashes: Vec<Digest<P>>,
+    /// `frontier[i]` is the rightmost node of level `i` that is a left child,
+    /// if the last leaf appended descends from it.
+    frontier: Vec<Option<Digest<P>>>,
+    witnesses: BTreeMap<usize, Witness<P>>,
+    num_leaves: usize,
+    root: Digest<P>,
+}
+
+impl<P: Config> IncrementalMerkleTree<P> {
+    pub const HEIGHT: u8 = P::HEIGHT as u8;
+
+    /// Creates a tree in which all leaves are empty.
+    pub fn blank(parameters: Parameters<P>) -> Result<Self, crate::Error> {
+        let depth = P::HEIGHT - 1;
+        let mut buffer = [0u8; 128];
+        let mut empty_hashes = Vec::with_capacity(depth + 1);
+        empty_hashes.push(hash_empty::<P::H>(&parameters)?);
+        for i in 0..depth {
+            let empty_hash = &empty_hashes[i];
+            let next = hash_inner_node::<P::H>(&parameters, empty_hash, empty_hash, &mut buffer)?;
+            empty_hashes.push(next);
+        }
+        empty_hashes.pop();
+
+        let empty_leaf = &empty_hashes[0];
+        let mut root = empty_leaf.clone();
+        for _ in 0..depth {
+            root = hash_inner_node::<P::H>(&parameters, &root, empty_leaf, &mut buffer)?;
+        }
+
+        Ok(Self {
+            parameters,
+            empty_hashes,
+            frontier: vec![None; depth],
+            witnesses: BTreeMap::new(),
+            num_leaves: 0,
+            root,
+        })
+    }
+
+    /// Returns the number of leaves appended so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.num_leaves
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.num_leaves == 0
+    }
+
+    #[inline]
+    pub fn root(&self) -> Digest<P> {
+        self.root.clone()
+    }
+
+    #[inline]
+    pub fn parameters(&self) -> &Parameters<P> {
+        &self.parameters
+    }
+
+    /// Appends `leaf` to the tree, and returns its index.
+    pub fn append<L: ToBytes>(&mut self, leaf: &L) -> Result<usize, crate::Error> {
+        self.append_inner(leaf, false)
+    }
+
+    /// Appends `leaf` to the tree, and returns its index. The tree keeps
+    /// track of the authentication path of `leaf`, so that
+    /// `Self::generate_proof` can be called on it later.
+    pub fn append_and_witness<L: ToBytes>(&mut self, leaf: &L) -> Result<usize, crate::Error> {
+        self.append_inner(leaf, true)
+    }
+
+    /// Stops keeping track of the authentication path of the leaf at
+    /// `index`.
+    pub fn forget(&mut self, index: usize) {
+        self.witnesses.remove(&index);
+    }
+
+    fn append_inner<L: ToBytes>(&mut self, leaf: &L, witness: bool) -> Result<usize, crate::Error> {
+        let append_time = start_timer!(|| "IncrementalMerkleTree::Append");
+        let depth = P::HEIGHT - 1;
+        let index = self.num_leaves;
+        if index >> depth != 0 {
+            return Err(Error::TreeFull(index).into());
+        }
+
+        let mut buffer = [0u8; 128];
+        let leaf_hash = hash_leaf::<P::H, _>(&self.parameters, leaf, &mut buffer)?;
+
+        // Compute the ancestors of the new leaf, updating the frontier on the
+        // way. A new leaf can only complete nodes which are left children.
+        // Above the smallest subtree that holds all leaves, nodes are paired
+        // with the hash of an empty leaf, as in `MerkleTree`.
+        let subtree_height = algebra_core::log2(index + 1) as usize;
+        let mut ancestors = Vec::with_capacity(depth + 1);
+        let mut siblings = Vec::with_capacity(depth);
+        ancestors.push(leaf_hash.clone());
+        for level in 0..depth {
+            let current = &ancestors[level];
+            let parent = if (index >> level) & 1 == 0 {
+                self.frontier[level] = Some(current.clone());
+                let sibling = if level < subtree_height {
+                    &self.empty_hashes[level]
+                } else {
+                    &self.empty_hashes[0]
+                };
+                siblings.push(sibling.clone());
+                hash_inner_node::<P::H>(&self.parameters, current, sibling, &mut buffer)?
+            } else {
+                let sibling = self.frontier[level].as_ref().unwrap();
+                siblings.push(sibling.clone());
+                hash_inner_node::<P::H>(&self.parameters, sibling, current, &mut buffer)?
+            };
+            ancestors.push(parent);
+        }
+
+        // The only node on the authentication path of an older leaf `i` that
+        // changes is the sibling at the level where the paths of `i` and
+        // `index` merge.
+        for (&i, witness) in self.witnesses.iter_mut() {
+            let level = algebra_core::log2((i ^ index) + 1) as usize - 1;
+            witness.siblings[level] = ancestors[level].clone();
+        }
+        if witness {
+            self.witnesses.insert(
+                index,
+                Witness {
+                    leaf_hash,
+                    siblings,
+                },
+            );
+        }
+
+        self.root = ancestors.pop().unwrap();
+        self.num_leaves += 1;
+        end_timer!(append_time);
+        Ok(index)
+    }
+
+    /// Returns the authentication path of `leaf`, which must have been
+    /// appended at `index` with `Self::append_and_witness`.
+    pub fn generate_proof<L: ToBytes>(
+        &self,
+        index: usize,
+        leaf: &L,
+    ) -> Result<Path<P>, crate::Error> {
+        let prove_time = start_timer!(|| "IncrementalMerkleTree::GenProof");
+        let witness = self
+            .witnesses
+            .get(&index)
+            .ok_or(Error::LeafNotWitnessed(index))?;
+
+        let mut buffer = [0u8; 128];
+        if hash_leaf::<P::H, _>(&self.parameters, leaf, &mut buffer)? != witness.leaf_hash {
+            return Err(Error::IncorrectLeafIndex(index).into());
+        }
+
+        let mut path = Vec::with_capacity(witness.siblings.len());
+        let mut current = witness.leaf_hash.clone();
+        for (level, sibling) in witness.siblings.iter().enumerate() {
+            let pair = if (index >> level) & 1 == 0 {
+                (current, sibling.clone())
+            } else {
+                (sibling.clone(), current)
+            };
+            current = hash_inner_node::<P::H>(&self.parameters, &pair.0, &pair.1, &mut buffer)?;
+            path.push(pair);
+        }
+        end_timer!(prove_time);
+        Ok(Path { path })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::{pedersen, *},
+        merkle_tree::*,
+    };
+    use algebra::ed_on_bls12_381::EdwardsProjective as JubJub;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[derive(Clone)]
+    pub(super) struct Window4x256;
+    impl pedersen::Window for Window4x256 {
+        const WINDOW_SIZE: usize = 4;
+        const NUM_WINDOWS: usize = 256;
+    }
+
+    type H = pedersen::CRH<JubJub, Window4x256>;
+
+    struct JubJubMerkleTreeParams;
+
+    impl Config for JubJubMerkleTreeParams {
+        const HEIGHT: usize = 5;
+        type H = H;
+    }
+    type JubJubMerkleTree = MerkleTree<JubJubMerkleTreeParams>;
+    type JubJubIncrementalMerkleTree = IncrementalMerkleTree<JubJubMerkleTreeParams>;
+
+    #[test]
+    fn witnesses_are_updated_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let crh_parameters = H::setup(&mut rng).unwrap();
+        let mut tree = JubJubIncrementalMerkleTree::blank(crh_parameters.clone()).unwrap();
+
+        let leaves = (0..16u8).map(|i| [i; 8]).collect::<Vec<_>>();
+        for (i, leaf) in leaves.iter().enumerate() {
+            let index = if i % 3 == 0 {
+                tree.append_and_witness(leaf).unwrap()
+            } else {
+                tree.append(leaf).unwrap()
+            };
+            assert_eq!(index, i);
+
+            let root = tree.root();
+            for (j, leaf) in leaves[..=i].iter().enumerate() {
+                if j % 3 == 0 {
+                    let proof = tree.generate_proof(j, leaf).unwrap();
+                    assert!(proof.verify(&crh_parameters, &root, leaf).unwrap());
+                } else {
+                    assert!(tree.generate_proof(j, leaf).is_err());
+                }
+            }
+        }
+
+        // A full tree has the same root as one built from all its leaves.
+        let full_tree = JubJubMerkleTree::new(crh_parameters, &leaves).unwrap();
+        assert!(tree.root() == full_tree.root());
+        assert!(tree.append(&[0u8; 8]).is_err());
+    }
+
+    #[test]
+    fn roots_match_merkle_tree_test() {
+        let mut rng = XorShiftRng::seed_from_u64(5264358u64);
+        let crh_parameters = H::setup(&mut rng).unwrap();
+        let mut tree = JubJubIncrementalMerkleTree::blank(crh_parameters.clone()).unwrap();
+        let no_leaves: &[[u8; 8]] = &[];
+        let blank_tree = JubJubMerkleTree::new(crh_parameters.clone(), no_leaves).unwrap();
+        assert!(tree.root() == blank_tree.root());
+
+        let leaves = (0..16u8).map(|i| [i; 8]).collect::<Vec<_>>();
+        for (i, leaf) in leaves.iter().enumerate() {
+            tree.append_and_witness(leaf).unwrap();
+            let full_tree = JubJubMerkleTree::new(crh_parameters.clone(), &leaves[..=i]).unwrap();
+            assert!(tree.root() == full_tree.root());
+            for (j, leaf) in leaves[..=i].iter().enumerate() {
+                let path = tree.generate_proof(j, leaf).unwrap().path;
+                let expected = full_tree.generate_proof(j, leaf).unwrap().path;
+                assert!(path == expected);
+            }
+        }
+    }
+
+    #[test]
+    fn wrong_leaf_test() {
+        let mut rng = XorShiftRng::seed_from_u64(13423423u64);
+        let crh_parameters = H::setup(&mut rng).unwrap();
+        let mut tree = JubJubIncrementalMerkleTree::blank(crh_parameters.clone()).unwrap();
+        tree.append_and_witness(&[1u8; 8]).unwrap();
+        tree.append_and_witness(&[2u8; 8]).unwrap();
+
+        assert!(tree.generate_proof(0, &[2u8; 8]).is_err());
+        let proof = tree.generate_proof(1, &[2u8; 8]).unwrap();
+        assert!(!proof
+            .verify(&crh_parameters, &tree.root(), &[3u8; 8])
+            .unwrap());
+
+        tree.forget(1);
+        assert!(tree.generate_proof(1, &[2u8; 8]).is_err());
+    }
+}
@@ -1,10 +1,16 @@
-use crate::{crh::FixedLengthCRH, Vec};
+use crate::{crh::FixedLengthCRH, ToOwned, Vec};
 use algebra_core::bytes::ToBytes;
 use core::fmt;
 
 #[cfg(feature = "r1cs")]
 pub mod constraints;
 
+pub mod incremental;
+pub use incremental::IncrementalMerkleTree;
+
+pub mod sparse;
+pub use sparse::{SparseMerkleTree, SparsePath};
+
 pub trait Config {
     const HEIGHT: usize;
     type H: FixedLengthCRH;
@@ -244,6 +250,11 @@ impl<P: Config> MerkleTree<P> {
 pub enum Error {
     IncorrectLeafIndex(usize),
     IncorrectPathLength(usize),
+    LeafNotWitnessed(usize),
+    TreeFull(usize),
+    KeyAlreadyExists,
+    KeyNotFound,
+    KeyTooLarge,
 }
 
 impl core::fmt::Display for Error {
@@ -251,6 +262,11 @@ impl core::fmt::Display for Error {
         let msg = match self {
             Error::IncorrectLeafIndex(index) => format!("incorrect leaf index: {}", index),
             Error::IncorrectPathLength(len) => format!("incorrect path length: {}", len),
+            Error::LeafNotWitnessed(index) => format!("leaf is not witnessed: {}", index),
+            Error::TreeFull(len) => format!("tree is full: {} leaves", len),
+            Error::KeyAlreadyExists => "key is already in the tree".to_owned(),
+            Error::KeyNotFound => "key is not in the tree".to_owned(),
+            Error::KeyTooLarge => "key does not fit in the tree".to_owned(),
         };
         write!(f, "{}", msg)
     }
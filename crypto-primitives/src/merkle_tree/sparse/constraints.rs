@@ -0,0 +1,221 @@
+use algebra_core::Field;
+use r1cs_core::{Namespace, SynthesisError};
+use r1cs_std::prelude::*;
+
+use crate::{
+    crh::{FixedLengthCRH, FixedLengthCRHGadget},
+    merkle_tree::{constraints::hash_inner_node, sparse::SparsePath, Config},
+    Vec,
+};
+
+use core::borrow::Borrow;
+
+pub struct SparsePathVar<P, HGadget, ConstraintF>
+where
+    P: Config,
+    HGadget: FixedLengthCRHGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    siblings: Vec<HGadget::OutputVar>,
+}
+
+impl<P, CRHGadget, ConstraintF> SparsePathVar<P, CRHGadget, ConstraintF>
+where
+    P: Config,
+    ConstraintF: Field,
+    CRHGadget: FixedLengthCRHGadget<P::H, ConstraintF>,
+{
+    /// Returns whether `key` is in the tree with root `root`, and its value is
+    /// `value`.
+    #[tracing::instrument(target = "r1cs", skip(self, parameters, root, key, value))]
+    pub fn check_membership(
+        &self,
+        parameters: &CRHGadget::ParametersVar,
+        root: &CRHGadget::OutputVar,
+        key: impl ToBytesGadget<ConstraintF>,
+        value: impl ToBytesGadget<ConstraintF>,
+    ) -> Result<Boolean<ConstraintF>, SynthesisError> {
+        let mut leaf_bytes = vec![UInt8::constant(1u8)];
+        leaf_bytes.extend(value.to_bytes()?);
+        let leaf_hash = CRHGadget::evaluate(parameters, &leaf_bytes)?;
+        self.check_leaf(parameters, root, key, leaf_hash)
+    }
+
+    /// Returns whether `key` is not in the tree with root `root`.
+    #[tracing::instrument(target = "r1cs", skip(self, parameters, root, key))]
+    pub fn check_non_membership(
+        &self,
+        parameters: &CRHGadget::ParametersVar,
+        root: &CRHGadget::OutputVar,
+        key: impl ToBytesGadget<ConstraintF>,
+    ) -> Result<Boolean<ConstraintF>, SynthesisError> {
+        let empty_bytes = UInt8::constant_vec(&vec![0u8; P::H::INPUT_SIZE_BITS / 8]);
+        let leaf_hash = CRHGadget::evaluate(parameters, &empty_bytes)?;
+        self.check_leaf(parameters, root, key, leaf_hash)
+    }
+
+    /// Returns whether the leaf of `key` is `leaf_hash`, in the tree with
+    /// root `root`.
+    fn check_leaf(
+        &self,
+        parameters: &CRHGadget::ParametersVar,
+        root: &CRHGadget::OutputVar,
+        key: impl ToBytesGadget<ConstraintF>,
+        leaf_hash: CRHGadget::OutputVar,
+    ) -> Result<Boolean<ConstraintF>, SynthesisError> {
+        assert_eq!(self.siblings.len(), P::HEIGHT - 1);
+        let mut key_bits = Vec::new();
+        for byte in key.to_bytes()? {
+            key_bits.extend(byte.to_bits_le()?);
+        }
+        // The key is only valid if the bits beyond the height of the tree
+        // are unset.
+        let key_is_valid = match key_bits.get(P::HEIGHT - 1..) {
+            Some(high_bits) if !high_bits.is_empty() => Boolean::kary_or(high_bits)?.not(),
+            _ => Boolean::TRUE,
+        };
+        key_bits.resize(P::HEIGHT - 1, Boolean::FALSE);
+
+        let mut current = leaf_hash;
+        for (sibling, is_right) in self.siblings.iter().zip(&key_bits) {
+            let left = is_right.select(sibling, &current)?;
+            let right = is_right.select(&current, sibling)?;
+            current = hash_inner_node::<P::H, CRHGadget, ConstraintF>(parameters, &left, &right)?;
+        }
+
+        key_is_valid.and(&root.is_eq(&current)?)
+    }
+}
+
+impl<P, HGadget, ConstraintF> AllocVar<SparsePath<P>, ConstraintF>
+    for SparsePathVar<P, HGadget, ConstraintF>
+where
+    P: Config,
+    HGadget: FixedLengthCRHGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    fn new_variable<T: Borrow<SparsePath<P>>>(
+        cs: impl Into<Namespace<ConstraintF>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        f().and_then(|val| {
+            let siblings = val
+                .borrow()
+                .siblings
+                .iter()
+                .map(|sibling| {
+                    HGadget::OutputVar::new_variable(
+                        r1cs_core::ns!(cs, "sibling"),
+                        || Ok(sibling),
+                        mode,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(SparsePathVar { siblings })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::{
+            pedersen::{self, constraints::CRHGadget},
+            FixedLengthCRH, FixedLengthCRHGadget,
+        },
+        merkle_tree::*,
+    };
+    use algebra::ed_on_bls12_381::{EdwardsProjective as JubJub, Fq};
+    use r1cs_core::ConstraintSystem;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use super::*;
+    use r1cs_std::{ed_on_bls12_381::EdwardsVar, fields::fp::FpVar};
+
+    #[derive(Clone)]
+    pub(super) struct Window4x256;
+    impl pedersen::Window for Window4x256 {
+        const WINDOW_SIZE: usize = 4;
+        const NUM_WINDOWS: usize = 256;
+    }
+
+    type H = pedersen::CRH<JubJub, Window4x256>;
+    type HG = CRHGadget<JubJub, EdwardsVar, Window4x256>;
+
+    struct JubJubMerkleTreeParams;
+
+    impl Config for JubJubMerkleTreeParams {
+        const HEIGHT: usize = 17;
+        type H = H;
+    }
+
+    type JubJubSparseMerkleTree = SparseMerkleTree<JubJubMerkleTreeParams>;
+
+    /// Checks the proofs of `key` against a tree that contains `3`, `1000`
+    /// and `65535`, each mapping to a value of `[7u8; 16]`.
+    fn check_proof(key: u64, is_member: bool, use_bad_root: bool) -> bool {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let crh_parameters = H::setup(&mut rng).unwrap();
+        let mut tree = JubJubSparseMerkleTree::blank(crh_parameters.clone()).unwrap();
+        for k in [3u64, 1000, 65535].iter() {
+            tree.insert(&Fq::from(*k), &[7u8; 16]).unwrap();
+        }
+        let proof = tree.generate_proof(&Fq::from(key % (1 << 16))).unwrap();
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let root = <HG as FixedLengthCRHGadget<H, _>>::OutputVar::new_witness(
+            r1cs_core::ns!(cs, "root"),
+            || {
+                if use_bad_root {
+                    Ok(<H as FixedLengthCRH>::Output::default())
+                } else {
+                    Ok(tree.root())
+                }
+            },
+        )
+        .unwrap();
+        let crh_parameters = <HG as FixedLengthCRHGadget<H, Fq>>::ParametersVar::new_constant(
+            r1cs_core::ns!(cs, "parameters"),
+            &crh_parameters,
+        )
+        .unwrap();
+        let key = FpVar::new_witness(r1cs_core::ns!(cs, "key"), || Ok(Fq::from(key))).unwrap();
+        let proof =
+            SparsePathVar::<_, HG, _>::new_witness(r1cs_core::ns!(cs, "proof"), || Ok(&proof))
+                .unwrap();
+
+        let result = if is_member {
+            let value = UInt8::new_witness_vec(r1cs_core::ns!(cs, "value"), &[7u8; 16]).unwrap();
+            proof
+                .check_membership(&crh_parameters, &root, &key, value.as_slice())
+                .unwrap()
+        } else {
+            proof
+                .check_non_membership(&crh_parameters, &root, &key)
+                .unwrap()
+        };
+        result.enforce_equal(&Boolean::TRUE).unwrap();
+        cs.is_satisfied().unwrap()
+    }
+
+    #[test]
+    fn membership_test() {
+        assert!(check_proof(1000, true, false));
+        assert!(check_proof(65535, true, false));
+        assert!(!check_proof(1000, true, true));
+        assert!(!check_proof(1000, false, false));
+    }
+
+    #[test]
+    fn non_membership_test() {
+        assert!(check_proof(4, false, false));
+        assert!(!check_proof(4, false, true));
+        assert!(!check_proof(4, true, false));
+        // The proof for `4` must not be usable for `4 + 2^16`.
+        assert!(!check_proof(4 + (1 << 16), false, false));
+    }
+}
@@ -0,0 +1,350 @@
+use crate::{
+    crh::FixedLengthCRH,
+    merkle_tree::{hash_empty, hash_inner_node, Config, Digest, Error, Parameters},
+    Vec,
+};
+use algebra_core::bytes::ToBytes;
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+#[cfg(feature = "r1cs")]
+pub mod constraints;
+
+/// Stores the siblings of the nodes on the path from the leaf of a key to the
+/// root, starting with the sibling of the leaf.
+///
+/// Unlike a `Path`, the position of the leaf is not part of the proof, but is
+/// derived from the key, so that the same proof type can show both that a key
+/// is in the tree and that it is not.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: Config"),
+    Debug(bound = "P: Config, <P::H as FixedLengthCRH>::Output: fmt::Debug")
+)]
+pub struct SparsePath<P: Config> {
+    pub(crate) siblings: Vec<Digest<P>>,
+}
+
+impl<P: Config> SparsePath<P> {
+    /// Checks that `key` is in the tree with root `root_hash`, and that its
+    /// value is `value`.
+    pub fn verify_membership<K: ToBytes, V: ToBytes>(
+        &self,
+        parameters: &Parameters<P>,
+        root_hash: &Digest<P>,
+        key: &K,
+        value: &V,
+    ) -> Result<bool, crate::Error> {
+        let mut buffer = [0u8; 128];
+        let leaf_hash = hash_occupied_leaf::<P::H, _>(parameters, value, &mut buffer)?;
+        self.verify(parameters, root_hash, key, leaf_hash)
+    }
+
+    /// Checks that `key` is not in the tree with root `root_hash`.
+    pub fn verify_non_membership<K: ToBytes>(
+        &self,
+        parameters: &Parameters<P>,
+        root_hash: &Digest<P>,
+        key: &K,
+    ) -> Result<bool, crate::Error> {
+        let leaf_hash = hash_empty::<P::H>(parameters)?;
+        self.verify(parameters, root_hash, key, leaf_hash)
+    }
+
+    fn verify<K: ToBytes>(
+        &self,
+        parameters: &Parameters<P>,
+        root_hash: &Digest<P>,
+        key: &K,
+        leaf_hash: Digest<P>,
+    ) -> Result<bool, crate::Error> {
+        if self.siblings.len() != P::HEIGHT - 1 {
+            return Ok(false);
+        }
+        let key_bits = match key_to_bits::<P, _>(key) {
+            Ok(bits) => bits,
+            Err(_) => return Ok(false),
+        };
+
+        let mut buffer = [0u8; 128];
+        let mut current = leaf_hash;
+        for (sibling, is_right) in self.siblings.iter().zip(key_bits) {
+            current = if is_right {
+                hash_inner_node::<P::H>(parameters, sibling, &current, &mut buffer)?
+            } else {
+                hash_inner_node::<P::H>(parameters, &current, sibling, &mut buffer)?
+            };
+        }
+        Ok(&current == root_hash)
+    }
+}
+
+/// A Merkle tree of height `P::HEIGHT` that maps keys to values.
+///
+/// The little-endian bits of a key determine the position of its leaf, so
+/// keys must be smaller than `2^(P::HEIGHT - 1)`. A key can be any type that
+/// implements `ToBytes`, such as a field element or a byte string. Only the
+/// nodes above non-empty leaves are stored; all other nodes are roots of
+/// empty subtrees, whose hashes are cached per level.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "P: Config"))]
+pub struct SparseMerkleTree<P: Config> {
+    parameters: Parameters<P>,
+    /// `empty_hashes[i]` is the root of an empty subtree of height `i + 1`.
+    empty_hashes: Vec<Digest<P>>,
+    /// Maps the bits of a key above level `i` to the node on level `i` that
+    /// is an ancestor of the leaf of that key.
+    nodes: BTreeMap<Vec<bool>, Digest<P>>,
+}
+
+impl<P: Config> SparseMerkleTree<P> {
+    pub const HEIGHT: u8 = P::HEIGHT as u8;
+
+    /// Creates a tree that contains no keys.
+    pub fn blank(parameters: Parameters<P>) -> Result<Self, crate::Error> {
+        let mut buffer = [0u8; 128];
+        let mut empty_hashes = Vec::with_capacity(P::HEIGHT);
+        empty_hashes.push(hash_empty::<P::H>(&parameters)?);
+        for i in 1..P::HEIGHT {
+            let empty_hash = &empty_hashes[i - 1];
+            let next = hash_inner_node::<P::H>(&parameters, empty_hash, empty_hash, &mut buffer)?;
+            empty_hashes.push(next);
+        }
+        Ok(Self {
+            parameters,
+            empty_hashes,
+            nodes: BTreeMap::new(),
+        })
+    }
+
+    #[inline]
+    pub fn root(&self) -> Digest<P> {
+        self.node(&[], P::HEIGHT - 1)
+    }
+
+    #[inline]
+    pub fn parameters(&self) -> &Parameters<P> {
+        &self.parameters
+    }
+
+    /// Returns whether `key` is in the tree.
+    pub fn contains<K: ToBytes>(&self, key: &K) -> Result<bool, crate::Error> {
+        let key_bits = key_to_bits::<P, _>(key)?;
+        Ok(self.nodes.contains_key(&key_bits))
+    }
+
+    /// Adds `key` to the tree with value `value`. Fails if `key` is already
+    /// in the tree.
+    pub fn insert<K: ToBytes, V: ToBytes>(
+        &mut self,
+        key: &K,
+        value: &V,
+    ) -> Result<(), crate::Error> {
+        let key_bits = key_to_bits::<P, _>(key)?;
+        if self.nodes.contains_key(&key_bits) {
+            return Err(Error::KeyAlreadyExists.into());
+        }
+        self.set(key_bits, value)
+    }
+
+    /// Changes the value of `key` to `value`. Fails if `key` is not in the
+    /// tree.
+    pub fn update<K: ToBytes, V: ToBytes>(
+        &mut self,
+        key: &K,
+        value: &V,
+    ) -> Result<(), crate::Error> {
+        let key_bits = key_to_bits::<P, _>(key)?;
+        if !self.nodes.contains_key(&key_bits) {
+            return Err(Error::KeyNotFound.into());
+        }
+        self.set(key_bits, value)
+    }
+
+    fn set<V: ToBytes>(&mut self, key_bits: Vec<bool>, value: &V) -> Result<(), crate::Error> {
+        let update_time = start_timer!(|| "SparseMerkleTree::Update");
+        let mut buffer = [0u8; 128];
+        let mut current = hash_occupied_leaf::<P::H, _>(&self.parameters, value, &mut buffer)?;
+        self.nodes.insert(key_bits.clone(), current.clone());
+
+        for level in 0..P::HEIGHT - 1 {
+            let sibling = self.sibling(&key_bits[level..], level);
+            current = if key_bits[level] {
+                hash_inner_node::<P::H>(&self.parameters, &sibling, &current, &mut buffer)?
+            } else {
+                hash_inner_node::<P::H>(&self.parameters, &current, &sibling, &mut buffer)?
+            };
+            self.nodes
+                .insert(key_bits[level + 1..].to_vec(), current.clone());
+        }
+        end_timer!(update_time);
+        Ok(())
+    }
+
+    /// Returns the proof that `key` is or is not in the tree, depending on
+    /// whether it was inserted.
+    pub fn generate_proof<K: ToBytes>(&self, key: &K) -> Result<SparsePath<P>, crate::Error> {
+        let prove_time = start_timer!(|| "SparseMerkleTree::GenProof");
+        let key_bits = key_to_bits::<P, _>(key)?;
+        let siblings = (0..P::HEIGHT - 1)
+            .map(|level| self.sibling(&key_bits[level..], level))
+            .collect();
+        end_timer!(prove_time);
+        Ok(SparsePath { siblings })
+    }
+
+    /// Returns the node on level `level` whose index is given by the bits
+    /// `index`.
+    fn node(&self, index: &[bool], level: usize) -> Digest<P> {
+        self.nodes
+            .get(index)
+            .unwrap_or(&self.empty_hashes[level])
+            .clone()
+    }
+
+    fn sibling(&self, index: &[bool], level: usize) -> Digest<P> {
+        let mut sibling_index = index.to_vec();
+        sibling_index[0] = !sibling_index[0];
+        self.node(&sibling_index, level)
+    }
+}
+
+/// Returns the first `P::HEIGHT - 1` little-endian bits of `key`. Fails if
+/// any of the remaining bits are set.
+fn key_to_bits<P: Config, K: ToBytes>(key: &K) -> Result<Vec<bool>, crate::Error> {
+    let depth = P::HEIGHT - 1;
+    let mut bits = algebra_core::to_bytes![key]?
+        .into_iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+        .collect::<Vec<_>>();
+    if bits.iter().skip(depth).any(|b| *b) {
+        return Err(Error::KeyTooLarge.into());
+    }
+    bits.resize(depth, false);
+    Ok(bits)
+}
+
+/// Returns the hash of a leaf that contains `value`. The input to the hash
+/// starts with a one byte, so that it differs from that of an empty leaf.
+fn hash_occupied_leaf<H: FixedLengthCRH, V: ToBytes>(
+    parameters: &H::Parameters,
+    value: &V,
+    buffer: &mut [u8],
+) -> Result<H::Output, crate::Error> {
+    let bytes = algebra_core::to_bytes![1u8, value]?;
+    buffer.iter_mut().for_each(|b| *b = 0);
+    buffer.iter_mut().zip(&bytes).for_each(|(b, v_b)| *b = *v_b);
+    H::evaluate(parameters, &buffer[..(H::INPUT_SIZE_BITS / 8)])
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::{pedersen, *},
+        merkle_tree::*,
+    };
+    use algebra::ed_on_bls12_381::{EdwardsProjective as JubJub, Fq};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[derive(Clone)]
+    pub(super) struct Window4x256;
+    impl pedersen::Window for Window4x256 {
+        const WINDOW_SIZE: usize = 4;
+        const NUM_WINDOWS: usize = 256;
+    }
+
+    type H = pedersen::CRH<JubJub, Window4x256>;
+
+    struct JubJubMerkleTreeParams;
+
+    impl Config for JubJubMerkleTreeParams {
+        const HEIGHT: usize = 17;
+        type H = H;
+    }
+    type JubJubSparseMerkleTree = SparseMerkleTree<JubJubMerkleTreeParams>;
+
+    #[test]
+    fn membership_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let crh_parameters = H::setup(&mut rng).unwrap();
+        let mut tree = JubJubSparseMerkleTree::blank(crh_parameters.clone()).unwrap();
+
+        let keys = [0u16, 1, 2, 1000, 65535];
+        for (i, key) in keys.iter().enumerate() {
+            tree.insert(key, &[i as u8; 16]).unwrap();
+        }
+        assert!(tree.insert(&keys[0], &[0u8; 16]).is_err());
+
+        let root = tree.root();
+        for (i, key) in keys.iter().enumerate() {
+            assert!(tree.contains(key).unwrap());
+            let proof = tree.generate_proof(key).unwrap();
+            assert!(proof
+                .verify_membership(&crh_parameters, &root, key, &[i as u8; 16])
+                .unwrap());
+            assert!(!proof
+                .verify_membership(&crh_parameters, &root, key, &[i as u8 + 1; 16])
+                .unwrap());
+            assert!(!proof
+                .verify_non_membership(&crh_parameters, &root, key)
+                .unwrap());
+        }
+
+        // Keys are bound to their position in the tree.
+        let proof = tree.generate_proof(&keys[1]).unwrap();
+        assert!(!proof
+            .verify_membership(&crh_parameters, &root, &keys[0], &[1u8; 16])
+            .unwrap());
+    }
+
+    #[test]
+    fn non_membership_test() {
+        let mut rng = XorShiftRng::seed_from_u64(13423423u64);
+        let crh_parameters = H::setup(&mut rng).unwrap();
+        let mut tree = JubJubSparseMerkleTree::blank(crh_parameters.clone()).unwrap();
+
+        for key in [3u16, 4, 9000].iter() {
+            tree.insert(key, &[0u8; 16]).unwrap();
+        }
+
+        let root = tree.root();
+        for key in [0u16, 2, 5, 8999, 65535].iter() {
+            assert!(!tree.contains(key).unwrap());
+            let proof = tree.generate_proof(key).unwrap();
+            assert!(proof
+                .verify_non_membership(&crh_parameters, &root, key)
+                .unwrap());
+            assert!(!proof
+                .verify_membership(&crh_parameters, &root, key, &[0u8; 16])
+                .unwrap());
+        }
+
+        // Keys with bits beyond the height of the tree are rejected.
+        assert!(tree.generate_proof(&Fq::from(1u64 << 16)).is_err());
+        assert!(tree.insert(&65536u32, &[0u8; 16]).is_err());
+    }
+
+    #[test]
+    fn update_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let crh_parameters = H::setup(&mut rng).unwrap();
+        let mut tree = JubJubSparseMerkleTree::blank(crh_parameters.clone()).unwrap();
+        let empty_root = tree.root();
+
+        assert!(tree.update(&7u16, &[1u8; 16]).is_err());
+        tree.insert(&7u16, &[1u8; 16]).unwrap();
+        let old_root = tree.root();
+        tree.update(&7u16, &[2u8; 16]).unwrap();
+        let new_root = tree.root();
+        assert!(old_root != new_root && new_root != empty_root);
+
+        let proof = tree.generate_proof(&7u16).unwrap();
+        assert!(proof
+            .verify_membership(&crh_parameters, &new_root, &7u16, &[2u8; 16])
+            .unwrap());
+        assert!(!proof
+            .verify_membership(&crh_parameters, &new_root, &7u16, &[1u8; 16])
+            .unwrap());
+    }
+}
@@ -11,6 +11,9 @@ pub use constraints::*;
 pub mod blake2s;
 pub use self::blake2s::*;
 
+pub mod sha256;
+pub use self::sha256::*;
+
 pub trait PRF {
     type Input: FromBytes + Default;
     type Output: ToBytes + Eq + Clone + Debug + Default + Hash;
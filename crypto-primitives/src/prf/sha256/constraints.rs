@@ -0,0 +1,385 @@
+use algebra_core::PrimeField;
+use r1cs_core::{ConstraintSystemRef, Namespace, SynthesisError};
+
+use crate::{prf::PRFGadget, Vec};
+use r1cs_std::prelude::*;
+
+use core::borrow::Borrow;
+
+// FIPS 180-4, Section 4.2.2: the first 32 bits of the fractional parts of the
+// cube roots of the first 64 primes.
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+// FIPS 180-4, Section 5.3.3: the first 32 bits of the fractional parts of the
+// square roots of the first 8 primes.
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Returns `(x >>> r0) ^ (x >>> r1) ^ (x >>> r2)`.
+fn big_sigma<F: PrimeField>(x: &UInt32<F>, r: [usize; 3]) -> Result<UInt32<F>, SynthesisError> {
+    x.rotr(r[0]).xor(&x.rotr(r[1]))?.xor(&x.rotr(r[2]))
+}
+
+/// Returns `(x >>> r0) ^ (x >>> r1) ^ (x >> s)`.
+fn small_sigma<F: PrimeField>(
+    x: &UInt32<F>,
+    r: [usize; 2],
+    s: usize,
+) -> Result<UInt32<F>, SynthesisError> {
+    x.rotr(r[0]).xor(&x.rotr(r[1]))?.xor(&x.shr(s))
+}
+
+/// Applies `f` to the corresponding bits of `x`, `y` and `z`.
+fn bitwise<F: PrimeField>(
+    x: &UInt32<F>,
+    y: &UInt32<F>,
+    z: &UInt32<F>,
+    f: impl Fn(&Boolean<F>, &Boolean<F>, &Boolean<F>) -> Result<Boolean<F>, SynthesisError>,
+) -> Result<UInt32<F>, SynthesisError> {
+    let bits = x
+        .to_bits_le()
+        .iter()
+        .zip(&y.to_bits_le())
+        .zip(&z.to_bits_le())
+        .map(|((x, y), z)| f(x, y, z))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(UInt32::from_bits_le(&bits))
+}
+
+// FIPS 180-4, Section 6.2.2: applies the compression function to the
+// 16-word message block `block`, and adds the result to `state`.
+fn sha256_compression<F: PrimeField>(
+    state: &mut [UInt32<F>],
+    block: &[UInt32<F>],
+) -> Result<(), SynthesisError> {
+    assert_eq!(state.len(), 8);
+    assert_eq!(block.len(), 16);
+
+    // Prepare the message schedule.
+    let mut w = block.to_vec();
+    for t in 16..64 {
+        let s0 = small_sigma(&w[t - 15], [7, 18], 3)?;
+        let s1 = small_sigma(&w[t - 2], [17, 19], 10)?;
+        w.push(UInt32::addmany(&[
+            s1,
+            w[t - 7].clone(),
+            s0,
+            w[t - 16].clone(),
+        ])?);
+    }
+
+    let mut a = state[0].clone();
+    let mut b = state[1].clone();
+    let mut c = state[2].clone();
+    let mut d = state[3].clone();
+    let mut e = state[4].clone();
+    let mut f = state[5].clone();
+    let mut g = state[6].clone();
+    let mut h = state[7].clone();
+
+    for t in 0..64 {
+        let s1 = big_sigma(&e, [6, 11, 25])?;
+        let ch = bitwise(&e, &f, &g, Boolean::sha256_ch)?;
+        let s0 = big_sigma(&a, [2, 13, 22])?;
+        let maj = bitwise(&a, &b, &c, Boolean::sha256_maj)?;
+        let k = UInt32::constant(K[t]);
+
+        // T1 = h + Σ1(e) + Ch(e, f, g) + K[t] + W[t]
+        // e' = d + T1 and a' = T1 + Σ0(a) + Maj(a, b, c) are each computed
+        // with a single addition, rather than by first allocating T1.
+        let t1 = [h, s1, ch, k, w[t].clone()];
+        let new_e = UInt32::addmany(&[&[d][..], &t1[..]].concat())?;
+        let new_a = UInt32::addmany(&[&t1[..], &[s0, maj][..]].concat())?;
+
+        h = g;
+        g = f;
+        f = e;
+        e = new_e;
+        d = c;
+        c = b;
+        b = a;
+        a = new_a;
+    }
+
+    for (s, v) in state.iter_mut().zip(&[a, b, c, d, e, f, g, h]) {
+        *s = UInt32::addmany(&[s.clone(), v.clone()])?;
+    }
+    Ok(())
+}
+
+/// Computes the SHA-256 digest of `input`.
+pub fn evaluate_sha256<F: PrimeField>(input: &[UInt8<F>]) -> Result<Vec<UInt8<F>>, SynthesisError> {
+    // Pad the message with a one bit, zeros, and its big-endian length in
+    // bits, so that its length is a multiple of 512 bits.
+    let mut padded = input.to_vec();
+    padded.push(UInt8::constant(0x80));
+    while padded.len() % 64 != 56 {
+        padded.push(UInt8::constant(0));
+    }
+    padded.extend(UInt8::constant_vec(
+        &((input.len() as u64) * 8).to_be_bytes(),
+    ));
+
+    // Words are read in big-endian byte order.
+    let words = padded
+        .chunks(4)
+        .map(|bytes| {
+            let bits = bytes
+                .iter()
+                .rev()
+                .flat_map(|b| b.to_bits_le().unwrap())
+                .collect::<Vec<_>>();
+            UInt32::from_bits_le(&bits)
+        })
+        .collect::<Vec<_>>();
+
+    let mut state = IV
+        .iter()
+        .map(|iv| UInt32::constant(*iv))
+        .collect::<Vec<_>>();
+    for block in words.chunks(16) {
+        sha256_compression(&mut state, block)?;
+    }
+
+    Ok(state
+        .iter()
+        .flat_map(|word| word.to_bytes().unwrap().into_iter().rev())
+        .collect())
+}
+
+use crate::prf::Sha256;
+
+pub struct Sha256Gadget;
+
+#[derive(Clone, Debug)]
+pub struct OutputVar<ConstraintF: PrimeField>(pub Vec<UInt8<ConstraintF>>);
+
+impl<ConstraintF: PrimeField> EqGadget<ConstraintF> for OutputVar<ConstraintF> {
+    #[tracing::instrument(target = "r1cs")]
+    fn is_eq(&self, other: &Self) -> Result<Boolean<ConstraintF>, SynthesisError> {
+        self.0.is_eq(&other.0)
+    }
+
+    /// If `should_enforce == true`, enforce that `self` and `other` are equal;
+    /// else, enforce a vacuously true statement.
+    #[tracing::instrument(target = "r1cs")]
+    fn conditional_enforce_equal(
+        &self,
+        other: &Self,
+        should_enforce: &Boolean<ConstraintF>,
+    ) -> Result<(), SynthesisError> {
+        self.0.conditional_enforce_equal(&other.0, should_enforce)
+    }
+
+    /// If `should_enforce == true`, enforce that `self` and `other` are not
+    /// equal; else, enforce a vacuously true statement.
+    #[tracing::instrument(target = "r1cs")]
+    fn conditional_enforce_not_equal(
+        &self,
+        other: &Self,
+        should_enforce: &Boolean<ConstraintF>,
+    ) -> Result<(), SynthesisError> {
+        self.0
+            .as_slice()
+            .conditional_enforce_not_equal(other.0.as_slice(), should_enforce)
+    }
+}
+
+impl<ConstraintF: PrimeField> ToBytesGadget<ConstraintF> for OutputVar<ConstraintF> {
+    #[inline]
+    fn to_bytes(&self) -> Result<Vec<UInt8<ConstraintF>>, SynthesisError> {
+        Ok(self.0.clone())
+    }
+}
+
+impl<ConstraintF: PrimeField> CondSelectGadget<ConstraintF> for OutputVar<ConstraintF> {
+    #[tracing::instrument(target = "r1cs")]
+    fn conditionally_select(
+        cond: &Boolean<ConstraintF>,
+        true_value: &Self,
+        false_value: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let bits = true_value
+            .0
+            .iter()
+            .zip(&false_value.0)
+            .map(|(t, f)| {
+                let bits = t
+                    .to_bits_le()?
+                    .iter()
+                    .zip(f.to_bits_le()?)
+                    .map(|(t, f)| cond.select(t, &f))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(UInt8::from_bits_le(&bits))
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+        Ok(Self(bits))
+    }
+}
+
+impl<ConstraintF: PrimeField> AllocVar<[u8; 32], ConstraintF> for OutputVar<ConstraintF> {
+    #[tracing::instrument(target = "r1cs", skip(cs, f))]
+    fn new_variable<T: Borrow<[u8; 32]>>(
+        cs: impl Into<Namespace<ConstraintF>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let bytes = f().map(|b| *b.borrow()).unwrap_or([0u8; 32]);
+        match mode {
+            AllocationMode::Constant => Ok(Self(UInt8::constant_vec(&bytes))),
+            AllocationMode::Input => UInt8::new_input_vec(cs, &bytes).map(Self),
+            AllocationMode::Witness => UInt8::new_witness_vec(cs, &bytes).map(Self),
+        }
+    }
+}
+
+impl<F: PrimeField> R1CSVar<F> for OutputVar<F> {
+    type Value = [u8; 32];
+
+    fn cs(&self) -> ConstraintSystemRef<F> {
+        self.0.cs()
+    }
+
+    fn value(&self) -> Result<Self::Value, SynthesisError> {
+        let mut value = [0u8; 32];
+        for (val_i, self_i) in value.iter_mut().zip(&self.0) {
+            *val_i = self_i.value()?;
+        }
+        Ok(value)
+    }
+}
+
+impl<F: PrimeField> PRFGadget<Sha256, F> for Sha256Gadget {
+    type OutputVar = OutputVar<F>;
+
+    #[tracing::instrument(target = "r1cs", skip(cs))]
+    fn new_seed(cs: impl Into<Namespace<F>>, seed: &[u8; 32]) -> Vec<UInt8<F>> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        UInt8::new_witness_vec(r1cs_core::ns!(cs, "New Sha256 seed"), seed).unwrap()
+    }
+
+    #[tracing::instrument(target = "r1cs", skip(seed, input))]
+    fn evaluate(seed: &[UInt8<F>], input: &[UInt8<F>]) -> Result<Self::OutputVar, SynthesisError> {
+        assert_eq!(seed.len(), 32);
+        let input: Vec<_> = seed.iter().chain(input).cloned().collect();
+        Ok(OutputVar(evaluate_sha256(&input)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use algebra::ed_on_bls12_381::Fq as Fr;
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    use crate::prf::sha256::{constraints::evaluate_sha256, Sha256 as S256PRF};
+    use digest::Digest;
+    use r1cs_core::ConstraintSystem;
+    use sha2::Sha256;
+
+    use super::Sha256Gadget;
+    use r1cs_std::prelude::*;
+
+    #[test]
+    fn test_sha256_constraints() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let input = UInt8::new_witness_vec(r1cs_core::ns!(cs, "input"), &[0xffu8; 55]).unwrap();
+        evaluate_sha256(&input).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(cs.num_constraints(), 26311);
+    }
+
+    #[test]
+    fn test_sha256_constant_constraints() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let input: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+        let result = evaluate_sha256::<Fr>(&UInt8::constant_vec(&input)).unwrap();
+        assert_eq!(cs.num_constraints(), 0);
+        assert_eq!(result.value().unwrap(), Sha256::digest(&input).to_vec());
+    }
+
+    #[test]
+    fn test_sha256_prf() {
+        use crate::prf::{PRFGadget, PRF};
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let cs = ConstraintSystem::<Fr>::new_ref();
+
+        let mut seed = [0u8; 32];
+        rng.fill(&mut seed);
+
+        let mut input = [0u8; 32];
+        rng.fill(&mut input);
+
+        let seed_var = Sha256Gadget::new_seed(cs.clone(), &seed);
+        let input_var =
+            UInt8::new_witness_vec(r1cs_core::ns!(cs, "declare_input"), &input).unwrap();
+        let out = S256PRF::evaluate(&seed, &input).unwrap();
+        let actual_out_var = <Sha256Gadget as PRFGadget<_, Fr>>::OutputVar::new_witness(
+            r1cs_core::ns!(cs, "declare_output"),
+            || Ok(out),
+        )
+        .unwrap();
+
+        let output_var = Sha256Gadget::evaluate(&seed_var, &input_var).unwrap();
+        output_var.enforce_equal(&actual_out_var).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_sha256() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        for input_len in (0..70).chain((70..200).filter(|a| a % 13 == 0)) {
+            let data: Vec<u8> = (0..input_len).map(|_| rng.gen()).collect();
+            let hash_result = Sha256::digest(&data);
+
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let input = UInt8::new_witness_vec(r1cs_core::ns!(cs, "input"), &data).unwrap();
+            let r = evaluate_sha256(&input).unwrap();
+
+            assert!(cs.is_satisfied().unwrap());
+            assert_eq!(r.value().unwrap(), hash_result.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_sha256_vectors() {
+        // Test vectors from FIPS 180-2, Appendix B.
+        let vectors: [(&[u8], &str); 2] = [
+            (
+                b"abc",
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+            ),
+        ];
+        for (input, expected) in vectors.iter() {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let input = UInt8::new_witness_vec(r1cs_core::ns!(cs, "input"), input).unwrap();
+            let r = evaluate_sha256(&input).unwrap();
+            assert!(cs.is_satisfied().unwrap());
+            let digest: String = r
+                .value()
+                .unwrap()
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect();
+            assert_eq!(&digest, expected);
+        }
+    }
+}
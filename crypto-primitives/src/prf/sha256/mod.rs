@@ -0,0 +1,28 @@
+use digest::Digest;
+use sha2::Sha256 as S256;
+
+use super::PRF;
+use crate::CryptoError;
+
+#[cfg(feature = "r1cs")]
+pub mod constraints;
+
+#[derive(Clone)]
+pub struct Sha256;
+
+impl PRF for Sha256 {
+    type Input = [u8; 32];
+    type Output = [u8; 32];
+    type Seed = [u8; 32];
+
+    fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Result<Self::Output, CryptoError> {
+        let eval_time = start_timer!(|| "Sha256::Eval");
+        let mut h = S256::new();
+        h.input(seed.as_ref());
+        h.input(input.as_ref());
+        let mut result = [0u8; 32];
+        result.copy_from_slice(&h.result());
+        end_timer!(eval_time);
+        Ok(result)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    signature::bls::{BLSVariant, MinPk, MinSig},
+    Vec,
+};
+use algebra_core::{AffineCurve, HashToField, MapToCurve, PairingEngine, ProjectiveCurve};
+use r1cs_core::SynthesisError;
+use r1cs_std::prelude::*;
+
+use core::marker::PhantomData;
+
+/// The R1CS counterpart of [`BLSVariant`], for circuits over the base field
+/// of `E` (e.g. BLS12-377 inside BW6-761).
+pub trait BLSVariantVar<E: PairingEngine, P: PairingVar<E>>: BLSVariant<E> {
+    type PublicKeyVar: CurveVar<<Self::PublicKeyGroup as AffineCurve>::Projective, E::Fq>;
+
+    type SignatureVar: CurveVar<<Self::SignatureGroup as AffineCurve>::Projective, E::Fq>;
+
+    /// Computes the product of the pairings of the given public key and
+    /// signature group variables.
+    fn product_of_pairings(
+        pairs: &[(Self::PublicKeyVar, Self::SignatureVar)],
+    ) -> Result<P::GTVar, SynthesisError>;
+}
+
+impl<E, P> BLSVariantVar<E, P> for MinPk
+where
+    E: PairingEngine,
+    P: PairingVar<E>,
+    E::G2Affine: MapToCurve,
+    E::Fqe: HashToField,
+{
+    type PublicKeyVar = P::G1Var;
+    type SignatureVar = P::G2Var;
+
+    fn product_of_pairings(pairs: &[(P::G1Var, P::G2Var)]) -> Result<P::GTVar, SynthesisError> {
+        let mut g1 = Vec::with_capacity(pairs.len());
+        let mut g2 = Vec::with_capacity(pairs.len());
+        for (pk, sig) in pairs {
+            g1.push(P::prepare_g1(pk)?);
+            g2.push(P::prepare_g2(sig)?);
+        }
+        P::product_of_pairings(&g1, &g2)
+    }
+}
+
+impl<E, P> BLSVariantVar<E, P> for MinSig
+where
+    E: PairingEngine,
+    P: PairingVar<E>,
+    E::G1Affine: MapToCurve,
+    E::Fq: HashToField,
+{
+    type PublicKeyVar = P::G2Var;
+    type SignatureVar = P::G1Var;
+
+    fn product_of_pairings(pairs: &[(P::G2Var, P::G1Var)]) -> Result<P::GTVar, SynthesisError> {
+        let mut g1 = Vec::with_capacity(pairs.len());
+        let mut g2 = Vec::with_capacity(pairs.len());
+        for (pk, sig) in pairs {
+            g1.push(P::prepare_g1(sig)?);
+            g2.push(P::prepare_g2(pk)?);
+        }
+        P::product_of_pairings(&g1, &g2)
+    }
+}
+
+/// Verifies BLS signatures inside a circuit over the base field of `E`.
+///
+/// Hashing a message to the curve needs SHA-256 and a square root, so it is
+/// done outside the circuit: callers allocate `BLS::hash_message(message)`
+/// and pass it in as `message_hash`. A circuit that must be bound to the
+/// message itself should expose the hash as a public input.
+pub struct BLSVerifyGadget<E: PairingEngine, V: BLSVariantVar<E, P>, P: PairingVar<E>> {
+    _engine: PhantomData<E>,
+    _variant: PhantomData<V>,
+    _pairing_gadget: PhantomData<P>,
+}
+
+impl<E, V, P> BLSVerifyGadget<E, V, P>
+where
+    E: PairingEngine,
+    V: BLSVariantVar<E, P>,
+    P: PairingVar<E>,
+{
+    /// Outputs a `Boolean` that is `true` iff `signature` is a valid
+    /// signature on the message hashed to `message_hash` under `public_key`.
+    ///
+    /// `signature` must be allocated as a witness, which checks that it lies
+    /// in the prime order subgroup. Public keys and message hashes allocated
+    /// as inputs skip that check, so the verifier must check them natively.
+    #[tracing::instrument(target = "r1cs", skip(public_key, message_hash, signature))]
+    pub fn verify(
+        public_key: &V::PublicKeyVar,
+        message_hash: &V::SignatureVar,
+        signature: &V::SignatureVar,
+    ) -> Result<Boolean<E::Fq>, SynthesisError> {
+        let generator = <V::PublicKeyGroup as AffineCurve>::Projective::prime_subgroup_generator();
+        // The pairing gadgets cannot take the identity, so a zero public key
+        // is replaced by the generator and the result is forced to `false`.
+        let pk_is_zero = public_key.is_zero()?;
+        let public_key = pk_is_zero.select(&V::PublicKeyVar::constant(generator), public_key)?;
+        let product = <V as BLSVariantVar<E, P>>::product_of_pairings(&[
+            (public_key, message_hash.clone()),
+            (V::PublicKeyVar::constant(-generator), signature.clone()),
+        ])?;
+        pk_is_zero.not().and(&product.is_one()?)
+    }
+
+    /// Outputs a `Boolean` that is `true` iff `signature` is a valid
+    /// aggregate signature on the message hashed to `message_hash` under
+    /// `public_keys`.
+    ///
+    /// As with `BLS::fast_aggregate_verify`, every public key must come with
+    /// a valid proof of possession.
+    #[tracing::instrument(target = "r1cs", skip(public_keys, message_hash, signature))]
+    pub fn fast_aggregate_verify(
+        public_keys: &[V::PublicKeyVar],
+        message_hash: &V::SignatureVar,
+        signature: &V::SignatureVar,
+    ) -> Result<Boolean<E::Fq>, SynthesisError> {
+        let mut aggregate_pk = V::PublicKeyVar::zero();
+        for pk in public_keys {
+            aggregate_pk += pk;
+        }
+        Self::verify(&aggregate_pk, message_hash, signature)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::signature::{
+        bls::{constraints::*, Parameters, BLS},
+        SignatureScheme,
+    };
+    use algebra::{bls12_377::Fq, test_rng, Bls12_377, Zero};
+    use r1cs_core::ConstraintSystem;
+    use r1cs_std::bls12_377::{G1Var, G2Var, PairingVar};
+
+    type TestSignature = BLS<Bls12_377, MinSig>;
+    type TestVerifyGadget = BLSVerifyGadget<Bls12_377, MinSig, PairingVar>;
+
+    fn verify_in_circuit(num_signers: usize, message: &[u8], signed_message: &[u8]) -> bool {
+        let rng = &mut test_rng();
+        let parameters = Parameters::default();
+        let mut pks = Vec::new();
+        let mut signatures = Vec::new();
+        for _ in 0..num_signers {
+            let (pk, sk) = TestSignature::keygen(&parameters, rng).unwrap();
+            pks.push(pk);
+            signatures.push(TestSignature::sign(&parameters, &sk, signed_message, rng).unwrap());
+        }
+        let signature = TestSignature::aggregate_signatures(&signatures).unwrap();
+        let native_result =
+            TestSignature::fast_aggregate_verify(&parameters, &pks, message, &signature).unwrap();
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let pk_vars = pks
+            .iter()
+            .map(|pk| G2Var::new_input(r1cs_core::ns!(cs, "pk"), || Ok(*pk)).unwrap())
+            .collect::<Vec<_>>();
+        let message_hash = TestSignature::hash_message(&parameters, message).unwrap();
+        let message_hash_var =
+            G1Var::new_input(r1cs_core::ns!(cs, "message hash"), || Ok(message_hash)).unwrap();
+        let signature_var =
+            G1Var::new_witness(r1cs_core::ns!(cs, "signature"), || Ok(signature)).unwrap();
+        let result = if num_signers == 1 {
+            TestVerifyGadget::verify(&pk_vars[0], &message_hash_var, &signature_var)
+        } else {
+            TestVerifyGadget::fast_aggregate_verify(&pk_vars, &message_hash_var, &signature_var)
+        }
+        .unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(result.value().unwrap(), native_result);
+        result.value().unwrap()
+    }
+
+    #[test]
+    fn bls_verify_test() {
+        let message = "Hi, I am a BLS signature!".as_bytes();
+        let bad_message = "Bye, I am a BLS signature!".as_bytes();
+        assert!(verify_in_circuit(1, message, message));
+        assert!(!verify_in_circuit(1, bad_message, message));
+    }
+
+    #[test]
+    fn bls_fast_aggregate_verify_test() {
+        let message = "Hi, we are BLS signatures!".as_bytes();
+        assert!(verify_in_circuit(3, message, message));
+    }
+
+    #[test]
+    fn bls_zero_public_key_test() {
+        let rng = &mut test_rng();
+        let parameters = Parameters::default();
+        let (_, sk) = TestSignature::keygen(&parameters, rng).unwrap();
+        let signature = TestSignature::sign(&parameters, &sk, b"message", rng).unwrap();
+        let message_hash = TestSignature::hash_message(&parameters, b"message").unwrap();
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let pk_var = G2Var::new_input(r1cs_core::ns!(cs, "pk"), || {
+            Ok(algebra::bls12_377::G2Affine::zero())
+        })
+        .unwrap();
+        let message_hash_var =
+            G1Var::new_input(r1cs_core::ns!(cs, "message hash"), || Ok(message_hash)).unwrap();
+        let signature_var =
+            G1Var::new_witness(r1cs_core::ns!(cs, "signature"), || Ok(signature)).unwrap();
+        let result = TestVerifyGadget::verify(&pk_var, &message_hash_var, &signature_var).unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+        assert!(!result.value().unwrap());
+    }
+}
@@ -0,0 +1,502 @@
+//! BLS signatures over a pairing-friendly curve, following the IETF BLS
+//! signature draft (<https://datatracker.ietf.org/doc/draft-irtf-cfrg-bls-signature/>).
+//!
+//! A signature on `m` under the secret key `sk` is `sk * H(m)`, where `H`
+//! hashes to the signature group with [`hash_to_curve`]. Public keys and
+//! signatures live in different source groups of the pairing; [`MinPk`]
+//! puts public keys in `G1` and [`MinSig`] puts signatures in `G1`.
+//!
+//! Signatures on the same message can be aggregated and checked against the
+//! aggregate of the public keys with [`BLS::fast_aggregate_verify`]. This is
+//! only secure if every public key comes with a valid proof of possession
+//! (see [`BLS::prove_possession`]), which rules out rogue-key attacks.
+//!
+//! Messages are signed as in the draft, but proofs of possession hash the
+//! public key in zexe's own [`ToBytes`] encoding rather than the draft's
+//! compressed encoding, so they do not interoperate with other
+//! implementations.
+use crate::{CryptoError, Error, SignatureScheme, Vec};
+use algebra_core::{
+    bytes::ToBytes,
+    hash_to_curve,
+    io::{Result as IoResult, Write},
+    to_bytes, AffineCurve, HashToField, MapToCurve, One, PairingEngine, ProjectiveCurve,
+    UniformRand, Zero,
+};
+use core::marker::PhantomData;
+use digest::{BlockInput, Digest};
+use rand::Rng;
+use sha2::Sha256;
+
+#[cfg(feature = "r1cs")]
+pub mod constraints;
+
+/// Selects the source groups of the pairing that hold public keys and
+/// signatures.
+pub trait BLSVariant<E: PairingEngine>: 'static + Send + Sync {
+    /// The group of public keys.
+    type PublicKeyGroup: AffineCurve<ScalarField = E::Fr>;
+
+    /// The group of signatures, which messages are hashed to.
+    type SignatureGroup: AffineCurve<ScalarField = E::Fr>;
+
+    /// Hashes `message` to the signature group with the domain separation
+    /// tag `dst`.
+    fn hash_to_signature_group<D: Digest + BlockInput>(
+        message: &[u8],
+        dst: &[u8],
+    ) -> Result<Self::SignatureGroup, Error>;
+
+    /// Computes the product of the pairings of the given public key and
+    /// signature group elements.
+    fn product_of_pairings(pairs: &[(Self::PublicKeyGroup, Self::SignatureGroup)]) -> E::Fqk;
+}
+
+/// Public keys in `G1` and signatures in `G2`. Public keys are small, which
+/// suits aggregation of many keys.
+pub struct MinPk;
+
+/// Public keys in `G2` and signatures in `G1`. Signatures are small, and
+/// hashing to `G1` is cheaper.
+pub struct MinSig;
+
+impl<E: PairingEngine> BLSVariant<E> for MinPk
+where
+    E::G2Affine: MapToCurve,
+    E::Fqe: HashToField,
+{
+    type PublicKeyGroup = E::G1Affine;
+    type SignatureGroup = E::G2Affine;
+
+    fn hash_to_signature_group<D: Digest + BlockInput>(
+        message: &[u8],
+        dst: &[u8],
+    ) -> Result<Self::SignatureGroup, Error> {
+        Ok(hash_to_curve::<E::G2Affine, D>(message, dst)?)
+    }
+
+    fn product_of_pairings(pairs: &[(E::G1Affine, E::G2Affine)]) -> E::Fqk {
+        let prepared = pairs
+            .iter()
+            .map(|(pk, sig)| ((*pk).into(), (*sig).into()))
+            .collect::<Vec<_>>();
+        E::product_of_pairings(&prepared)
+    }
+}
+
+impl<E: PairingEngine> BLSVariant<E> for MinSig
+where
+    E::G1Affine: MapToCurve,
+    E::Fq: HashToField,
+{
+    type PublicKeyGroup = E::G2Affine;
+    type SignatureGroup = E::G1Affine;
+
+    fn hash_to_signature_group<D: Digest + BlockInput>(
+        message: &[u8],
+        dst: &[u8],
+    ) -> Result<Self::SignatureGroup, Error> {
+        Ok(hash_to_curve::<E::G1Affine, D>(message, dst)?)
+    }
+
+    fn product_of_pairings(pairs: &[(E::G2Affine, E::G1Affine)]) -> E::Fqk {
+        let prepared = pairs
+            .iter()
+            .map(|(pk, sig)| ((*sig).into(), (*pk).into()))
+            .collect::<Vec<_>>();
+        E::product_of_pairings(&prepared)
+    }
+}
+
+/// The BLS signature scheme over `E`, with the groups chosen by `V` and
+/// messages hashed with `D`.
+pub struct BLS<E: PairingEngine, V: BLSVariant<E>, D = Sha256> {
+    _engine: PhantomData<E>,
+    _variant: PhantomData<V>,
+    _hash: PhantomData<D>,
+}
+
+/// The domain separation tags used to hash messages and public keys.
+///
+/// To sign messages as in the IETF ciphersuites over BLS12-381, set `dst` to
+/// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` with [`MinPk`], or the same
+/// tag with `G1` in place of `G2` with [`MinSig`]. Proofs of possession are
+/// not the IETF ones, so `pop_dst` should not reuse an IETF `BLS_POP_` tag.
+#[derive(Clone, Debug)]
+pub struct Parameters {
+    /// The tag used to hash messages.
+    pub dst: Vec<u8>,
+    /// The tag used to hash public keys for proofs of possession.
+    pub pop_dst: Vec<u8>,
+}
+
+impl Parameters {
+    pub fn new(dst: &[u8], pop_dst: &[u8]) -> Self {
+        Self {
+            dst: dst.to_vec(),
+            pop_dst: pop_dst.to_vec(),
+        }
+    }
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self::new(b"BLS_SIG_ZEXE_POP_", b"BLS_POP_ZEXE_POP_")
+    }
+}
+
+pub type PublicKey<E, V> = <V as BLSVariant<E>>::PublicKeyGroup;
+
+pub type Signature<E, V> = <V as BLSVariant<E>>::SignatureGroup;
+
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Default(bound = ""), Debug(bound = ""))]
+pub struct SecretKey<E: PairingEngine>(pub E::Fr);
+
+impl<E: PairingEngine> ToBytes for SecretKey<E> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl<E, V, D> BLS<E, V, D>
+where
+    E: PairingEngine,
+    V: BLSVariant<E>,
+    D: Digest + BlockInput + Send + Sync,
+{
+    /// Hashes `message` to the point that is signed in its place.
+    pub fn hash_message(parameters: &Parameters, message: &[u8]) -> Result<Signature<E, V>, Error> {
+        V::hash_to_signature_group::<D>(message, &parameters.dst)
+    }
+
+    /// Sums `signatures` into a single signature. The result is valid for
+    /// the aggregate of the public keys if all signatures are on the same
+    /// message, and for [`Self::aggregate_verify`] otherwise.
+    pub fn aggregate_signatures(signatures: &[Signature<E, V>]) -> Result<Signature<E, V>, Error> {
+        if signatures.is_empty() {
+            return Err(CryptoError::IncorrectInputLength(0).into());
+        }
+        Ok(signatures
+            .iter()
+            .map(|sig| sig.into_projective())
+            .sum::<<Signature<E, V> as AffineCurve>::Projective>()
+            .into_affine())
+    }
+
+    /// Sums `public_keys` into a single public key.
+    pub fn aggregate_public_keys(
+        public_keys: &[PublicKey<E, V>],
+    ) -> Result<PublicKey<E, V>, Error> {
+        if public_keys.is_empty() {
+            return Err(CryptoError::IncorrectInputLength(0).into());
+        }
+        Ok(public_keys
+            .iter()
+            .map(|pk| pk.into_projective())
+            .sum::<<PublicKey<E, V> as AffineCurve>::Projective>()
+            .into_affine())
+    }
+
+    /// Signs the public key of `sk` under `parameters.pop_dst`, proving
+    /// knowledge of `sk`. The public key is hashed in its [`ToBytes`]
+    /// encoding, not the compressed encoding of the IETF draft.
+    pub fn prove_possession(
+        parameters: &Parameters,
+        sk: &SecretKey<E>,
+    ) -> Result<Signature<E, V>, Error> {
+        let public_key: PublicKey<E, V> = V::PublicKeyGroup::prime_subgroup_generator()
+            .mul_uniform(&sk.0)
+            .into();
+        let hash = V::hash_to_signature_group::<D>(&to_bytes![public_key]?, &parameters.pop_dst)?;
+        Ok(hash.mul_uniform(&sk.0).into())
+    }
+
+    /// Checks a proof of possession produced by [`Self::prove_possession`].
+    pub fn verify_possession(
+        parameters: &Parameters,
+        pk: &PublicKey<E, V>,
+        proof: &Signature<E, V>,
+    ) -> Result<bool, Error> {
+        let hash = V::hash_to_signature_group::<D>(&to_bytes![pk]?, &parameters.pop_dst)?;
+        Ok(Self::core_verify(&[*pk], &[hash], proof))
+    }
+
+    /// Verifies an aggregate signature on a single message, given the public
+    /// keys of the signers.
+    ///
+    /// Every public key must have been checked with
+    /// [`Self::verify_possession`]; otherwise an attacker can choose a key
+    /// that cancels out the others.
+    pub fn fast_aggregate_verify(
+        parameters: &Parameters,
+        public_keys: &[PublicKey<E, V>],
+        message: &[u8],
+        signature: &Signature<E, V>,
+    ) -> Result<bool, Error> {
+        if public_keys.is_empty() {
+            return Ok(false);
+        }
+        let aggregate_pk = Self::aggregate_public_keys(public_keys)?;
+        Self::verify(parameters, &aggregate_pk, message, signature)
+    }
+
+    /// Verifies an aggregate of signatures by the holders of `public_keys` on
+    /// the corresponding `messages`.
+    pub fn aggregate_verify(
+        parameters: &Parameters,
+        public_keys: &[PublicKey<E, V>],
+        messages: &[&[u8]],
+        signature: &Signature<E, V>,
+    ) -> Result<bool, Error> {
+        if public_keys.len() != messages.len() {
+            return Err(CryptoError::IncorrectInputLength(messages.len()).into());
+        }
+        if public_keys.is_empty() {
+            return Ok(false);
+        }
+        let hashes = messages
+            .iter()
+            .map(|message| Self::hash_message(parameters, message))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::core_verify(public_keys, &hashes, signature))
+    }
+
+    /// Verifies a batch of independent signatures with a single product of
+    /// pairings. Each equation is scaled by a random 128-bit scalar, so a
+    /// batch with an invalid signature is accepted with probability at most
+    /// `2^-128`.
+    pub fn batch_verify<R: Rng>(
+        parameters: &Parameters,
+        public_keys: &[PublicKey<E, V>],
+        messages: &[&[u8]],
+        signatures: &[Signature<E, V>],
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        if public_keys.len() != messages.len() || signatures.len() != messages.len() {
+            return Err(CryptoError::IncorrectInputLength(messages.len()).into());
+        }
+        if !public_keys.iter().all(is_valid_public_key)
+            || !signatures.iter().all(is_in_prime_order_subgroup)
+        {
+            return Ok(false);
+        }
+        let mut scaled_public_keys = Vec::with_capacity(public_keys.len());
+        let mut hashes = Vec::with_capacity(messages.len());
+        let mut combined_signature = <Signature<E, V> as AffineCurve>::Projective::zero();
+        for ((pk, message), signature) in public_keys.iter().zip(messages).zip(signatures) {
+            let r = E::Fr::from(rng.gen::<u128>());
+            scaled_public_keys.push(pk.mul(r).into_affine());
+            hashes.push(Self::hash_message(parameters, message)?);
+            combined_signature += &signature.mul(r);
+        }
+        Ok(Self::pairing_check(
+            &scaled_public_keys,
+            &hashes,
+            &combined_signature.into_affine(),
+        ))
+    }
+
+    /// Checks that the public keys and the signature are valid, and that
+    /// `prod_i e(pk_i, h_i) == e(g, signature)`.
+    fn core_verify(
+        public_keys: &[PublicKey<E, V>],
+        hashes: &[Signature<E, V>],
+        signature: &Signature<E, V>,
+    ) -> bool {
+        is_in_prime_order_subgroup(signature)
+            && public_keys.iter().all(is_valid_public_key)
+            && Self::pairing_check(public_keys, hashes, signature)
+    }
+
+    /// Checks that `prod_i e(pk_i, h_i) == e(g, signature)`, where `g` is the
+    /// generator of the public key group.
+    fn pairing_check(
+        public_keys: &[PublicKey<E, V>],
+        hashes: &[Signature<E, V>],
+        signature: &Signature<E, V>,
+    ) -> bool {
+        let mut pairs = public_keys
+            .iter()
+            .copied()
+            .zip(hashes.iter().copied())
+            .collect::<Vec<_>>();
+        pairs.push((-V::PublicKeyGroup::prime_subgroup_generator(), *signature));
+        V::product_of_pairings(&pairs).is_one()
+    }
+}
+
+impl<E, V, D> SignatureScheme for BLS<E, V, D>
+where
+    E: PairingEngine,
+    V: BLSVariant<E>,
+    D: Digest + BlockInput + Send + Sync,
+{
+    type Parameters = Parameters;
+    type PublicKey = PublicKey<E, V>;
+    type SecretKey = SecretKey<E>;
+    type Signature = Signature<E, V>;
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(Parameters::default())
+    }
+
+    fn keygen<R: Rng>(
+        _parameters: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), Error> {
+        let keygen_time = start_timer!(|| "BLS::KeyGen");
+
+        let secret_key = loop {
+            let secret_key = E::Fr::rand(rng);
+            if !secret_key.is_zero() {
+                break secret_key;
+            }
+        };
+        let public_key = V::PublicKeyGroup::prime_subgroup_generator()
+            .mul_uniform(&secret_key)
+            .into();
+
+        end_timer!(keygen_time);
+        Ok((public_key, SecretKey(secret_key)))
+    }
+
+    fn sign<R: Rng>(
+        parameters: &Self::Parameters,
+        sk: &Self::SecretKey,
+        message: &[u8],
+        _rng: &mut R,
+    ) -> Result<Self::Signature, Error> {
+        let sign_time = start_timer!(|| "BLS::Sign");
+        let signature = Self::hash_message(parameters, message)?
+            .mul_uniform(&sk.0)
+            .into();
+        end_timer!(sign_time);
+        Ok(signature)
+    }
+
+    fn verify(
+        parameters: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<bool, Error> {
+        let verify_time = start_timer!(|| "BLS::Verify");
+        let hash = Self::hash_message(parameters, message)?;
+        let result = Self::core_verify(&[*pk], &[hash], signature);
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
+    fn randomize_public_key(
+        _parameters: &Self::Parameters,
+        public_key: &Self::PublicKey,
+        randomness: &[u8],
+    ) -> Result<Self::PublicKey, Error> {
+        Ok(public_key.mul(E::Fr::from_okm(randomness)).into())
+    }
+
+    fn randomize_signature(
+        _parameters: &Self::Parameters,
+        signature: &Self::Signature,
+        randomness: &[u8],
+    ) -> Result<Self::Signature, Error> {
+        Ok(signature.mul(E::Fr::from_okm(randomness)).into())
+    }
+}
+
+/// Checks that `pk` is not the identity and lies in the prime order
+/// subgroup.
+fn is_valid_public_key<G: AffineCurve>(pk: &G) -> bool {
+    !pk.is_zero() && is_in_prime_order_subgroup(pk)
+}
+
+fn is_in_prime_order_subgroup<G: AffineCurve>(point: &G) -> bool {
+    point.is_in_correct_subgroup_assuming_on_curve()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::{test_rng, Bls12_381};
+
+    fn aggregation_test<V: BLSVariant<Bls12_381>>() {
+        type S<V> = BLS<Bls12_381, V>;
+
+        let rng = &mut test_rng();
+        let parameters = S::<V>::setup(rng).unwrap();
+        let message = "Hi, we are BLS signatures!".as_bytes();
+        let other_message = "Bye, we are BLS signatures!".as_bytes();
+
+        let mut keys = Vec::new();
+        for _ in 0..4 {
+            let (pk, sk) = S::<V>::keygen(&parameters, rng).unwrap();
+            let proof = S::<V>::prove_possession(&parameters, &sk).unwrap();
+            assert!(S::<V>::verify_possession(&parameters, &pk, &proof).unwrap());
+            keys.push((pk, sk, proof));
+        }
+        let pks = keys.iter().map(|(pk, ..)| *pk).collect::<Vec<_>>();
+        // A proof of possession is not a valid signature, and vice versa.
+        let (pk, sk, proof) = &keys[0];
+        assert!(!S::<V>::verify(&parameters, pk, &to_bytes![pk].unwrap(), proof).unwrap());
+        let signature = S::<V>::sign(&parameters, sk, &to_bytes![pk].unwrap(), rng).unwrap();
+        assert!(!S::<V>::verify_possession(&parameters, pk, &signature).unwrap());
+
+        // Fast aggregate verification on a single message.
+        let signatures = keys
+            .iter()
+            .map(|(_, sk, _)| S::<V>::sign(&parameters, sk, message, rng).unwrap())
+            .collect::<Vec<_>>();
+        let aggregate = S::<V>::aggregate_signatures(&signatures).unwrap();
+        assert!(S::<V>::fast_aggregate_verify(&parameters, &pks, message, &aggregate).unwrap());
+        assert!(
+            !S::<V>::fast_aggregate_verify(&parameters, &pks, other_message, &aggregate).unwrap()
+        );
+        assert!(
+            !S::<V>::fast_aggregate_verify(&parameters, &pks[1..], message, &aggregate).unwrap()
+        );
+        assert!(!S::<V>::fast_aggregate_verify(&parameters, &[], message, &aggregate).unwrap());
+        let aggregate_pk = S::<V>::aggregate_public_keys(&pks).unwrap();
+        assert!(S::<V>::verify(&parameters, &aggregate_pk, message, &aggregate).unwrap());
+
+        // Aggregate verification on distinct messages.
+        let messages = [&b"zero"[..], b"one", b"two", b"three"];
+        let signatures = keys
+            .iter()
+            .zip(messages.iter())
+            .map(|((_, sk, _), m)| S::<V>::sign(&parameters, sk, m, rng).unwrap())
+            .collect::<Vec<_>>();
+        let aggregate = S::<V>::aggregate_signatures(&signatures).unwrap();
+        assert!(S::<V>::aggregate_verify(&parameters, &pks, &messages, &aggregate).unwrap());
+        let mut swapped = messages;
+        swapped.swap(0, 1);
+        assert!(!S::<V>::aggregate_verify(&parameters, &pks, &swapped, &aggregate).unwrap());
+        assert!(S::<V>::aggregate_verify(&parameters, &pks, &messages[1..], &aggregate).is_err());
+
+        // Batch verification of independent signatures.
+        assert!(S::<V>::batch_verify(&parameters, &pks, &messages, &signatures, rng).unwrap());
+        assert!(!S::<V>::batch_verify(&parameters, &pks, &swapped, &signatures, rng).unwrap());
+        let mut swapped_signatures = signatures.clone();
+        swapped_signatures.swap(0, 1);
+        assert!(
+            !S::<V>::batch_verify(&parameters, &pks, &messages, &swapped_signatures, rng).unwrap()
+        );
+        assert!(S::<V>::batch_verify(&parameters, &pks, &messages, &signatures[1..], rng).is_err());
+
+        // The identity is never a valid public key.
+        let zero_pk = PublicKey::<Bls12_381, V>::zero();
+        let zero_sig = Signature::<Bls12_381, V>::zero();
+        assert!(!S::<V>::verify(&parameters, &zero_pk, message, &zero_sig).unwrap());
+    }
+
+    #[test]
+    fn bls_min_pk_aggregation_test() {
+        aggregation_test::<MinPk>();
+    }
+
+    #[test]
+    fn bls_min_sig_aggregation_test() {
+        aggregation_test::<MinSig>();
+    }
+}
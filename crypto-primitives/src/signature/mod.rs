@@ -8,6 +8,7 @@ pub mod constraints;
 #[cfg(feature = "r1cs")]
 pub use constraints::*;
 
+pub mod bls;
 pub mod schnorr;
 
 pub trait SignatureScheme {
@@ -52,10 +53,10 @@ pub trait SignatureScheme {
 
 #[cfg(test)]
 mod test {
-    use crate::signature::{schnorr, *};
+    use crate::signature::{bls, schnorr, *};
     use algebra::{
-        ed_on_bls12_381::EdwardsProjective as JubJub, groups::Group, test_rng, to_bytes,
-        UniformRand,
+        ed_on_bls12_381::EdwardsProjective as JubJub, groups::Group, test_rng, to_bytes, Bls12_377,
+        Bls12_381, UniformRand,
     };
     use blake2::Blake2s;
 
@@ -101,4 +102,22 @@ mod test {
             &random_scalar.as_slice(),
         );
     }
+
+    fn bls_signature_test<S: SignatureScheme>() {
+        let message = "Hi, I am a BLS signature!";
+        sign_and_verify::<S>(message.as_bytes());
+        failed_verification::<S>(message.as_bytes(), "Bad message".as_bytes());
+        randomize_and_verify::<S>(message.as_bytes(), "randomness".as_bytes());
+    }
+
+    #[test]
+    fn bls_min_pk_signature_test() {
+        bls_signature_test::<bls::BLS<Bls12_381, bls::MinPk>>();
+    }
+
+    #[test]
+    fn bls_min_sig_signature_test() {
+        bls_signature_test::<bls::BLS<Bls12_381, bls::MinSig>>();
+        bls_signature_test::<bls::BLS<Bls12_377, bls::MinSig>>();
+    }
 }
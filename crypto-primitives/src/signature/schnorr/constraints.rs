@@ -1,13 +1,18 @@
 use crate::Vec;
-use algebra_core::{Field, ProjectiveCurve};
+use algebra_core::{to_bytes, BigInteger, Field, FpParameters, PrimeField, ProjectiveCurve};
 use r1cs_core::{Namespace, SynthesisError};
-use r1cs_std::prelude::*;
+use r1cs_std::{prelude::*, Assignment};
 
-use crate::signature::SigRandomizePkGadget;
+use crate::{
+    crh::poseidon::{constraints::PoseidonDigestGadget, PoseidonDigest, PoseidonRoundParams},
+    prf::blake2s::constraints::{evaluate_blake2s, Blake2sGadget},
+    signature::{SigRandomizePkGadget, SigVerifyGadget},
+};
 
 use core::{borrow::Borrow, marker::PhantomData};
 
-use crate::signature::schnorr::{Parameters, PublicKey, Schnorr};
+use crate::signature::schnorr::{Parameters, PublicKey, Schnorr, Signature};
+use blake2::Blake2s;
 use digest::Digest;
 
 type ConstraintF<C> = <<C as ProjectiveCurve>::BaseField as Field>::BasePrimeField;
@@ -18,6 +23,7 @@ where
     for<'a> &'a GC: GroupOpsBounds<'a, C, GC>,
 {
     generator: GC,
+    salt: Vec<UInt8<ConstraintF<C>>>,
     _curve: PhantomData<C>,
 }
 
@@ -35,6 +41,108 @@ where
     _group: PhantomData<*const C>,
 }
 
+#[derive(Derivative)]
+#[derivative(Clone(bound = "C: ProjectiveCurve"))]
+pub struct SignatureVar<C: ProjectiveCurve> {
+    /// The little-endian bytes of the prover response.
+    prover_response: Vec<UInt8<ConstraintF<C>>>,
+    /// The little-endian bytes of the verifier challenge.
+    verifier_challenge: Vec<UInt8<ConstraintF<C>>>,
+    #[doc(hidden)]
+    _group: PhantomData<*const C>,
+}
+
+/// Computes inside a circuit the digest `D::digest(input)`, which `Schnorr`
+/// uses to derive the verifier challenge.
+///
+/// Implementing this for a field-friendly hash (together with `Digest` for
+/// its native counterpart) makes in-circuit verification much cheaper than
+/// with Blake2s; `PoseidonDigestGadget` is such an implementation.
+pub trait DigestGadget<D: Digest, ConstraintF: PrimeField> {
+    fn digest(input: &[UInt8<ConstraintF>]) -> Result<Vec<UInt8<ConstraintF>>, SynthesisError>;
+}
+
+impl<ConstraintF: PrimeField> DigestGadget<Blake2s, ConstraintF> for Blake2sGadget {
+    #[tracing::instrument(target = "r1cs", skip(input))]
+    fn digest(input: &[UInt8<ConstraintF>]) -> Result<Vec<UInt8<ConstraintF>>, SynthesisError> {
+        let input = input
+            .iter()
+            .flat_map(|b| b.to_bits_le().unwrap())
+            .collect::<Vec<_>>();
+        let mut result = Vec::new();
+        for int in evaluate_blake2s(&input)? {
+            result.extend(int.to_bytes()?);
+        }
+        Ok(result)
+    }
+}
+
+impl<F: PrimeField, P: PoseidonRoundParams> DigestGadget<PoseidonDigest<F, P>, F>
+    for PoseidonDigestGadget<F, P>
+{
+    fn digest(input: &[UInt8<F>]) -> Result<Vec<UInt8<F>>, SynthesisError> {
+        PoseidonDigestGadget::<F, P>::digest(input)
+    }
+}
+
+pub struct SchnorrSigVerifyGadget<C: ProjectiveCurve, GC: CurveVar<C, ConstraintF<C>>, DG>
+where
+    for<'a> &'a GC: GroupOpsBounds<'a, C, GC>,
+{
+    #[doc(hidden)]
+    _group: PhantomData<*const C>,
+    #[doc(hidden)]
+    _group_gadget: PhantomData<*const GC>,
+    #[doc(hidden)]
+    _digest_gadget: PhantomData<*const DG>,
+}
+
+impl<C, GC, D, DG> SigVerifyGadget<Schnorr<C, D>, ConstraintF<C>>
+    for SchnorrSigVerifyGadget<C, GC, DG>
+where
+    C: ProjectiveCurve,
+    GC: CurveVar<C, ConstraintF<C>>,
+    D: Digest + Send + Sync,
+    DG: DigestGadget<D, ConstraintF<C>>,
+    for<'a> &'a GC: GroupOpsBounds<'a, C, GC>,
+{
+    type ParametersVar = ParametersVar<C, GC>;
+    type PublicKeyVar = PublicKeyVar<C, GC>;
+    type SignatureVar = SignatureVar<C>;
+
+    #[tracing::instrument(target = "r1cs", skip(parameters, public_key, message, signature))]
+    fn verify(
+        parameters: &Self::ParametersVar,
+        public_key: &Self::PublicKeyVar,
+        message: &[UInt8<ConstraintF<C>>],
+        signature: &Self::SignatureVar,
+    ) -> Result<Boolean<ConstraintF<C>>, SynthesisError> {
+        let prover_response = signature.prover_response.to_bits_le()?;
+        let verifier_challenge = signature.verifier_challenge.to_bits_le()?;
+
+        let claimed_prover_commitment =
+            parameters.generator.scalar_mul_le(prover_response.iter())?
+                + public_key
+                    .pub_key
+                    .scalar_mul_le(verifier_challenge.iter())?;
+
+        let mut hash_input = parameters.salt.clone();
+        hash_input.extend(claimed_prover_commitment.to_bytes()?);
+        hash_input.extend_from_slice(message);
+        let digest = DG::digest(&hash_input)?.to_bits_le()?;
+
+        // `C::ScalarField::from_random_bytes` reads the digest as a
+        // little-endian integer, after clearing its top `REPR_SHAVE_BITS`
+        // bits. The remaining bits of the challenge are zero, since
+        // `SignatureVar` checks that it is smaller than the modulus.
+        let num_bits = <C::ScalarField as PrimeField>::BigInt::NUM_LIMBS * 64
+            - <C::ScalarField as PrimeField>::Params::REPR_SHAVE_BITS as usize;
+        let mut obtained_verifier_challenge = digest;
+        obtained_verifier_challenge.resize(num_bits, Boolean::FALSE);
+        verifier_challenge[..num_bits].is_eq(&obtained_verifier_challenge)
+    }
+}
+
 pub struct SchnorrRandomizePkGadget<C: ProjectiveCurve, GC: CurveVar<C, ConstraintF<C>>>
 where
     for<'a> &'a GC: GroupOpsBounds<'a, C, GC>,
@@ -87,9 +195,32 @@ where
         f: impl FnOnce() -> Result<T, SynthesisError>,
         mode: AllocationMode,
     ) -> Result<Self, SynthesisError> {
-        let generator = GC::new_variable(cs, || f().map(|g| g.borrow().generator), mode)?;
+        let ns = cs.into();
+        let cs = ns.cs();
+        let parameters = f();
+        let generator = GC::new_variable(
+            r1cs_core::ns!(cs, "generator"),
+            || {
+                parameters
+                    .as_ref()
+                    .map(|p| p.borrow().generator)
+                    .map_err(|e| *e)
+            },
+            mode,
+        )?;
+        let salt = parameters.map(|p| p.borrow().salt).ok();
+        let salt = (0..32)
+            .map(|i| {
+                UInt8::new_variable(
+                    r1cs_core::ns!(cs, "salt"),
+                    || salt.get().map(|s| s[i]),
+                    mode,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         Ok(Self {
             generator,
+            salt,
             _curve: PhantomData,
         })
     }
@@ -114,6 +245,53 @@ where
     }
 }
 
+impl<C> AllocVar<Signature<C>, ConstraintF<C>> for SignatureVar<C>
+where
+    C: ProjectiveCurve,
+{
+    /// Allocates the bytes of the prover response and the verifier
+    /// challenge, and checks that both encode elements of `C::ScalarField`.
+    fn new_variable<T: Borrow<Signature<C>>>(
+        cs: impl Into<Namespace<ConstraintF<C>>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let signature = f()
+            .map(|s| {
+                let s = s.borrow();
+                (s.prover_response, s.verifier_challenge)
+            })
+            .ok();
+
+        let mut modulus_minus_one = <C::ScalarField as PrimeField>::Params::MODULUS;
+        modulus_minus_one.sub_noborrow(&1u64.into());
+        let num_bytes = <C::ScalarField as PrimeField>::BigInt::NUM_LIMBS * 8;
+        let alloc_scalar = |scalar: Option<C::ScalarField>| {
+            let scalar_bytes = scalar.map(|s| to_bytes![s].unwrap());
+            let bytes = (0..num_bytes)
+                .map(|i| {
+                    UInt8::new_variable(
+                        r1cs_core::ns!(cs, "scalar byte"),
+                        || scalar_bytes.as_ref().map(|b| b[i]).get(),
+                        mode,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Boolean::enforce_smaller_or_equal_than_le(&bytes.to_bits_le()?, modulus_minus_one)?;
+            Ok(bytes)
+        };
+        let prover_response = alloc_scalar(signature.map(|s| s.0))?;
+        let verifier_challenge = alloc_scalar(signature.map(|s| s.1))?;
+        Ok(Self {
+            prover_response,
+            verifier_challenge,
+            _group: PhantomData,
+        })
+    }
+}
+
 impl<C, GC> EqGadget<ConstraintF<C>> for PublicKeyVar<C, GC>
 where
     C: ProjectiveCurve,
@@ -156,3 +334,129 @@ where
         self.pub_key.to_bytes()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::poseidon::{constraints::PoseidonDigestGadget, PoseidonDigest, PoseidonRoundParams},
+        prf::blake2s::constraints::Blake2sGadget,
+        signature::{
+            schnorr,
+            schnorr::{constraints::*, Signature},
+            SignatureScheme,
+        },
+    };
+    use algebra::{
+        ed_on_bls12_381::{EdwardsProjective as JubJub, Fq as Fr, Fr as Scalar},
+        test_rng, One,
+    };
+    use blake2::Blake2s;
+    use r1cs_core::ConstraintSystem;
+    use r1cs_std::ed_on_bls12_381::EdwardsVar;
+
+    #[derive(Clone)]
+    struct PoseidonParams;
+    impl PoseidonRoundParams for PoseidonParams {
+        const WIDTH: usize = 3;
+        const FULL_ROUNDS: usize = 8;
+        const PARTIAL_ROUNDS: usize = 57;
+        const ALPHA: u64 = 5;
+        const INPUT_SIZE_BITS: usize = 512;
+    }
+
+    type TestPoseidonDigest = PoseidonDigest<Fr, PoseidonParams>;
+    type TestPoseidonDigestGadget = PoseidonDigestGadget<Fr, PoseidonParams>;
+
+    fn verify_in_circuit<D, DG>(
+        message: &[u8],
+        signed_message: &[u8],
+        tamper: impl FnOnce(&mut Signature<JubJub>),
+    ) -> bool
+    where
+        D: Digest + Send + Sync,
+        DG: DigestGadget<D, Fr>,
+    {
+        let rng = &mut test_rng();
+        let parameters = schnorr::Schnorr::<JubJub, D>::setup(rng).unwrap();
+        let (pk, sk) = schnorr::Schnorr::<JubJub, D>::keygen(&parameters, rng).unwrap();
+        let mut signature =
+            schnorr::Schnorr::<JubJub, D>::sign(&parameters, &sk, signed_message, rng).unwrap();
+        tamper(&mut signature);
+        let native_result =
+            schnorr::Schnorr::<JubJub, D>::verify(&parameters, &pk, message, &signature).unwrap();
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let parameters_var =
+            ParametersVar::new_constant(r1cs_core::ns!(cs, "parameters"), &parameters).unwrap();
+        let pk_var =
+            PublicKeyVar::<JubJub, EdwardsVar>::new_input(r1cs_core::ns!(cs, "pk"), || Ok(&pk))
+                .unwrap();
+        let message_var = UInt8::new_witness_vec(r1cs_core::ns!(cs, "message"), message).unwrap();
+        let signature_var =
+            SignatureVar::new_witness(r1cs_core::ns!(cs, "signature"), || Ok(&signature)).unwrap();
+        let result = <SchnorrSigVerifyGadget<JubJub, EdwardsVar, DG> as SigVerifyGadget<
+            schnorr::Schnorr<JubJub, D>,
+            Fr,
+        >>::verify(&parameters_var, &pk_var, &message_var, &signature_var)
+        .unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(result.value().unwrap(), native_result);
+        result.value().unwrap()
+    }
+
+    #[test]
+    fn schnorr_verify_test() {
+        let message = "Hi, I am a Schnorr signature!".as_bytes();
+        assert!(verify_in_circuit::<Blake2s, Blake2sGadget>(
+            message,
+            message,
+            |_| {}
+        ));
+    }
+
+    #[test]
+    fn schnorr_verify_wrong_message_test() {
+        let message = "Hi, I am a Schnorr signature!".as_bytes();
+        let bad_message = "Bye, I am a Schnorr signature!".as_bytes();
+        assert!(!verify_in_circuit::<Blake2s, Blake2sGadget>(
+            bad_message,
+            message,
+            |_| {}
+        ));
+    }
+
+    #[test]
+    fn schnorr_verify_tampered_signature_test() {
+        let message = "Hi, I am a Schnorr signature!".as_bytes();
+        assert!(!verify_in_circuit::<Blake2s, Blake2sGadget>(
+            message,
+            message,
+            |s| s.prover_response += &Scalar::one()
+        ));
+        assert!(!verify_in_circuit::<Blake2s, Blake2sGadget>(
+            message,
+            message,
+            |s| s.verifier_challenge += &Scalar::one()
+        ));
+    }
+
+    #[test]
+    fn schnorr_poseidon_verify_test() {
+        let message = "Hi, I am a Schnorr signature!".as_bytes();
+        let bad_message = "Bye, I am a Schnorr signature!".as_bytes();
+        assert!(verify_in_circuit::<
+            TestPoseidonDigest,
+            TestPoseidonDigestGadget,
+        >(message, message, |_| {}));
+        assert!(!verify_in_circuit::<
+            TestPoseidonDigest,
+            TestPoseidonDigestGadget,
+        >(bad_message, message, |_| {}));
+        assert!(!verify_in_circuit::<
+            TestPoseidonDigest,
+            TestPoseidonDigestGadget,
+        >(message, message, |s| s.verifier_challenge +=
+            &Scalar::one()));
+    }
+}
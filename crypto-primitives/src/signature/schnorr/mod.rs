@@ -3,7 +3,7 @@ use algebra_core::{
     bytes::ToBytes,
     fields::{Field, PrimeField},
     io::{Result as IoResult, Write},
-    to_bytes, AffineCurve, One, ProjectiveCurve, ToConstraintField, UniformRand, Zero,
+    to_bytes, AffineCurve, ProjectiveCurve, ToConstraintField, UniformRand,
 };
 use core::{hash::Hash, marker::PhantomData};
 use digest::Digest;
@@ -74,7 +74,7 @@ where
         let keygen_time = start_timer!(|| "SchnorrSig::KeyGen");
 
         let secret_key = C::ScalarField::rand(rng);
-        let public_key = parameters.generator.mul(secret_key).into();
+        let public_key = parameters.generator.mul_uniform(&secret_key).into();
 
         end_timer!(keygen_time);
         Ok((public_key, SecretKey(secret_key)))
@@ -93,7 +93,10 @@ where
             let random_scalar: C::ScalarField = C::ScalarField::rand(rng);
             // Commit to the random scalar via r := k · G.
             // This is the prover's first msg in the Sigma protocol.
-            let prover_commitment = parameters.generator.mul(random_scalar).into_affine();
+            let prover_commitment = parameters
+                .generator
+                .mul_uniform(&random_scalar)
+                .into_affine();
 
             // Hash everything to get verifier challenge.
             let mut hash_input = Vec::new();
@@ -160,20 +163,10 @@ where
     ) -> Result<Self::PublicKey, Error> {
         let rand_pk_time = start_timer!(|| "SchnorrSig::RandomizePubKey");
 
-        let randomized_pk = *public_key;
-        let base = parameters.generator;
-        let mut encoded = C::zero();
-        for bit in bytes_to_bits(randomness)
-            .into_iter()
-            .rev()
-            .skip_while(|b| !b)
-        {
-            encoded.double_in_place();
-            if bit {
-                encoded.add_assign_mixed(&base)
-            }
-        }
-        encoded.add_assign_mixed(&randomized_pk);
+        let mut encoded = parameters
+            .generator
+            .mul_uniform(&bytes_to_scalar::<C::ScalarField>(randomness));
+        encoded.add_assign_mixed(public_key);
 
         end_timer!(rand_pk_time);
 
@@ -190,15 +183,7 @@ where
             prover_response,
             verifier_challenge,
         } = signature;
-        let mut base = C::ScalarField::one();
-        let mut multiplier = C::ScalarField::zero();
-        for bit in bytes_to_bits(randomness) {
-            if bit {
-                multiplier += &base;
-            }
-            base.double_in_place();
-        }
-
+        let multiplier = bytes_to_scalar::<C::ScalarField>(randomness);
         let new_sig = Signature {
             prover_response: *prover_response - &(*verifier_challenge * &multiplier),
             verifier_challenge: *verifier_challenge,
@@ -208,6 +193,18 @@ where
     }
 }
 
+/// Interprets `bytes_to_bits(bytes)` as the little-endian bits of a scalar,
+/// without branching on the bits.
+fn bytes_to_scalar<F: PrimeField>(bytes: &[u8]) -> F {
+    let mut base = F::one();
+    let mut scalar = F::zero();
+    for bit in bytes_to_bits(bytes) {
+        scalar = F::conditional_select(&scalar, &(scalar + &base), bit);
+        base.double_in_place();
+    }
+    scalar
+}
+
 pub fn bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
     let mut bits = Vec::with_capacity(bytes.len() * 8);
     for byte in bytes {
@@ -5,15 +5,15 @@ use crate::dpc::{
     },
     Record,
 };
-use algebra::{to_bytes, ToConstraintField};
+use algebra::{to_bytes, PrimeField, ToConstraintField};
 use crypto_primitives::{
     merkle_tree, CommitmentGadget, CommitmentScheme, FixedLengthCRH, FixedLengthCRHGadget,
     NIZKVerifierGadget, PRFGadget, PRF,
 };
-use r1cs_core::{ConstraintSystemRef, SynthesisError};
+use r1cs_core::{ConstraintSystemRef, SynthesisError, Variable};
 use r1cs_std::prelude::*;
 
-use r1cs_std::boolean::Boolean;
+use r1cs_std::boolean::{AllocatedBit, Boolean};
 
 pub fn execute_core_checks_gadget<C: PlainDPCComponents>(
     cs: ConstraintSystemRef<C::CoreCheckF>,
@@ -147,13 +147,6 @@ where
     let mut old_birth_pred_hashes = Vec::with_capacity(old_records.len());
     let mut old_death_pred_hashes = Vec::with_capacity(old_records.len());
 
-    let mut new_rec_comms = Vec::with_capacity(new_records.len());
-    let mut new_apks = Vec::with_capacity(new_records.len());
-    let mut new_dummy_flags = Vec::with_capacity(new_records.len());
-    let mut new_payloads = Vec::with_capacity(new_records.len());
-    let mut new_death_pred_hashes = Vec::with_capacity(new_records.len());
-    let mut new_birth_pred_hashes = Vec::with_capacity(new_records.len());
-
     // Order for new_witnessation of input:
     // 1. addr_comm_pp.
     // 2. rec_comm_pp.
@@ -162,14 +155,7 @@ where
     // 5. ledger_digest.
     // 6. for i in 0..NUM_INPUT_RECORDS: old_serial_numbers[i].
     // 7. for j in 0..NUM_OUTPUT_RECORDS: new_commitments[i].
-    let (
-        addr_comm_pp,
-        rec_comm_pp,
-        pred_vk_comm_pp,
-        local_data_comm_pp,
-        sn_nonce_crh_pp,
-        ledger_pp,
-    ) = {
+    let (addr_comm_pp, rec_comm_pp, pred_vk_comm_pp, local_data_comm_pp, ledger_pp) = {
         let _ns = r1cs_core::ns!(cs, "Declare Comm and CRH parameters");
         let addr_comm_pp = AddrCGadget::ParametersVar::new_constant(
             r1cs_core::ns!(cs, "Declare Addr Comm parameters"),
@@ -193,11 +179,6 @@ where
                 &comm_crh_parameters.pred_vk_comm_pp,
             )?;
 
-        let sn_nonce_crh_pp = SnNonceHGadget::ParametersVar::new_constant(
-            r1cs_core::ns!(cs, "Declare SN Nonce CRH parameters"),
-            &comm_crh_parameters.sn_nonce_crh_pp,
-        )?;
-
         let ledger_pp =
             <C::MerkleTreeHGadget as FixedLengthCRHGadget<_, _>>::ParametersVar::new_constant(
                 r1cs_core::ns!(cs, "Declare Ledger Parameters"),
@@ -208,7 +189,6 @@ where
             rec_comm_pp,
             pred_vk_comm_pp,
             local_data_comm_pp,
-            sn_nonce_crh_pp,
             ledger_pp,
         )
     };
@@ -395,72 +375,32 @@ where
         sn_nonce_input
     };
 
-    for (j, ((record, sn_nonce_rand), commitment)) in new_records
+    // The output records are checked independently of each other, so each
+    // of them is checked in its own child constraint system. The children
+    // output the bytes of their records that go into the local data.
+    let sn_nonce_input = bytes_to_variables(&cs, &sn_nonce_input)?;
+    let outputs = new_records
         .iter()
         .zip(new_sn_nonce_randomness)
         .zip(new_commitments)
         .enumerate()
-    {
-        let _ns = r1cs_core::ns!(cs, "Process output record");
-        let j = j as u8;
-
-        let (
-            given_apk,
-            given_record_comm,
-            given_comm,
-            given_is_dummy,
-            given_payload,
-            given_birth_pred_hash,
-            given_death_pred_hash,
-            given_comm_rand,
-            sn_nonce,
-        ) = {
-            let _declare_ns = r1cs_core::ns!(cs, "Declare output record");
-            let given_apk =
-                AddrCGadget::OutputVar::new_witness(r1cs_core::ns!(cs, "Addr PubKey"), || {
-                    Ok(&record.address_public_key().public_key)
-                })?;
-            new_apks.push(given_apk.clone());
-            let given_record_comm = RecCGadget::OutputVar::new_witness(
-                r1cs_core::ns!(cs, "Record Commitment"),
-                || Ok(record.commitment()),
+        .collect();
+    let new_local_data =
+        cs.fork_join(outputs, |cs, (j, ((record, sn_nonce_rand), commitment))| {
+            let _ns = r1cs_core::ns!(cs, "Process output record");
+            let j = j as u8;
+
+            let rec_comm_pp = RecCGadget::ParametersVar::new_constant(
+                r1cs_core::ns!(cs, "Declare Rec Comm parameters"),
+                &comm_crh_parameters.rec_comm_pp,
             )?;
-            new_rec_comms.push(given_record_comm.clone());
-            let given_comm =
-                RecCGadget::OutputVar::new_input(r1cs_core::ns!(cs, "Given Commitment"), || {
-                    Ok(commitment)
-                })?;
-
-            let given_is_dummy =
-                Boolean::new_witness(r1cs_core::ns!(cs, "is_dummy"), || Ok(record.is_dummy()))?;
-            new_dummy_flags.push(given_is_dummy.clone());
-
-            let given_payload =
-                UInt8::new_witness_vec(r1cs_core::ns!(cs, "Payload"), record.payload())?;
-            new_payloads.push(given_payload.clone());
-
-            let given_birth_pred_hash = UInt8::new_witness_vec(
-                r1cs_core::ns!(cs, "Birth predicate"),
-                &record.birth_predicate_repr(),
-            )?;
-            new_birth_pred_hashes.push(given_birth_pred_hash.clone());
-            let given_death_pred_hash = UInt8::new_witness_vec(
-                r1cs_core::ns!(cs, "Death predicate"),
-                &record.death_predicate_repr(),
-            )?;
-            new_death_pred_hashes.push(given_death_pred_hash.clone());
-
-            let given_comm_rand = RecCGadget::RandomnessVar::new_witness(
-                r1cs_core::ns!(cs, "Commitment randomness"),
-                || Ok(record.commitment_randomness()),
+            let sn_nonce_crh_pp = SnNonceHGadget::ParametersVar::new_constant(
+                r1cs_core::ns!(cs, "Declare SN Nonce CRH parameters"),
+                &comm_crh_parameters.sn_nonce_crh_pp,
             )?;
+            let sn_nonce_input = bytes_from_variables(&cs, &sn_nonce_input);
 
-            let sn_nonce =
-                SnNonceHGadget::OutputVar::new_witness(r1cs_core::ns!(cs, "Sn nonce"), || {
-                    Ok(record.serial_number_nonce())
-                })?;
-
-            (
+            let (
                 given_apk,
                 given_record_comm,
                 given_comm,
@@ -470,55 +410,130 @@ where
                 given_death_pred_hash,
                 given_comm_rand,
                 sn_nonce,
-            )
-        };
-
-        // *******************************************************************
-        // Check that the serial number nonce is computed correctly.
-        // *******************************************************************
-        {
-            let _sn_ns = r1cs_core::ns!(cs, "Check that serial number nonce is computed correctly");
+            ) = {
+                let _declare_ns = r1cs_core::ns!(cs, "Declare output record");
+                let given_apk =
+                    AddrCGadget::OutputVar::new_witness(r1cs_core::ns!(cs, "Addr PubKey"), || {
+                        Ok(&record.address_public_key().public_key)
+                    })?;
+                let given_record_comm = RecCGadget::OutputVar::new_witness(
+                    r1cs_core::ns!(cs, "Record Commitment"),
+                    || Ok(record.commitment()),
+                )?;
+                let given_comm = RecCGadget::OutputVar::new_input(
+                    r1cs_core::ns!(cs, "Given Commitment"),
+                    || Ok(commitment),
+                )?;
 
-            let cur_record_num = UInt8::constant(j);
-            let mut cur_record_num_bytes_le = vec![cur_record_num];
+                let given_is_dummy =
+                    Boolean::new_witness(r1cs_core::ns!(cs, "is_dummy"), || Ok(record.is_dummy()))?;
 
-            let sn_nonce_randomness = UInt8::new_witness_vec(
-                r1cs_core::ns!(cs, "Allocate serial number nonce randomness"),
-                sn_nonce_rand,
-            )?;
-            cur_record_num_bytes_le.extend_from_slice(&sn_nonce_randomness);
-            cur_record_num_bytes_le.extend_from_slice(&sn_nonce_input);
+                let given_payload =
+                    UInt8::new_witness_vec(r1cs_core::ns!(cs, "Payload"), record.payload())?;
 
-            let sn_nonce_input = cur_record_num_bytes_le;
-
-            let candidate_sn_nonce = SnNonceHGadget::evaluate(&sn_nonce_crh_pp, &sn_nonce_input)?;
-            candidate_sn_nonce.enforce_equal(&sn_nonce)?;
-        }
-        // *******************************************************************
+                let given_birth_pred_hash = UInt8::new_witness_vec(
+                    r1cs_core::ns!(cs, "Birth predicate"),
+                    &record.birth_predicate_repr(),
+                )?;
+                let given_death_pred_hash = UInt8::new_witness_vec(
+                    r1cs_core::ns!(cs, "Death predicate"),
+                    &record.death_predicate_repr(),
+                )?;
 
-        // *******************************************************************
-        // Check that the record is well-formed.
-        // *******************************************************************
-        {
-            let _comm_ns = r1cs_core::ns!(cs, "Check that record is well-formed");
-            let apk_bytes = given_apk.to_bytes()?;
-            let is_dummy_bytes = given_is_dummy.to_bytes()?;
-            let sn_nonce_bytes = sn_nonce.to_bytes()?;
+                let given_comm_rand = RecCGadget::RandomnessVar::new_witness(
+                    r1cs_core::ns!(cs, "Commitment randomness"),
+                    || Ok(record.commitment_randomness()),
+                )?;
 
-            let mut comm_input = Vec::new();
-            comm_input.extend_from_slice(&apk_bytes);
-            comm_input.extend_from_slice(&is_dummy_bytes);
-            comm_input.extend_from_slice(&given_payload);
-            comm_input.extend_from_slice(&given_birth_pred_hash);
-            comm_input.extend_from_slice(&given_death_pred_hash);
-            comm_input.extend_from_slice(&sn_nonce_bytes);
+                let sn_nonce =
+                    SnNonceHGadget::OutputVar::new_witness(r1cs_core::ns!(cs, "Sn nonce"), || {
+                        Ok(record.serial_number_nonce())
+                    })?;
+
+                (
+                    given_apk,
+                    given_record_comm,
+                    given_comm,
+                    given_is_dummy,
+                    given_payload,
+                    given_birth_pred_hash,
+                    given_death_pred_hash,
+                    given_comm_rand,
+                    sn_nonce,
+                )
+            };
+
+            // *******************************************************************
+            // Check that the serial number nonce is computed correctly.
+            // *******************************************************************
+            {
+                let _sn_ns =
+                    r1cs_core::ns!(cs, "Check that serial number nonce is computed correctly");
+
+                let cur_record_num = UInt8::constant(j);
+                let mut cur_record_num_bytes_le = vec![cur_record_num];
+
+                let sn_nonce_randomness = UInt8::new_witness_vec(
+                    r1cs_core::ns!(cs, "Allocate serial number nonce randomness"),
+                    sn_nonce_rand,
+                )?;
+                cur_record_num_bytes_le.extend_from_slice(&sn_nonce_randomness);
+                cur_record_num_bytes_le.extend_from_slice(&sn_nonce_input);
+
+                let sn_nonce_input = cur_record_num_bytes_le;
+
+                let candidate_sn_nonce =
+                    SnNonceHGadget::evaluate(&sn_nonce_crh_pp, &sn_nonce_input)?;
+                candidate_sn_nonce.enforce_equal(&sn_nonce)?;
+            }
+            // *******************************************************************
+
+            // *******************************************************************
+            // Check that the record is well-formed.
+            // *******************************************************************
+            {
+                let _comm_ns = r1cs_core::ns!(cs, "Check that record is well-formed");
+                let apk_bytes = given_apk.to_bytes()?;
+                let is_dummy_bytes = given_is_dummy.to_bytes()?;
+                let sn_nonce_bytes = sn_nonce.to_bytes()?;
+
+                let mut comm_input = Vec::new();
+                comm_input.extend_from_slice(&apk_bytes);
+                comm_input.extend_from_slice(&is_dummy_bytes);
+                comm_input.extend_from_slice(&given_payload);
+                comm_input.extend_from_slice(&given_birth_pred_hash);
+                comm_input.extend_from_slice(&given_death_pred_hash);
+                comm_input.extend_from_slice(&sn_nonce_bytes);
+
+                let candidate_commitment =
+                    RecCGadget::commit(&rec_comm_pp, &comm_input, &given_comm_rand)?;
+                candidate_commitment.enforce_equal(&given_comm)?;
+                candidate_commitment.enforce_equal(&given_record_comm)?;
+            }
+
+            let _local_data_ns = r1cs_core::ns!(cs, "Construct local data with Output Record");
+            let mut local_data = Vec::new();
+            local_data.extend_from_slice(&given_record_comm.to_bytes()?);
+            local_data.extend_from_slice(&given_apk.to_bytes()?);
+            local_data.extend_from_slice(&given_is_dummy.to_bytes()?);
+            local_data.extend_from_slice(&given_payload);
+            local_data.extend_from_slice(&given_birth_pred_hash);
+            local_data.extend_from_slice(&given_death_pred_hash);
+            bytes_to_variables(&cs, &local_data)
+        })?;
+    let mut new_birth_pred_hashes = Vec::with_capacity(new_records.len());
+    let new_local_data = new_records
+        .iter()
+        .zip(new_local_data)
+        .map(|(record, local_data)| {
+            let local_data = bytes_from_variables(&cs, &local_data);
+            let death_start = local_data.len() - record.death_predicate_repr().len();
+            let birth_start = death_start - record.birth_predicate_repr().len();
+            new_birth_pred_hashes.push(local_data[birth_start..death_start].to_vec());
+            local_data
+        })
+        .collect::<Vec<_>>();
 
-            let candidate_commitment =
-                RecCGadget::commit(&rec_comm_pp, &comm_input, &given_comm_rand)?;
-            candidate_commitment.enforce_equal(&given_comm)?;
-            candidate_commitment.enforce_equal(&given_record_comm)?;
-        }
-    }
     // *******************************************************************
     // Check that predicate commitment is well formed.
     // *******************************************************************
@@ -570,14 +585,8 @@ where
             local_data_bytes.extend_from_slice(&old_sns[i].to_bytes()?);
         }
 
-        for j in 0..C::NUM_OUTPUT_RECORDS {
-            let _ns = r1cs_core::ns!(cs, "Construct local data with Output Record");
-            local_data_bytes.extend_from_slice(&new_rec_comms[j].to_bytes()?);
-            local_data_bytes.extend_from_slice(&new_apks[j].to_bytes()?);
-            local_data_bytes.extend_from_slice(&new_dummy_flags[j].to_bytes()?);
-            local_data_bytes.extend_from_slice(&new_payloads[j]);
-            local_data_bytes.extend_from_slice(&new_birth_pred_hashes[j]);
-            local_data_bytes.extend_from_slice(&new_death_pred_hashes[j]);
+        for local_data in &new_local_data {
+            local_data_bytes.extend_from_slice(local_data);
         }
         let memo = UInt8::new_input_vec(r1cs_core::ns!(cs, "Allocate memorandum"), memo)?;
         local_data_bytes.extend_from_slice(&memo);
@@ -609,6 +618,40 @@ where
     Ok(())
 }
 
+/// The variables of the bits of `bytes`, so that they can be passed into and
+/// out of `ConstraintSystemRef::fork_join`.
+fn bytes_to_variables<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    bytes: &[UInt8<F>],
+) -> Result<Vec<Variable>, SynthesisError> {
+    bytes
+        .to_bits_le()?
+        .into_iter()
+        .map(|bit| match bit {
+            Boolean::Is(bit) => Ok(bit.variable()),
+            bit => cs.new_lc(bit.lc()),
+        })
+        .collect()
+}
+
+/// The bytes of `cs` whose bits are the variables output by
+/// `bytes_to_variables`.
+fn bytes_from_variables<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    variables: &[Variable],
+) -> Vec<UInt8<F>> {
+    variables
+        .chunks(8)
+        .map(|bits| {
+            let bits = bits
+                .iter()
+                .map(|v| Boolean::Is(AllocatedBit::from_variable_unchecked(cs.clone(), *v)))
+                .collect::<Vec<_>>();
+            UInt8::from_bits_le(&bits)
+        })
+        .collect()
+}
+
 pub fn execute_proof_check_gadget<C: PlainDPCComponents>(
     cs: ConstraintSystemRef<C::ProofCheckF>,
     // Parameters
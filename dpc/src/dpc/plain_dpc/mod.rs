@@ -46,7 +46,7 @@ mod test;
 /// Trait that stores all information about the components of a Plain DPC
 /// scheme. Simplifies the interface of Plain DPC by wrapping all these into
 /// one.
-pub trait PlainDPCComponents: 'static + Sized {
+pub trait PlainDPCComponents: 'static + Sized + Sync {
     const NUM_INPUT_RECORDS: usize;
     const NUM_OUTPUT_RECORDS: usize;
 
@@ -12,7 +12,7 @@ use rand_xorshift::XorShiftRng;
 
 use crypto_primitives::FixedLengthCRH;
 
-use r1cs_core::{ConstraintLayer, ConstraintSystem};
+use r1cs_core::{ConstraintLayer, ConstraintSystem, SynthesisMode};
 
 use crate::constraints::plain_dpc::{execute_core_checks_gadget, execute_proof_check_gadget};
 
@@ -182,8 +182,42 @@ fn test_execute_constraint_systems() {
     println!("=========================================================\n\n\n");
 
     assert!(cs.is_satisfied().unwrap());
+    let profile = cs.profile().unwrap();
+    assert_eq!(profile.root.total.num_constraints, cs.num_constraints());
+    assert_eq!(
+        profile.root.total.num_witness_variables,
+        cs.num_witness_variables()
+    );
     drop(core_ns);
 
+    // The output record checks are generated with `fork_join`, which must
+    // give the same constraint matrices in setup mode.
+    let setup_cs = ConstraintSystem::<Fr>::new_ref();
+    setup_cs.set_mode(SynthesisMode::Setup);
+    execute_core_checks_gadget::<_>(
+        setup_cs.clone(),
+        &comm_and_crh_pp,
+        ledger.parameters(),
+        &ledger_digest,
+        &old_records,
+        &old_witnesses,
+        &old_address_secret_keys,
+        &old_serial_numbers,
+        &new_records,
+        &new_sn_nonce_randomness,
+        &new_commitments,
+        &predicate_comm,
+        &predicate_rand,
+        &local_data_comm,
+        &local_data_rand,
+        &memo,
+        &auxiliary,
+    )
+    .unwrap();
+    core_cs.finalize().unwrap();
+    setup_cs.finalize().unwrap();
+    assert!(setup_cs.to_matrices() == core_cs.to_matrices());
+
     // Check that the proof check constraint system was satisfied.
     let pf_check_cs = ConstraintSystem::<Fq>::new_ref();
 
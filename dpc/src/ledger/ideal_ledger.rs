@@ -8,17 +8,23 @@ use std::{
 use crate::{dpc::Transaction, ledger::*};
 use algebra::bytes::ToBytes;
 use crypto_primitives::{
-    merkle_tree::{self, MerkleTree},
+    merkle_tree::{self, IncrementalMerkleTree},
     FixedLengthCRH,
 };
 
+/// A ledger that stores every transaction in memory.
+///
+/// The ledger keeps a membership witness for every commitment, and updates
+/// all of them on every push. Witnesses that are no longer needed, e.g. those
+/// of spent records, can be dropped with [`IdealLedger::forget_cm`] to keep
+/// pushes cheap.
 pub struct IdealLedger<T: Transaction, P: merkle_tree::Config>
 where
     T::Commitment: ToBytes,
 {
     crh_params: <P::H as FixedLengthCRH>::Parameters,
     transactions: Vec<T>,
-    cm_merkle_tree: MerkleTree<P>,
+    cm_merkle_tree: IncrementalMerkleTree<P>,
     cur_cm_index: usize,
     cur_sn_index: usize,
     cur_memo_index: usize,
@@ -32,6 +38,19 @@ where
     genesis_memo: T::Memorandum,
 }
 
+impl<T: Transaction, P: merkle_tree::Config> IdealLedger<T, P>
+where
+    T::Commitment: ToBytes,
+{
+    /// Stops keeping the membership witness of `cm`, e.g. once it has been
+    /// spent. Afterwards, `prove_cm` fails for `cm`.
+    pub fn forget_cm(&mut self, cm: &T::Commitment) {
+        if let Some(&index) = self.comm_to_index.get(cm) {
+            self.cm_merkle_tree.forget(index);
+        }
+    }
+}
+
 impl<T: Transaction, P: merkle_tree::Config> Ledger for IdealLedger<T, P>
 where
     T: Eq,
@@ -56,13 +75,12 @@ where
         genesis_sn: Self::SerialNumber,
         genesis_memo: Self::Memo,
     ) -> Self {
-        let cm_merkle_tree =
-            MerkleTree::<P>::new(parameters.clone(), &[genesis_cm.clone()]).unwrap();
+        let mut cm_merkle_tree = IncrementalMerkleTree::<P>::blank(parameters.clone()).unwrap();
+        let genesis_cm_index = cm_merkle_tree.append_and_witness(&genesis_cm).unwrap();
 
-        let mut cur_cm_index = 0;
         let mut comm_to_index = HashMap::new();
-        comm_to_index.insert(genesis_cm.clone(), cur_cm_index);
-        cur_cm_index += 1;
+        comm_to_index.insert(genesis_cm.clone(), genesis_cm_index);
+        let cur_cm_index = cm_merkle_tree.len();
 
         let root = cm_merkle_tree.root();
         let mut past_digests = HashSet::new();
@@ -116,6 +134,7 @@ where
                 return Err(LedgerError::InvalidCm.into());
             }
             self.comm_to_index.insert(cm.clone(), cur_cm_index);
+            self.cm_merkle_tree.append_and_witness(cm)?;
             cur_cm_index += 1;
         }
         self.cur_cm_index = cur_cm_index;
@@ -130,17 +149,6 @@ where
             }
         }
 
-        // Rebuild the tree.
-        let mut cm_and_indices = self.comm_to_index.iter().collect::<Vec<_>>();
-        cm_and_indices.sort_by(|&(_, i), &(_, j)| i.cmp(j));
-        let commitments = cm_and_indices
-            .into_iter()
-            .map(|(cm, _)| cm)
-            .cloned()
-            .collect::<Vec<_>>();
-        assert!(commitments[0] == self.genesis_cm);
-        self.cm_merkle_tree = MerkleTree::new(self.crh_params.clone(), &commitments)?;
-
         let new_digest = self.cm_merkle_tree.root();
         self.past_digests.insert(new_digest.clone());
         self.current_digest = Some(new_digest);
@@ -177,7 +177,7 @@ fn integration_test() {
         }
         new_proof_and_vk
     };
-    let (_new_records, transaction) = InstantiatedDPC::execute(
+    let (new_records, transaction) = InstantiatedDPC::execute(
         &parameters,
         &old_records,
         &old_asks,
@@ -199,4 +199,32 @@ fn integration_test() {
 
     ledger.push(transaction).unwrap();
     assert_eq!(ledger.len(), 1);
+
+    // Spend the (non-dummy) records created by the first transaction.
+    let old_asks = vec![new_address.secret_key.clone(); NUM_INPUT_RECORDS];
+    let old_records = new_records;
+    let memo = [5u8; 32];
+
+    let (_new_records, transaction) = InstantiatedDPC::execute(
+        &parameters,
+        &old_records,
+        &old_asks,
+        &old_death_vk_and_proof_generator,
+        &new_apks,
+        &new_dummy_flags,
+        &new_payloads,
+        &new_birth_predicates,
+        &new_death_predicates,
+        &new_birth_vk_and_proof_generator,
+        &auxiliary,
+        &memo,
+        &ledger,
+        &mut rng,
+    )
+    .unwrap();
+
+    assert!(InstantiatedDPC::verify(&parameters, &transaction, &ledger).unwrap());
+
+    ledger.push(transaction).unwrap();
+    assert_eq!(ledger.len(), 2);
 }
@@ -0,0 +1,654 @@
+//! An implementation of the polynomial commitment scheme of
+//! [Kate, Zaverucha and Goldberg][kzg10], with optional hiding via a random
+//! blinding polynomial.
+//!
+//! [kzg10]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
+
+use crate::{Cow, DenseOrSparsePolynomial, DensePolynomial, Vec};
+use algebra_core::{
+    msm::{FixedBaseMSM, VariableBaseMSM},
+    AffineCurve, Field, One, PairingEngine, PrimeField, ProjectiveCurve, UniformRand,
+};
+use core::{fmt, marker::PhantomData};
+use rand::RngCore;
+
+/// The output of the trusted setup, from which keys for any degree up to
+/// `max_degree` can be derived with `KZG10::trim`.
+#[derive(Clone, Debug)]
+pub struct UniversalParams<E: PairingEngine> {
+    /// Group elements of the form `β^i G`, for `i` in `0..=max_degree`.
+    pub powers_of_g: Vec<E::G1Affine>,
+    /// Group elements of the form `β^i γG`, for `i` in `0..=max_degree`.
+    pub powers_of_gamma_g: Vec<E::G1Affine>,
+    /// Group elements of the form `β^i H`, for `i` in `0..=max_num_points`.
+    pub powers_of_h: Vec<E::G2Affine>,
+}
+
+impl<E: PairingEngine> UniversalParams<E> {
+    /// The maximum degree of polynomials that can be committed to.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_g.len() - 1
+    }
+
+    /// The maximum number of points at which a single batch proof can open
+    /// a polynomial.
+    pub fn max_num_points(&self) -> usize {
+        self.powers_of_h.len() - 1
+    }
+}
+
+/// The committer key, consisting of the powers of `β` in the exponent.
+#[derive(Clone, Debug)]
+pub struct Powers<'a, E: PairingEngine> {
+    /// Group elements of the form `β^i G`.
+    pub powers_of_g: Cow<'a, [E::G1Affine]>,
+    /// Group elements of the form `β^i γG`.
+    pub powers_of_gamma_g: Cow<'a, [E::G1Affine]>,
+}
+
+impl<E: PairingEngine> Powers<'_, E> {
+    /// The number of coefficients that can be committed to.
+    pub fn size(&self) -> usize {
+        self.powers_of_g.len()
+    }
+}
+
+/// The verifier key.
+///
+/// Checking a proof for a single point only requires the first element of
+/// `powers_of_g` and `powers_of_gamma_g`, and the first two elements of
+/// `powers_of_h`. Checking a batch proof for `n` points requires `n`,
+/// `n` and `n + 1` elements respectively.
+#[derive(Clone, Debug)]
+pub struct VerifierKey<E: PairingEngine> {
+    /// Group elements of the form `β^i G`.
+    pub powers_of_g: Vec<E::G1Affine>,
+    /// Group elements of the form `β^i γG`.
+    pub powers_of_gamma_g: Vec<E::G1Affine>,
+    /// Group elements of the form `β^i H`.
+    pub powers_of_h: Vec<E::G2Affine>,
+    /// `H`, prepared for use in pairings.
+    pub prepared_h: E::G2Prepared,
+    /// `βH`, prepared for use in pairings.
+    pub prepared_beta_h: E::G2Prepared,
+}
+
+/// A commitment to a polynomial.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Commitment<E: PairingEngine>(
+    /// The commitment is a single element of `G1`.
+    pub E::G1Affine,
+);
+
+/// The randomness used to hide a commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Randomness<E: PairingEngine> {
+    /// The blinding polynomial; this is zero for non-hiding commitments.
+    pub blinding_polynomial: DensePolynomial<E::Fr>,
+}
+
+impl<E: PairingEngine> Randomness<E> {
+    /// Randomness for a non-hiding commitment.
+    pub fn empty() -> Self {
+        Self {
+            blinding_polynomial: DensePolynomial::zero(),
+        }
+    }
+
+    /// Samples randomness that keeps the committed polynomial hidden after it
+    /// has been evaluated at up to `hiding_bound` points.
+    pub fn rand<R: RngCore>(hiding_bound: usize, rng: &mut R) -> Self {
+        Self {
+            blinding_polynomial: DensePolynomial::rand(hiding_bound, rng),
+        }
+    }
+
+    /// Returns `true` if this is the randomness of a hiding commitment.
+    pub fn is_hiding(&self) -> bool {
+        !self.blinding_polynomial.is_zero()
+    }
+}
+
+/// A proof that a committed polynomial evaluates to a claimed value at a
+/// single point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Proof<E: PairingEngine> {
+    /// The commitment to the witness polynomial `(p(X) - p(z)) / (X - z)`.
+    pub w: E::G1Affine,
+    /// The evaluation of the blinding polynomial at the point, for hiding
+    /// commitments.
+    pub random_v: Option<E::Fr>,
+}
+
+/// A proof that a committed polynomial evaluates to claimed values at several
+/// points.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchProof<E: PairingEngine> {
+    /// The commitment to the witness polynomial `(p(X) - I(X)) / Z(X)`,
+    /// where `I` interpolates the claimed values and `Z` vanishes on the
+    /// points.
+    pub w: E::G1Affine,
+    /// The evaluations of the blinding polynomial at the points, for hiding
+    /// commitments.
+    pub random_values: Option<Vec<E::Fr>>,
+}
+
+/// The errors that can arise when committing to or opening polynomials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The degree given to `setup` was zero.
+    DegreeIsZero,
+    /// The requested degree or number of points exceeds what the parameters
+    /// support.
+    TrimmingDegreeTooLarge,
+    /// The polynomial has more coefficients than there are powers of `β`.
+    TooManyCoefficients {
+        /// The number of coefficients in the polynomial.
+        num_coefficients: usize,
+        /// The number of powers in the committer key.
+        num_powers: usize,
+    },
+    /// The hiding bound is too large for the committer key.
+    HidingBoundTooLarge {
+        /// The requested hiding bound.
+        hiding_bound: usize,
+        /// The number of powers in the committer key.
+        num_powers: usize,
+    },
+    /// A hiding commitment was requested without a source of randomness.
+    MissingRng,
+    /// A batch opening was requested at more points than supported.
+    TooManyPoints {
+        /// The number of points requested.
+        num_points: usize,
+        /// The maximum number of points supported.
+        max_num_points: usize,
+    },
+    /// The points of a batch opening are not distinct.
+    DuplicatePoints,
+    /// The number of points does not match the number of values.
+    MismatchedLengths,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DegreeIsZero => write!(f, "the maximum degree must be at least 1"),
+            Error::TrimmingDegreeTooLarge => {
+                write!(f, "the requested degree or number of points is too large")
+            },
+            Error::TooManyCoefficients {
+                num_coefficients,
+                num_powers,
+            } => write!(
+                f,
+                "the polynomial has {} coefficients, but the committer key only has {} powers",
+                num_coefficients, num_powers
+            ),
+            Error::HidingBoundTooLarge {
+                hiding_bound,
+                num_powers,
+            } => write!(
+                f,
+                "the hiding bound {} is too large for a committer key with {} powers",
+                hiding_bound, num_powers
+            ),
+            Error::MissingRng => write!(f, "hiding commitments require a source of randomness"),
+            Error::TooManyPoints {
+                num_points,
+                max_num_points,
+            } => write!(
+                f,
+                "cannot open at {} points; at most {} are supported",
+                num_points, max_num_points
+            ),
+            Error::DuplicatePoints => write!(f, "the opening points must be distinct"),
+            Error::MismatchedLengths => {
+                write!(f, "the number of points and values must be the same")
+            },
+        }
+    }
+}
+
+impl algebra_core::Error for Error {}
+
+/// The KZG10 polynomial commitment scheme over the pairing `E`.
+pub struct KZG10<E: PairingEngine> {
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> KZG10<E> {
+    /// Samples public parameters that support committing to polynomials of
+    /// degree up to `max_degree`, and batch opening at up to `max_num_points`
+    /// points at once.
+    ///
+    /// The trapdoors `β` and `γ` must be discarded, which is why this is a
+    /// trusted setup.
+    pub fn setup<R: RngCore>(
+        max_degree: usize,
+        max_num_points: usize,
+        rng: &mut R,
+    ) -> Result<UniversalParams<E>, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+        // Opening at more than `max_degree + 1` points is never useful, as
+        // that many evaluations determine the polynomial.
+        let max_num_points = core::cmp::min(core::cmp::max(max_num_points, 1), max_degree + 1);
+
+        let beta = E::Fr::rand(rng);
+        let g = E::G1Projective::rand(rng);
+        let gamma_g = E::G1Projective::rand(rng);
+        let h = E::G2Projective::rand(rng);
+
+        let mut powers_of_beta = vec![E::Fr::one()];
+        let mut cur = beta;
+        for _ in 0..core::cmp::max(max_degree, max_num_points) {
+            powers_of_beta.push(cur);
+            cur *= &beta;
+        }
+
+        let scalar_bits = E::Fr::size_in_bits();
+
+        let g_window = FixedBaseMSM::get_mul_window_size(max_degree + 1);
+        let g_table = FixedBaseMSM::get_window_table(scalar_bits, g_window, g);
+        let powers_of_g = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
+            scalar_bits,
+            g_window,
+            &g_table,
+            &powers_of_beta[..=max_degree],
+        );
+        let gamma_g_table = FixedBaseMSM::get_window_table(scalar_bits, g_window, gamma_g);
+        let powers_of_gamma_g = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
+            scalar_bits,
+            g_window,
+            &gamma_g_table,
+            &powers_of_beta[..=max_degree],
+        );
+
+        let h_window = FixedBaseMSM::get_mul_window_size(max_num_points + 1);
+        let h_table = FixedBaseMSM::get_window_table(scalar_bits, h_window, h);
+        let powers_of_h = FixedBaseMSM::multi_scalar_mul::<E::G2Projective>(
+            scalar_bits,
+            h_window,
+            &h_table,
+            &powers_of_beta[..=max_num_points],
+        );
+
+        Ok(UniversalParams {
+            powers_of_g: E::G1Projective::batch_normalization_into_affine(&powers_of_g),
+            powers_of_gamma_g: E::G1Projective::batch_normalization_into_affine(&powers_of_gamma_g),
+            powers_of_h: E::G2Projective::batch_normalization_into_affine(&powers_of_h),
+        })
+    }
+
+    /// Specializes the public parameters to polynomials of degree up to
+    /// `supported_degree`, and batch openings at up to `supported_num_points`
+    /// points.
+    pub fn trim(
+        pp: &UniversalParams<E>,
+        supported_degree: usize,
+        supported_num_points: usize,
+    ) -> Result<(Powers<'_, E>, VerifierKey<E>), Error> {
+        let supported_num_points = core::cmp::max(supported_num_points, 1);
+        if supported_degree > pp.max_degree() || supported_num_points > pp.max_num_points() {
+            return Err(Error::TrimmingDegreeTooLarge);
+        }
+
+        let powers = Powers {
+            powers_of_g: Cow::Borrowed(&pp.powers_of_g[..=supported_degree]),
+            powers_of_gamma_g: Cow::Borrowed(&pp.powers_of_gamma_g[..=supported_degree]),
+        };
+        let vk = VerifierKey {
+            powers_of_g: pp.powers_of_g[..supported_num_points].to_vec(),
+            powers_of_gamma_g: pp.powers_of_gamma_g[..supported_num_points].to_vec(),
+            powers_of_h: pp.powers_of_h[..=supported_num_points].to_vec(),
+            prepared_h: pp.powers_of_h[0].into(),
+            prepared_beta_h: pp.powers_of_h[1].into(),
+        };
+        Ok((powers, vk))
+    }
+
+    /// Commits to `polynomial`.
+    ///
+    /// If `hiding_bound` is set, the commitment is blinded by a random
+    /// polynomial of that degree, and so stays hiding after `polynomial` has
+    /// been opened at up to `hiding_bound` points.
+    pub fn commit(
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Commitment<E>, Randomness<E>), Error> {
+        Self::check_degree(polynomial, powers.size())?;
+
+        let mut commitment = msm(&powers.powers_of_g, polynomial);
+
+        let randomness = match hiding_bound {
+            Some(hiding_bound) => {
+                if hiding_bound >= powers.size() {
+                    return Err(Error::HidingBoundTooLarge {
+                        hiding_bound,
+                        num_powers: powers.size(),
+                    });
+                }
+                let mut rng = rng.ok_or(Error::MissingRng)?;
+                let randomness = Randomness::rand(hiding_bound, &mut rng);
+                commitment += &msm(&powers.powers_of_gamma_g, &randomness.blinding_polynomial);
+                randomness
+            },
+            None => Randomness::empty(),
+        };
+
+        Ok((Commitment(commitment.into()), randomness))
+    }
+
+    /// Proves the evaluation of `polynomial` at `point`.
+    pub fn open(
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        point: E::Fr,
+        randomness: &Randomness<E>,
+    ) -> Result<Proof<E>, Error> {
+        Self::check_degree(polynomial, powers.size())?;
+
+        let witness_polynomial = divide_by_linear(polynomial, point);
+        let mut w = msm(&powers.powers_of_g, &witness_polynomial);
+
+        let random_v = if randomness.is_hiding() {
+            let blinding_polynomial = &randomness.blinding_polynomial;
+            let random_witness_polynomial = divide_by_linear(blinding_polynomial, point);
+            w += &msm(&powers.powers_of_gamma_g, &random_witness_polynomial);
+            Some(blinding_polynomial.evaluate(point))
+        } else {
+            None
+        };
+
+        Ok(Proof {
+            w: w.into(),
+            random_v,
+        })
+    }
+
+    /// Verifies that the polynomial committed to in `commitment` evaluates to
+    /// `value` at `point`.
+    pub fn check(
+        vk: &VerifierKey<E>,
+        commitment: &Commitment<E>,
+        point: E::Fr,
+        value: E::Fr,
+        proof: &Proof<E>,
+    ) -> Result<bool, Error> {
+        // We check that e(C - vG - v'γG, H) = e(W, βH - zH), or equivalently
+        // that e(C - vG - v'γG + zW, H) · e(-W, βH) = 1.
+        let mut inner = commitment.0.into_projective() - vk.powers_of_g[0].mul(value);
+        if let Some(random_v) = proof.random_v {
+            inner -= &vk.powers_of_gamma_g[0].mul(random_v);
+        }
+        inner += &proof.w.mul(point);
+
+        let result = E::product_of_pairings(&[
+            (inner.into_affine().into(), vk.prepared_h.clone()),
+            ((-proof.w).into(), vk.prepared_beta_h.clone()),
+        ]);
+        Ok(result.is_one())
+    }
+
+    /// Proves the evaluations of `polynomial` at each of `points` with a
+    /// single group element.
+    pub fn batch_open(
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        points: &[E::Fr],
+        randomness: &Randomness<E>,
+    ) -> Result<BatchProof<E>, Error> {
+        Self::check_degree(polynomial, powers.size())?;
+        check_distinct(points)?;
+
+        // Dividing by the vanishing polynomial of the points leaves the
+        // interpolating polynomial of the evaluations as remainder, so the
+        // quotient is exactly the witness polynomial.
+        let vanishing_polynomial = vanishing_polynomial(points);
+        let divisor = DenseOrSparsePolynomial::from(&vanishing_polynomial);
+        let (witness_polynomial, _) = DenseOrSparsePolynomial::from(polynomial)
+            .divide_with_q_and_r(&divisor)
+            .unwrap();
+        let mut w = msm(&powers.powers_of_g, &witness_polynomial);
+
+        let random_values = if randomness.is_hiding() {
+            let blinding_polynomial = &randomness.blinding_polynomial;
+            let (random_witness_polynomial, _) = DenseOrSparsePolynomial::from(blinding_polynomial)
+                .divide_with_q_and_r(&divisor)
+                .unwrap();
+            w += &msm(&powers.powers_of_gamma_g, &random_witness_polynomial);
+            Some(
+                points
+                    .iter()
+                    .map(|point| blinding_polynomial.evaluate(*point))
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
+        Ok(BatchProof {
+            w: w.into(),
+            random_values,
+        })
+    }
+
+    /// Verifies that the polynomial committed to in `commitment` evaluates to
+    /// `values[i]` at `points[i]`, for every `i`.
+    pub fn batch_check(
+        vk: &VerifierKey<E>,
+        commitment: &Commitment<E>,
+        points: &[E::Fr],
+        values: &[E::Fr],
+        proof: &BatchProof<E>,
+    ) -> Result<bool, Error> {
+        if points.len() != values.len() {
+            return Err(Error::MismatchedLengths);
+        }
+        if points.len() >= vk.powers_of_h.len() {
+            return Err(Error::TooManyPoints {
+                num_points: points.len(),
+                max_num_points: vk.powers_of_h.len() - 1,
+            });
+        }
+
+        // We check that e(C - I(β)G - I'(β)γG, H) = e(W, Z(β)H), where I and
+        // I' interpolate the claimed and the random values respectively.
+        let mut inner =
+            commitment.0.into_projective() - msm(&vk.powers_of_g, &interpolate(points, values)?);
+        if let Some(random_values) = &proof.random_values {
+            if random_values.len() != points.len() {
+                return Err(Error::MismatchedLengths);
+            }
+            inner -= &msm(&vk.powers_of_gamma_g, &interpolate(points, random_values)?);
+        }
+        let vanishing_at_beta = msm(&vk.powers_of_h, &vanishing_polynomial(points));
+
+        let result = E::product_of_pairings(&[
+            (inner.into_affine().into(), vk.prepared_h.clone()),
+            ((-proof.w).into(), vanishing_at_beta.into_affine().into()),
+        ]);
+        Ok(result.is_one())
+    }
+
+    fn check_degree(polynomial: &DensePolynomial<E::Fr>, num_powers: usize) -> Result<(), Error> {
+        if polynomial.coeffs.len() > num_powers {
+            return Err(Error::TooManyCoefficients {
+                num_coefficients: polynomial.coeffs.len(),
+                num_powers,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Computes `Σ_i p_i · bases[i]`.
+fn msm<G: AffineCurve>(bases: &[G], polynomial: &DensePolynomial<G::ScalarField>) -> G::Projective {
+    let scalars = polynomial
+        .coeffs
+        .iter()
+        .map(|c| c.into_repr())
+        .collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(&bases[..scalars.len()], &scalars)
+}
+
+/// Computes `(p(X) - p(z)) / (X - z)` by synthetic division.
+fn divide_by_linear<F: Field>(polynomial: &DensePolynomial<F>, point: F) -> DensePolynomial<F> {
+    if polynomial.coeffs.len() <= 1 {
+        return DensePolynomial::zero();
+    }
+    let mut quotient = vec![F::zero(); polynomial.coeffs.len() - 1];
+    let mut carry = F::zero();
+    for i in (1..polynomial.coeffs.len()).rev() {
+        carry = polynomial.coeffs[i] + carry * point;
+        quotient[i - 1] = carry;
+    }
+    DensePolynomial::from_coefficients_vec(quotient)
+}
+
+/// Computes `Π_i (X - points[i])`.
+fn vanishing_polynomial<F: Field>(points: &[F]) -> DensePolynomial<F> {
+    points.iter().fold(
+        DensePolynomial::from_coefficients_vec(vec![F::one()]),
+        |acc, point| {
+            acc.naive_mul(&DensePolynomial::from_coefficients_vec(vec![
+                -*point,
+                F::one(),
+            ]))
+        },
+    )
+}
+
+fn check_distinct<F: Field>(points: &[F]) -> Result<(), Error> {
+    for (i, a) in points.iter().enumerate() {
+        if points[(i + 1)..].contains(a) {
+            return Err(Error::DuplicatePoints);
+        }
+    }
+    Ok(())
+}
+
+/// Computes the polynomial of degree less than `points.len()` that evaluates
+/// to `values[i]` at `points[i]`, via Lagrange interpolation.
+fn interpolate<F: Field>(points: &[F], values: &[F]) -> Result<DensePolynomial<F>, Error> {
+    check_distinct(points)?;
+    let vanishing_polynomial = vanishing_polynomial(points);
+    let mut result = DensePolynomial::zero();
+    for (point, value) in points.iter().zip(values) {
+        // The Lagrange basis polynomial for `point`, up to normalization.
+        let basis = divide_by_linear(&vanishing_polynomial, *point);
+        let scale = *value * basis.evaluate(*point).inverse().unwrap();
+        result += (scale, &basis);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::{Bls12_381, Fr};
+    use algebra_core::test_rng;
+
+    type Kzg = KZG10<Bls12_381>;
+
+    #[test]
+    fn divide_by_linear_test() {
+        let rng = &mut test_rng();
+        for degree in 0..10 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let point = Fr::rand(rng);
+            let q = divide_by_linear(&p, point);
+            let x = Fr::rand(rng);
+            assert_eq!(
+                q.evaluate(x) * (x - point),
+                p.evaluate(x) - p.evaluate(point)
+            );
+        }
+    }
+
+    #[test]
+    fn interpolate_test() {
+        let rng = &mut test_rng();
+        let p = DensePolynomial::<Fr>::rand(6, rng);
+        let points: Vec<Fr> = (0..7).map(|_| Fr::rand(rng)).collect();
+        let values: Vec<Fr> = points.iter().map(|z| p.evaluate(*z)).collect();
+        assert_eq!(interpolate(&points, &values).unwrap(), p);
+        assert_eq!(
+            interpolate(&[points[0], points[0]], &values[..2]),
+            Err(Error::DuplicatePoints)
+        );
+    }
+
+    #[test]
+    fn single_point_test() {
+        let rng = &mut test_rng();
+        let pp = Kzg::setup(16, 1, rng).unwrap();
+        for degree in 1..=16 {
+            let (powers, vk) = Kzg::trim(&pp, degree, 1).unwrap();
+            let p = DensePolynomial::rand(degree, rng);
+            for hiding_bound in &[None, Some(1)] {
+                let (comm, rand) =
+                    Kzg::commit(&powers, &p, *hiding_bound, Some(&mut *rng)).unwrap();
+                let point = Fr::rand(rng);
+                let value = p.evaluate(point);
+                let proof = Kzg::open(&powers, &p, point, &rand).unwrap();
+                assert!(Kzg::check(&vk, &comm, point, value, &proof).unwrap());
+                assert!(!Kzg::check(&vk, &comm, point, value + Fr::one(), &proof).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn batch_points_test() {
+        let rng = &mut test_rng();
+        let pp = Kzg::setup(20, 8, rng).unwrap();
+        let (powers, vk) = Kzg::trim(&pp, 20, 8).unwrap();
+        let p = DensePolynomial::rand(20, rng);
+        for num_points in 1..=8 {
+            for hiding_bound in &[None, Some(num_points)] {
+                let (comm, rand) =
+                    Kzg::commit(&powers, &p, *hiding_bound, Some(&mut *rng)).unwrap();
+                let points: Vec<Fr> = (0..num_points).map(|_| Fr::rand(rng)).collect();
+                let mut values: Vec<Fr> = points.iter().map(|z| p.evaluate(*z)).collect();
+                let proof = Kzg::batch_open(&powers, &p, &points, &rand).unwrap();
+                assert!(Kzg::batch_check(&vk, &comm, &points, &values, &proof).unwrap());
+
+                values[0] += &Fr::one();
+                assert!(!Kzg::batch_check(&vk, &comm, &points, &values, &proof).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn degree_errors_test() {
+        let rng = &mut test_rng();
+        let pp = Kzg::setup(8, 2, rng).unwrap();
+        assert!(Kzg::trim(&pp, 9, 1).is_err());
+        assert!(Kzg::trim(&pp, 8, 3).is_err());
+
+        let (powers, vk) = Kzg::trim(&pp, 4, 2).unwrap();
+        let p = DensePolynomial::<Fr>::rand(5, rng);
+        assert_eq!(
+            Kzg::commit(&powers, &p, None, None).unwrap_err(),
+            Error::TooManyCoefficients {
+                num_coefficients: 6,
+                num_powers: 5
+            }
+        );
+        let p = DensePolynomial::<Fr>::rand(4, rng);
+        assert_eq!(
+            Kzg::commit(&powers, &p, Some(1), None).unwrap_err(),
+            Error::MissingRng
+        );
+
+        let (comm, rand) = Kzg::commit(&powers, &p, None, None).unwrap();
+        let points: Vec<Fr> = (0..3).map(|_| Fr::rand(rng)).collect();
+        let values: Vec<Fr> = points.iter().map(|z| p.evaluate(*z)).collect();
+        let proof = Kzg::batch_open(&powers, &p, &points, &rand).unwrap();
+        assert!(Kzg::batch_check(&vk, &comm, &points, &values, &proof).is_err());
+    }
+}
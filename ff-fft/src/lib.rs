@@ -1,5 +1,5 @@
 //! This crate implements functions for manipulating polynomials over finite
-//! fields, including FFTs.
+//! fields, including FFTs, and the KZG10 polynomial commitment scheme.
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(unused_import_braces, trivial_casts, bare_trait_objects, missing_docs)]
 #![deny(unused_qualifications, variant_size_differences, stable_features)]
@@ -94,6 +94,7 @@ macro_rules! cfg_chunks_mut {
 pub mod domain;
 
 pub mod evaluations;
+pub mod kzg10;
 pub mod polynomial;
 
 pub use domain::{
@@ -49,6 +49,8 @@ where
     // Synthesize the circuit.
     let synthesis_time = start_timer!(|| "Constraint synthesis");
     circuit.generate_constraints(cs.clone())?;
+    #[cfg(debug_assertions)]
+    cs.check_satisfied()?;
     end_timer!(synthesis_time);
 
     let lc_time = start_timer!(|| "Inlining LCs");
@@ -7,7 +7,7 @@ use algebra_core::{
 
 use crate::{r1cs_to_qap::R1CStoQAP, Parameters, Proof, Vec};
 
-use r1cs_core::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+use r1cs_core::{ConstraintSynthesizer, ConstraintSystem, SynthesisError, SynthesisMode};
 
 use ff_fft::{cfg_into_iter, cfg_iter, EvaluationDomain};
 
@@ -56,11 +56,14 @@ where
 {
     let prover_time = start_timer!(|| "Groth16::Prover");
     let cs = ConstraintSystem::new_ref();
+    // The QAP witness map only needs the evaluations of the constraints.
+    cs.set_mode(SynthesisMode::WitnessOnly);
 
     // Synthesize the circuit.
     let synthesis_time = start_timer!(|| "Constraint synthesis");
     circuit.generate_constraints(cs.clone())?;
-    debug_assert!(cs.is_satisfied().unwrap());
+    #[cfg(debug_assertions)]
+    cs.check_satisfied()?;
     end_timer!(synthesis_time);
 
     let lc_time = start_timer!(|| "Inlining LCs");
@@ -122,10 +125,10 @@ where
     let c_acc_time = start_timer!(|| "Compute C");
 
     let h_query = params.get_h_query_full()?;
-    let h_acc = VariableBaseMSM::multi_scalar_mul(&h_query, &h_assignment);
+    let h_acc = msm(h_query, &h_assignment);
 
     let l_aux_source = params.get_l_query_full()?;
-    let l_aux_acc = VariableBaseMSM::multi_scalar_mul(l_aux_source, &aux_assignment);
+    let l_aux_acc = msm(l_aux_source, &aux_assignment);
 
     let s_g_a = g_a.mul(s);
     let r_g1_b = g1_b.mul(r);
@@ -147,6 +150,23 @@ where
     })
 }
 
+/// Computes `sum_i scalars[i] * bases[i]` using batched affine additions.
+/// Bases without a matching scalar are ignored.
+fn msm<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInt],
+) -> G::Projective {
+    let n = core::cmp::min(bases.len(), scalars.len());
+    if n == 0 {
+        return G::Projective::zero();
+    }
+    VariableBaseMSM::multi_scalar_mul_batched(
+        &bases[..n],
+        &scalars[..n],
+        G::ScalarField::size_in_bits(),
+    )
+}
+
 fn calculate_coeff<G: AffineCurve>(
     initial: G::Projective,
     query: &[G],
@@ -154,7 +174,7 @@ fn calculate_coeff<G: AffineCurve>(
     assignment: &[<G::ScalarField as PrimeField>::BigInt],
 ) -> G::Projective {
     let el = query[0];
-    let acc = VariableBaseMSM::multi_scalar_mul(&query[1..], assignment);
+    let acc = msm(&query[1..], assignment);
 
     let mut res = initial;
     res.add_assign_mixed(&el);
@@ -1,5 +1,5 @@
-use crate::{Parameters, Proof};
-use algebra_core::PairingEngine;
+use crate::{Parameters, Proof, VerifyingKey};
+use algebra_core::{AffineCurve, Field, PairingEngine, ProjectiveCurve, UniformRand, Zero};
 use ff_fft::GeneralEvaluationDomain;
 use r1cs_core::{ConstraintSynthesizer, SynthesisError};
 use rand::Rng;
@@ -47,3 +47,33 @@ where
 {
     self::generic::create_proof::<E, C, GeneralEvaluationDomain<E::Fr>>(circuit, params, r, s)
 }
+
+/// Given a valid proof, outputs a fresh proof for the same statement that
+/// cannot be linked to the original, using only the verifying key.
+///
+/// For random `r_1 ≠ 0` and `r_2`, the new proof is
+/// `(A / r_1, r_1 B + r_1 r_2 δ, C + r_2 A)`, which is distributed exactly
+/// like a proof created from scratch.
+pub fn rerandomize_proof<E, R>(vk: &VerifyingKey<E>, proof: &Proof<E>, rng: &mut R) -> Proof<E>
+where
+    E: PairingEngine,
+    R: Rng,
+{
+    let r_1 = loop {
+        let r = E::Fr::rand(rng);
+        if !r.is_zero() {
+            break r;
+        }
+    };
+    let r_2 = E::Fr::rand(rng);
+
+    let new_a = proof.a.mul(r_1.inverse().unwrap());
+    let new_b = proof.b.mul(r_1) + vk.delta_g2.mul(r_1 * r_2);
+    let new_c = proof.c.into_projective() + proof.a.mul(r_2);
+
+    Proof {
+        a: new_a.into_affine(),
+        b: new_b.into_affine(),
+        c: new_c.into_affine(),
+    }
+}
@@ -89,13 +89,20 @@ impl R1CStoQAP {
     pub(crate) fn witness_map<E: PairingEngine, D: EvaluationDomain<E::Fr>>(
         prover: ConstraintSystemRef<E::Fr>,
     ) -> Result<Vec<E::Fr>, SynthesisError> {
-        let matrices = prover.to_matrices().unwrap();
         let zero = E::Fr::zero();
         let num_inputs = prover.num_instance_variables();
         let num_constraints = prover.num_constraints();
         let cs = prover.borrow().unwrap();
         let prover = cs.deref();
 
+        // In witness-only mode, the evaluations of the constraints were
+        // already computed during synthesis, and there are no matrices.
+        let evaluations = prover.constraint_evaluations();
+        let matrices = match evaluations {
+            Some(_) => None,
+            None => Some(prover.to_matrices().unwrap()),
+        };
+
         let full_assignment = [
             prover.instance_assignment.as_slice(),
             prover.witness_assignment.as_slice(),
@@ -109,14 +116,20 @@ impl R1CStoQAP {
         let mut a = vec![zero; domain_size];
         let mut b = vec![zero; domain_size];
 
-        cfg_iter_mut!(a[..num_constraints])
-            .zip(cfg_iter_mut!(b[..num_constraints]))
-            .zip(cfg_iter!(&matrices.a))
-            .zip(cfg_iter!(&matrices.b))
-            .for_each(|(((a, b), at_i), bt_i)| {
-                *a = evaluate_constraint(&at_i, &full_assignment);
-                *b = evaluate_constraint(&bt_i, &full_assignment);
-            });
+        if let Some((a_evaluations, b_evaluations, _)) = evaluations {
+            a[..num_constraints].copy_from_slice(a_evaluations);
+            b[..num_constraints].copy_from_slice(b_evaluations);
+        } else {
+            let matrices = matrices.as_ref().unwrap();
+            cfg_iter_mut!(a[..num_constraints])
+                .zip(cfg_iter_mut!(b[..num_constraints]))
+                .zip(cfg_iter!(&matrices.a))
+                .zip(cfg_iter!(&matrices.b))
+                .for_each(|(((a, b), at_i), bt_i)| {
+                    *a = evaluate_constraint(&at_i, &full_assignment);
+                    *b = evaluate_constraint(&bt_i, &full_assignment);
+                });
+        }
 
         for i in 0..num_inputs {
             a[num_constraints + i] = full_assignment[i];
@@ -133,11 +146,16 @@ impl R1CStoQAP {
         drop(b);
 
         let mut c = vec![zero; domain_size];
-        cfg_iter_mut!(c[..prover.num_constraints])
-            .enumerate()
-            .for_each(|(i, c)| {
-                *c = evaluate_constraint(&matrices.c[i], &full_assignment);
-            });
+        if let Some((_, _, c_evaluations)) = evaluations {
+            c[..num_constraints].copy_from_slice(c_evaluations);
+        } else {
+            let matrices = matrices.as_ref().unwrap();
+            cfg_iter_mut!(c[..prover.num_constraints])
+                .enumerate()
+                .for_each(|(i, c)| {
+                    *c = evaluate_constraint(&matrices.c[i], &full_assignment);
+                });
+        }
 
         domain.ifft_in_place(&mut c);
         domain.coset_fft_in_place(&mut c);
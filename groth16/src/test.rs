@@ -34,7 +34,8 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
 mod bls12_377 {
     use super::*;
     use crate::{
-        create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+        create_random_proof, generate_random_parameters, prepare_verifying_key, rerandomize_proof,
+        verify_proof, verify_proofs_batch,
     };
 
     use algebra::bls12_377::{Bls12_377, Fr};
@@ -71,6 +72,141 @@ mod bls12_377 {
             assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
         }
     }
+
+    #[test]
+    fn prove_and_verify_circom_circuit() {
+        use r1cs_core::{write_r1cs, write_wtns, CircomCircuit, ConstraintSystem};
+
+        let rng = &mut test_rng();
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let cs = ConstraintSystem::new_ref();
+        MySillyCircuit {
+            a: Some(a),
+            b: Some(b),
+        }
+        .generate_constraints(cs.clone())
+        .unwrap();
+        cs.inline_all_lcs();
+
+        let (mut r1cs, mut wtns) = (Vec::new(), Vec::new());
+        write_r1cs(&cs.to_matrices().unwrap(), &mut r1cs).unwrap();
+        let cs = cs.borrow().unwrap();
+        let assignment = [&cs.instance_assignment[..], &cs.witness_assignment[..]].concat();
+        write_wtns(&assignment, &mut wtns).unwrap();
+
+        let circuit = CircomCircuit::<Fr>::read(&r1cs[..], None::<&[u8]>).unwrap();
+        let params = generate_random_parameters::<Bls12_377, _, _>(circuit, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        let circuit = CircomCircuit::<Fr>::read(&r1cs[..], Some(&wtns[..])).unwrap();
+        let public_inputs = circuit.public_inputs().unwrap().to_vec();
+        let proof = create_random_proof(circuit, &params, rng).unwrap();
+        assert!(verify_proof(&pvk, &proof, &public_inputs).unwrap());
+        assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+    }
+
+    #[test]
+    fn batch_verify() {
+        let rng = &mut test_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        let mut proofs = Vec::new();
+        let mut inputs = Vec::new();
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let c = a * b;
+
+            let proof = create_random_proof(
+                MySillyCircuit {
+                    a: Some(a),
+                    b: Some(b),
+                },
+                &params,
+                rng,
+            )
+            .unwrap();
+            proofs.push(proof);
+            inputs.push(vec![c]);
+        }
+
+        let batch = proofs
+            .iter()
+            .zip(&inputs)
+            .map(|(proof, input)| (proof, input.as_slice()))
+            .collect::<Vec<_>>();
+        assert!(verify_proofs_batch(&pvk, &batch, rng).unwrap().is_empty());
+        assert!(verify_proofs_batch(&pvk, &[], rng).unwrap().is_empty());
+
+        // Corrupt the statements of proofs 3 and 7.
+        let bad_inputs = [vec![Fr::rand(rng)], vec![Fr::rand(rng)]];
+        let mut bad_batch = batch.clone();
+        bad_batch[3].1 = &bad_inputs[0];
+        bad_batch[7].1 = &bad_inputs[1];
+        assert_eq!(
+            verify_proofs_batch(&pvk, &bad_batch, rng).unwrap(),
+            vec![3, 7]
+        );
+
+        // Swap the `C` elements of two proofs, which breaks both.
+        let mut swapped = [proofs[0].clone(), proofs[1].clone()];
+        swapped[0].c = proofs[1].c;
+        swapped[1].c = proofs[0].c;
+        let mut bad_batch = batch.clone();
+        bad_batch[0].0 = &swapped[0];
+        bad_batch[1].0 = &swapped[1];
+        assert_eq!(
+            verify_proofs_batch(&pvk, &bad_batch, rng).unwrap(),
+            vec![0, 1]
+        );
+
+        let malformed = [(&proofs[0], &[][..])];
+        assert!(verify_proofs_batch(&pvk, &malformed, rng).is_err());
+    }
+
+    #[test]
+    fn rerandomize_and_verify() {
+        let rng = &mut test_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let c = a * b;
+
+            let proof = create_random_proof(
+                MySillyCircuit {
+                    a: Some(a),
+                    b: Some(b),
+                },
+                &params,
+                rng,
+            )
+            .unwrap();
+
+            let rerandomized = rerandomize_proof(&params.vk, &proof, rng);
+            assert_ne!(rerandomized.a, proof.a);
+            assert_ne!(rerandomized.b, proof.b);
+            assert_ne!(rerandomized.c, proof.c);
+            assert!(verify_proof(&pvk, &rerandomized, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &rerandomized, &[a]).unwrap());
+
+            // Re-randomizing twice still yields a valid proof.
+            let rerandomized = rerandomize_proof(&params.vk, &rerandomized, rng);
+            assert!(verify_proof(&pvk, &rerandomized, &[c]).unwrap());
+        }
+    }
 }
 
 mod cp6_782 {
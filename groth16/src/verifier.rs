@@ -1,10 +1,13 @@
-use algebra_core::{AffineCurve, PairingEngine, PrimeField, ProjectiveCurve};
+use algebra_core::{
+    msm::VariableBaseMSM, AffineCurve, Field, PairingEngine, PrimeField, ProjectiveCurve, Zero,
+};
 
 use super::{PreparedVerifyingKey, Proof, VerifyingKey};
 
-use crate::SynthesisError;
+use crate::{SynthesisError, Vec};
 
 use core::ops::{AddAssign, Neg};
+use rand::Rng;
 
 pub fn prepare_verifying_key<E: PairingEngine>(vk: &VerifyingKey<E>) -> PreparedVerifyingKey<E> {
     PreparedVerifyingKey {
@@ -43,3 +46,107 @@ pub fn verify_proof<E: PairingEngine>(
 
     Ok(test == pvk.alpha_g1_beta_g2)
 }
+
+/// Verifies many proofs against the same verifying key at once, and returns
+/// the indices of the proofs that are invalid. An empty result means that
+/// every proof verified.
+///
+/// The verification equations of all proofs are combined with random
+/// 128-bit coefficients, so that a single multi-Miller loop and a single
+/// final exponentiation cover the whole batch. If the combined check fails,
+/// the batch is repeatedly bisected to locate the invalid proofs.
+pub fn verify_proofs_batch<E: PairingEngine, R: Rng>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(&Proof<E>, &[E::Fr])],
+    rng: &mut R,
+) -> Result<Vec<usize>, SynthesisError> {
+    for (_, public_inputs) in proofs_and_inputs {
+        if (public_inputs.len() + 1) != pvk.gamma_abc_g1.len() {
+            return Err(SynthesisError::MalformedVerifyingKey);
+        }
+    }
+
+    let mut invalid = Vec::new();
+    find_invalid_proofs(pvk, proofs_and_inputs, 0, rng, &mut invalid)?;
+    Ok(invalid)
+}
+
+fn find_invalid_proofs<E: PairingEngine, R: Rng>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(&Proof<E>, &[E::Fr])],
+    offset: usize,
+    rng: &mut R,
+    invalid: &mut Vec<usize>,
+) -> Result<(), SynthesisError> {
+    if proofs_and_inputs.is_empty() || batch_is_valid(pvk, proofs_and_inputs, rng)? {
+        return Ok(());
+    }
+    if proofs_and_inputs.len() == 1 {
+        invalid.push(offset);
+        return Ok(());
+    }
+
+    let (left, right) = proofs_and_inputs.split_at(proofs_and_inputs.len() / 2);
+    find_invalid_proofs(pvk, left, offset, rng, invalid)?;
+    find_invalid_proofs(pvk, right, offset + left.len(), rng, invalid)
+}
+
+/// Checks that `Π_i e(r_i A_i, B_i) · e(Σ_i r_i IC_i, -γ) · e(Σ_i r_i C_i, -δ)`
+/// equals `e(α, β)^{Σ_i r_i}`.
+fn batch_is_valid<E: PairingEngine, R: Rng>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(&Proof<E>, &[E::Fr])],
+    rng: &mut R,
+) -> Result<bool, SynthesisError> {
+    if let [(proof, public_inputs)] = proofs_and_inputs {
+        return verify_proof(pvk, proof, public_inputs);
+    }
+
+    let batch_time = start_timer!(|| format!("Batch verify {} proofs", proofs_and_inputs.len()));
+    let coeffs: Vec<E::Fr> = (0..proofs_and_inputs.len())
+        .map(|_| E::Fr::from(rng.gen::<u128>()))
+        .collect();
+
+    // The input commitments are linear in the public inputs, so we can sum
+    // the scaled inputs before a single MSM over `gamma_abc_g1`.
+    let mut input_scalars = vec![E::Fr::zero(); pvk.gamma_abc_g1.len()];
+    for (r, (_, public_inputs)) in coeffs.iter().zip(proofs_and_inputs) {
+        input_scalars[0] += r;
+        for (scalar, input) in input_scalars[1..].iter_mut().zip(public_inputs.iter()) {
+            *scalar += &(*r * input);
+        }
+    }
+    let input_scalars_repr = input_scalars
+        .iter()
+        .map(|s| s.into_repr())
+        .collect::<Vec<_>>();
+    let g_ic = VariableBaseMSM::multi_scalar_mul(&pvk.gamma_abc_g1, &input_scalars_repr);
+
+    let coeffs_repr = coeffs.iter().map(|r| r.into_repr()).collect::<Vec<_>>();
+    let c_bases = proofs_and_inputs
+        .iter()
+        .map(|(proof, _)| proof.c)
+        .collect::<Vec<_>>();
+    let c_sum = VariableBaseMSM::multi_scalar_mul(&c_bases, &coeffs_repr);
+
+    let scaled_a = proofs_and_inputs
+        .iter()
+        .zip(&coeffs_repr)
+        .map(|((proof, _), r)| proof.a.mul(*r))
+        .collect::<Vec<_>>();
+    let scaled_a = E::G1Projective::batch_normalization_into_affine(&scaled_a);
+
+    let mut pairs = scaled_a
+        .into_iter()
+        .zip(proofs_and_inputs)
+        .map(|(a, (proof, _))| (a.into(), proof.b.into()))
+        .collect::<Vec<_>>();
+    pairs.push((g_ic.into_affine().into(), pvk.gamma_g2_neg_pc.clone()));
+    pairs.push((c_sum.into_affine().into(), pvk.delta_g2_neg_pc.clone()));
+
+    let qap = E::miller_loop(pairs.iter());
+    let test = E::final_exponentiation(&qap).ok_or(SynthesisError::UnexpectedIdentity)?;
+    end_timer!(batch_time);
+
+    Ok(test == pvk.alpha_g1_beta_g2.pow(input_scalars[0].into_repr()))
+}
@@ -1,11 +1,16 @@
-#[cfg(feature = "std")]
-use crate::ConstraintTrace;
 use crate::{
-    format, vec, BTreeMap, LcIndex, LinearCombination, Matrix, Rc, String, SynthesisError,
-    Variable, Vec,
+    format, vec, Arc, BTreeMap, BTreeSet, LcIndex, LinearCombination, Matrix, Rc, String,
+    SynthesisError, ToString, Variable, Vec,
+};
+#[cfg(feature = "std")]
+use crate::{ConstraintProfile, ConstraintTrace};
+use algebra_core::{serialize::*, Field};
+use core::{
+    cell::{Ref, RefCell, RefMut},
+    fmt,
 };
-use algebra_core::Field;
-use core::cell::{Ref, RefCell, RefMut};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 /// Computations are expressed in terms of rank-1 constraint systems (R1CS).
 /// The `generate_constraints` method is called to generate constraints for
@@ -27,8 +32,12 @@ pub struct ConstraintSystem<F: Field> {
     /// proving mode (i.e., `self.mode == SynthesisMode::Prove`). If we are
     /// in proving mode, then we have the additional option of whether or
     /// not to construct the A, B, and C matrices of the constraint system
-    /// (see below).
+    /// (see below). Finally, `SynthesisMode::WitnessOnly` is a proving mode
+    /// that only keeps what is needed to compute a proof.
     pub mode: SynthesisMode,
+    /// The parameter we aim to minimize when finalizing the constraint
+    /// system (see `ConstraintSystem::finalize`).
+    pub optimization_goal: OptimizationGoal,
     /// The number of variables that are "public inputs" to the constraint
     /// system.
     pub num_instance_variables: usize,
@@ -48,15 +57,76 @@ pub struct ConstraintSystem<F: Field> {
     pub witness_assignment: Vec<F>,
 
     lc_map: BTreeMap<LcIndex, LinearCombination<F>>,
+    lc_num_times_used: BTreeMap<LcIndex, usize>,
 
     #[cfg(feature = "std")]
     constraint_traces: Vec<Option<ConstraintTrace>>,
+    #[cfg(feature = "std")]
+    instance_traces: Vec<Option<ConstraintTrace>>,
+    #[cfg(feature = "std")]
+    witness_traces: Vec<Option<ConstraintTrace>>,
 
     a_constraints: Vec<LcIndex>,
     b_constraints: Vec<LcIndex>,
     c_constraints: Vec<LcIndex>,
 
+    a_evaluations: Vec<F>,
+    b_evaluations: Vec<F>,
+    c_evaluations: Vec<F>,
+
     lc_assignment_cache: Rc<RefCell<BTreeMap<LcIndex, F>>>,
+    /// The values of the symbolic LCs created in witness-only mode, in order
+    /// of creation. None of them can be dropped early, since a gadget may
+    /// refer to any earlier symbolic LC.
+    lc_values: Vec<F>,
+
+    fork_base: Option<ForkBase<F>>,
+}
+
+/// The state of a parent `ConstraintSystem` at the time that children were
+/// forked off it via `ConstraintSystemRef::fork_join`. Variables and symbolic
+/// LCs with indices below the base counts belong to the parent, and the rest
+/// belong to the child.
+#[derive(Debug, Clone)]
+struct ForkBase<F: Field> {
+    mode: SynthesisMode,
+    optimization_goal: OptimizationGoal,
+    num_instance_variables: usize,
+    num_witness_variables: usize,
+    num_linear_combinations: usize,
+    instance_assignment: Arc<Vec<F>>,
+    witness_assignment: Arc<Vec<F>>,
+    lc_values: Arc<Vec<F>>,
+}
+
+/// The part of a child `ConstraintSystem` that was created after forking, in a
+/// form that can be sent back to the thread that owns the parent.
+struct ForkedFragment<F: Field> {
+    base_instance_variables: usize,
+    base_witness_variables: usize,
+    base_linear_combinations: usize,
+    num_instance_variables: usize,
+    num_witness_variables: usize,
+    num_linear_combinations: usize,
+    num_constraints: usize,
+    instance_assignment: Vec<F>,
+    witness_assignment: Vec<F>,
+    lc_map: BTreeMap<LcIndex, LinearCombination<F>>,
+    lc_num_times_used: BTreeMap<LcIndex, usize>,
+    a_constraints: Vec<LcIndex>,
+    b_constraints: Vec<LcIndex>,
+    c_constraints: Vec<LcIndex>,
+    a_evaluations: Vec<F>,
+    b_evaluations: Vec<F>,
+    c_evaluations: Vec<F>,
+    lc_values: Vec<F>,
+    #[cfg(feature = "std")]
+    constraint_traces: Vec<Option<ConstraintTrace>>,
+    #[cfg(feature = "std")]
+    instance_traces: Vec<Option<ConstraintTrace>>,
+    #[cfg(feature = "std")]
+    witness_traces: Vec<Option<ConstraintTrace>>,
+    outputs: Vec<Variable>,
 }
 
 /// Defines the mode of operation of a `ConstraintSystem`.
@@ -73,6 +143,25 @@ pub enum SynthesisMode {
         /// the matrices as in the `Setup` case.
         construct_matrices: bool,
     },
+    /// Indicate to the `ConstraintSystem` that it should only populate the
+    /// variable assignments and the evaluations `⟨a_i, z⟩`, `⟨b_i, z⟩` and
+    /// `⟨c_i, z⟩` of every constraint. Neither linear combinations nor traces
+    /// are stored, and symbolic LCs are evaluated as soon as they are created.
+    ///
+    /// This produces the same assignment as `Prove` when symbolic LCs are
+    /// inlined, but it does not support outlining them.
+    WitnessOnly,
+}
+
+/// Defines the parameter to optimize for when finalizing a `ConstraintSystem`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum OptimizationGoal {
+    /// Minimize the number of constraints by inlining every symbolic LC into
+    /// the places that use it.
+    Constraints,
+    /// Minimize the number of non-zero entries in the constraint matrices by
+    /// outlining symbolic LCs that are used in more than one place.
+    Weight,
 }
 
 impl<F: Field> ConstraintSystem<F> {
@@ -103,20 +192,197 @@ impl<F: Field> ConstraintSystem<F> {
             a_constraints: Vec::new(),
             b_constraints: Vec::new(),
             c_constraints: Vec::new(),
+            a_evaluations: Vec::new(),
+            b_evaluations: Vec::new(),
+            c_evaluations: Vec::new(),
             instance_assignment: vec![F::one()],
             witness_assignment: Vec::new(),
             #[cfg(feature = "std")]
             constraint_traces: Vec::new(),
+            #[cfg(feature = "std")]
+            instance_traces: Vec::new(),
+            #[cfg(feature = "std")]
+            witness_traces: Vec::new(),
 
             lc_map: BTreeMap::new(),
+            lc_num_times_used: BTreeMap::new(),
             lc_assignment_cache: Rc::new(RefCell::new(BTreeMap::new())),
+            lc_values: Vec::new(),
 
             mode: SynthesisMode::Prove {
                 construct_matrices: true,
             },
+            optimization_goal: OptimizationGoal::Constraints,
+            fork_base: None,
+        }
+    }
+
+    /// Construct an empty child of the constraint system described by `base`.
+    fn new_child(base: &ForkBase<F>) -> Self {
+        Self {
+            mode: base.mode,
+            optimization_goal: base.optimization_goal,
+            num_instance_variables: base.num_instance_variables,
+            num_witness_variables: base.num_witness_variables,
+            num_linear_combinations: base.num_linear_combinations,
+            instance_assignment: Vec::new(),
+            fork_base: Some(base.clone()),
+            ..Self::new()
+        }
+    }
+
+    /// Snapshot the current state of `self` for forking off children.
+    fn fork_base(&self) -> ForkBase<F> {
+        let (instance_assignment, witness_assignment) = match &self.fork_base {
+            Some(base) => (
+                [&base.instance_assignment[..], &self.instance_assignment[..]].concat(),
+                [&base.witness_assignment[..], &self.witness_assignment[..]].concat(),
+            ),
+            None => (
+                self.instance_assignment.clone(),
+                self.witness_assignment.clone(),
+            ),
+        };
+        // Children cannot see the LCs of `self`, so they need a copy of the
+        // values of its symbolic LCs. In witness-only mode these are already
+        // stored; otherwise they are evaluated here, unless there is no
+        // assignment to evaluate them with.
+        let lc_values = match &self.fork_base {
+            _ if self.is_in_setup_mode() => Vec::new(),
+            Some(base) if self.is_witness_only() => {
+                [&base.lc_values[..], &self.lc_values[..]].concat()
+            },
+            None if self.is_witness_only() => self.lc_values.clone(),
+            _ => (0..self.num_linear_combinations)
+                .map(|i| self.assigned_value(Variable::SymbolicLc(LcIndex(i))))
+                .collect::<Option<Vec<_>>>()
+                .unwrap_or_default(),
+        };
+        ForkBase {
+            mode: self.mode,
+            optimization_goal: self.optimization_goal,
+            num_instance_variables: self.num_instance_variables,
+            num_witness_variables: self.num_witness_variables,
+            num_linear_combinations: self.num_linear_combinations,
+            instance_assignment: Arc::new(instance_assignment),
+            witness_assignment: Arc::new(witness_assignment),
+            lc_values: Arc::new(lc_values),
         }
     }
 
+    /// Move everything that was created in the child `self` after forking into
+    /// a `ForkedFragment`.
+    fn take_fragment(&mut self, outputs: Vec<Variable>) -> ForkedFragment<F> {
+        let base = self.fork_base.as_ref().expect("should be a child");
+        ForkedFragment {
+            base_instance_variables: base.num_instance_variables,
+            base_witness_variables: base.num_witness_variables,
+            base_linear_combinations: base.num_linear_combinations,
+            num_instance_variables: self.num_instance_variables - base.num_instance_variables,
+            num_witness_variables: self.num_witness_variables - base.num_witness_variables,
+            num_linear_combinations: self.num_linear_combinations - base.num_linear_combinations,
+            num_constraints: self.num_constraints,
+            instance_assignment: core::mem::take(&mut self.instance_assignment),
+            witness_assignment: core::mem::take(&mut self.witness_assignment),
+            lc_map: core::mem::take(&mut self.lc_map),
+            lc_num_times_used: core::mem::take(&mut self.lc_num_times_used),
+            a_constraints: core::mem::take(&mut self.a_constraints),
+            b_constraints: core::mem::take(&mut self.b_constraints),
+            c_constraints: core::mem::take(&mut self.c_constraints),
+            a_evaluations: core::mem::take(&mut self.a_evaluations),
+            b_evaluations: core::mem::take(&mut self.b_evaluations),
+            c_evaluations: core::mem::take(&mut self.c_evaluations),
+            lc_values: core::mem::take(&mut self.lc_values),
+            #[cfg(feature = "std")]
+            constraint_traces: core::mem::take(&mut self.constraint_traces),
+            #[cfg(feature = "std")]
+            instance_traces: core::mem::take(&mut self.instance_traces),
+            #[cfg(feature = "std")]
+            witness_traces: core::mem::take(&mut self.witness_traces),
+            outputs,
+        }
+    }
+
+    /// Append the variables, symbolic LCs and constraints in `fragment` to
+    /// `self`, shifting their indices to follow those already in `self`.
+    /// Outputs the shifted `fragment.outputs`.
+    fn merge_fragment(&mut self, fragment: ForkedFragment<F>) -> Vec<Variable> {
+        let base_instance_variables = fragment.base_instance_variables;
+        let base_witness_variables = fragment.base_witness_variables;
+        let base_linear_combinations = fragment.base_linear_combinations;
+        let instance_offset = self.num_instance_variables - base_instance_variables;
+        let witness_offset = self.num_witness_variables - base_witness_variables;
+        let lc_offset = self.num_linear_combinations - base_linear_combinations;
+        let shift_lc_index = |LcIndex(i)| {
+            if i >= base_linear_combinations {
+                LcIndex(i + lc_offset)
+            } else {
+                LcIndex(i)
+            }
+        };
+        let shift = |var| match var {
+            Variable::Instance(i) if i >= base_instance_variables => {
+                Variable::Instance(i + instance_offset)
+            },
+            Variable::Witness(i) if i >= base_witness_variables => {
+                Variable::Witness(i + witness_offset)
+            },
+            Variable::SymbolicLc(index) => Variable::SymbolicLc(shift_lc_index(index)),
+            var => var,
+        };
+
+        for (index, lc) in fragment.lc_map {
+            let lc = lc.0.into_iter().map(|(coeff, var)| (coeff, shift(var)));
+            self.lc_map
+                .insert(shift_lc_index(index), LinearCombination(lc.collect()));
+        }
+        for (index, n) in fragment.lc_num_times_used {
+            *self
+                .lc_num_times_used
+                .entry(shift_lc_index(index))
+                .or_insert(0) += n;
+        }
+        let a = fragment.a_constraints.into_iter().map(shift_lc_index);
+        self.a_constraints.extend(a);
+        let b = fragment.b_constraints.into_iter().map(shift_lc_index);
+        self.b_constraints.extend(b);
+        let c = fragment.c_constraints.into_iter().map(shift_lc_index);
+        self.c_constraints.extend(c);
+        self.a_evaluations.extend(fragment.a_evaluations);
+        self.b_evaluations.extend(fragment.b_evaluations);
+        self.c_evaluations.extend(fragment.c_evaluations);
+        // The values are stored in order of creation, and the symbolic LCs of
+        // the fragment follow those already in `self`.
+        self.lc_values.extend(fragment.lc_values);
+
+        #[cfg(feature = "std")]
+        {
+            self.constraint_traces.extend(fragment.constraint_traces);
+            // Pad the traces of `self` to its own variables first, since
+            // they only extend as far as the last recorded trace.
+            if !fragment.instance_traces.is_empty() {
+                let (base, _) = self.base_num_variables();
+                let len = self.num_instance_variables - base;
+                self.instance_traces.resize_with(len, || None);
+                self.instance_traces.extend(fragment.instance_traces);
+            }
+            if !fragment.witness_traces.is_empty() {
+                let (_, base) = self.base_num_variables();
+                let len = self.num_witness_variables - base;
+                self.witness_traces.resize_with(len, || None);
+                self.witness_traces.extend(fragment.witness_traces);
+            }
+        }
+        self.num_instance_variables += fragment.num_instance_variables;
+        self.num_witness_variables += fragment.num_witness_variables;
+        self.num_linear_combinations += fragment.num_linear_combinations;
+        self.num_constraints += fragment.num_constraints;
+        self.instance_assignment
+            .extend(fragment.instance_assignment);
+        self.witness_assignment.extend(fragment.witness_assignment);
+        fragment.outputs.into_iter().map(shift).collect()
+    }
+
     /// Create a new `ConstraintSystemRef<F>`.
     pub fn new_ref() -> ConstraintSystemRef<F> {
         ConstraintSystemRef::new(Self::new())
@@ -132,11 +398,55 @@ impl<F: Field> ConstraintSystem<F> {
         self.mode == SynthesisMode::Setup
     }
 
+    /// Set `self.optimization_goal` to `goal`.
+    ///
+    /// This must be called before constraints are generated, since uses of
+    /// symbolic LCs are only counted when the goal is
+    /// `OptimizationGoal::Weight`.
+    pub fn set_optimization_goal(&mut self, goal: OptimizationGoal) {
+        self.optimization_goal = goal;
+    }
+
+    /// Returns the parameter that `self.finalize()` will optimize for.
+    pub fn optimization_goal(&self) -> OptimizationGoal {
+        self.optimization_goal
+    }
+
     /// Check whether or not `self` will construct matrices.
     pub fn should_construct_matrices(&self) -> bool {
         match self.mode {
             SynthesisMode::Setup => true,
             SynthesisMode::Prove { construct_matrices } => construct_matrices,
+            SynthesisMode::WitnessOnly => false,
+        }
+    }
+
+    fn is_witness_only(&self) -> bool {
+        self.mode == SynthesisMode::WitnessOnly
+    }
+
+    /// The numbers of instance and witness variables that `self` was forked
+    /// off with, or zero if `self` is not a child.
+    #[cfg(feature = "std")]
+    fn base_num_variables(&self) -> (usize, usize) {
+        self.fork_base.as_ref().map_or((0, 0), |b| {
+            (b.num_instance_variables, b.num_witness_variables)
+        })
+    }
+
+    /// Record the current trace for the `local_index`-th instance or witness
+    /// variable created by `self` (as opposed to its fork base).
+    ///
+    /// Traces are only captured when a `ConstraintLayer` is enabled, so
+    /// `traces` only grows as far as the last recorded trace, and variables
+    /// past its end have no trace.
+    #[cfg(feature = "std")]
+    fn record_trace(traces: &mut Vec<Option<ConstraintTrace>>, local_index: usize) {
+        if ConstraintTrace::is_enabled() {
+            if let Some(trace) = ConstraintTrace::capture() {
+                traces.resize_with(local_index, || None);
+                traces.push(Some(trace));
+            }
         }
     }
 
@@ -162,6 +472,11 @@ impl<F: Field> ConstraintSystem<F> {
     {
         let index = self.num_instance_variables;
         self.num_instance_variables += 1;
+        #[cfg(feature = "std")]
+        if !self.is_witness_only() {
+            let (base, _) = self.base_num_variables();
+            Self::record_trace(&mut self.instance_traces, index - base);
+        }
 
         if !self.is_in_setup_mode() {
             self.instance_assignment.push(f()?);
@@ -177,6 +492,11 @@ impl<F: Field> ConstraintSystem<F> {
     {
         let index = self.num_witness_variables;
         self.num_witness_variables += 1;
+        #[cfg(feature = "std")]
+        if !self.is_witness_only() {
+            let (_, base) = self.base_num_variables();
+            Self::record_trace(&mut self.witness_traces, index - base);
+        }
 
         if !self.is_in_setup_mode() {
             self.witness_assignment.push(f()?);
@@ -190,7 +510,15 @@ impl<F: Field> ConstraintSystem<F> {
         let index = LcIndex(self.num_linear_combinations);
         let var = Variable::SymbolicLc(index);
 
-        self.lc_map.insert(index, lc);
+        if self.is_witness_only() {
+            let value = self
+                .eval_linear_combination(&lc)
+                .ok_or(SynthesisError::AssignmentMissing)?;
+            self.lc_values.push(value);
+        } else {
+            self.count_lc_uses(&lc);
+            self.lc_map.insert(index, lc);
+        }
 
         self.num_linear_combinations += 1;
         Ok(var)
@@ -204,6 +532,19 @@ impl<F: Field> ConstraintSystem<F> {
         b: LinearCombination<F>,
         c: LinearCombination<F>,
     ) -> Result<(), SynthesisError> {
+        if self.is_witness_only() {
+            let a = self.eval_linear_combination(&a);
+            let b = self.eval_linear_combination(&b);
+            let c = self.eval_linear_combination(&c);
+            self.a_evaluations
+                .push(a.ok_or(SynthesisError::AssignmentMissing)?);
+            self.b_evaluations
+                .push(b.ok_or(SynthesisError::AssignmentMissing)?);
+            self.c_evaluations
+                .push(c.ok_or(SynthesisError::AssignmentMissing)?);
+            self.num_constraints += 1;
+            return Ok(());
+        }
         if self.should_construct_matrices() {
             let a_index = self.new_lc(a)?.get_lc_index().unwrap();
             let b_index = self.new_lc(b)?.get_lc_index().unwrap();
@@ -211,6 +552,12 @@ impl<F: Field> ConstraintSystem<F> {
             self.a_constraints.push(a_index);
             self.b_constraints.push(b_index);
             self.c_constraints.push(c_index);
+        } else {
+            // Symbolic LCs used by this constraint still have to be counted,
+            // so that `outline_lcs` produces the same variables as in setup.
+            self.count_lc_uses(&a);
+            self.count_lc_uses(&b);
+            self.count_lc_uses(&c);
         }
         self.num_constraints += 1;
         #[cfg(feature = "std")]
@@ -259,18 +606,108 @@ impl<F: Field> ConstraintSystem<F> {
     ///
     /// Useful for SNARKs like `Marlin` or `Fractal`, where addition gates
     /// are not cheap.
-    pub fn outline_lcs(&mut self) {
-        unimplemented!()
+    ///
+    /// The new variables are allocated in the order of the `SymbolicLc`s that
+    /// they replace, so setup and proving produce the same variable layout.
+    ///
+    /// Uses are only counted when `self.optimization_goal` is
+    /// `OptimizationGoal::Weight`; under any other goal, no LC is outlined.
+    ///
+    /// Returns `SynthesisError::LcsNotStored` if
+    /// `self.mode == SynthesisMode::WitnessOnly`, because the symbolic LCs
+    /// that would have to be outlined are not stored.
+    pub fn outline_lcs(&mut self) -> Result<(), SynthesisError> {
+        if self.is_witness_only() {
+            return Err(SynthesisError::LcsNotStored);
+        }
+        let lc_map = core::mem::take(&mut self.lc_map);
+        let mut outlined_lcs = BTreeMap::new();
+        let mut new_constraints = Vec::new();
+        for (index, lc) in lc_map {
+            let mut inlined_lc = LinearCombination::new();
+            for (coeff, var) in lc.0 {
+                if let Some(lc_index) = var.get_lc_index() {
+                    // If `var` is a `SymbolicLc`, substitute in either the
+                    // variable it was outlined into, or its inlined LC.
+                    let lc = outlined_lcs.get(&lc_index).expect("should be outlined");
+                    inlined_lc.extend((lc * coeff).0);
+                } else {
+                    inlined_lc.push((coeff, var));
+                }
+            }
+            inlined_lc.compactify();
+
+            let num_times_used = self.lc_num_times_used.get(&index).copied().unwrap_or(0);
+            if num_times_used > 1 && inlined_lc.len() > 1 {
+                let var = self.new_outlined_variable(&inlined_lc);
+                new_constraints.push((var, inlined_lc));
+                inlined_lc = LinearCombination::from(var);
+            }
+            outlined_lcs.insert(index, inlined_lc);
+        }
+        self.lc_map = outlined_lcs;
+        for (var, lc) in new_constraints {
+            self.enforce_constraint(var.into(), Variable::One.into(), lc)?;
+        }
+        Ok(())
+    }
+
+    /// Finalize the constraint system by either inlining or outlining symbolic
+    /// LCs, depending on `self.optimization_goal`.
+    ///
+    /// This must be called after constraint generation has completed, and
+    /// before `self.to_matrices()`.
+    pub fn finalize(&mut self) -> Result<(), SynthesisError> {
+        match self.optimization_goal {
+            OptimizationGoal::Constraints => {
+                self.inline_all_lcs();
+                Ok(())
+            },
+            OptimizationGoal::Weight => self.outline_lcs(),
+        }
+    }
+
+    /// Counts the uses of symbolic LCs in `lc`. The counts are only read by
+    /// `outline_lcs`, so this does nothing unless the optimization goal is
+    /// `OptimizationGoal::Weight`.
+    fn count_lc_uses(&mut self, lc: &LinearCombination<F>) {
+        if self.optimization_goal != OptimizationGoal::Weight {
+            return;
+        }
+        for (_, var) in lc.iter() {
+            if let Some(index) = var.get_lc_index() {
+                *self.lc_num_times_used.entry(index).or_insert(0) += 1;
+            }
+        }
+    }
+
+    fn new_outlined_variable(&mut self, lc: &LinearCombination<F>) -> Variable {
+        let index = self.num_witness_variables;
+        self.num_witness_variables += 1;
+        #[cfg(feature = "std")]
+        {
+            let (_, base) = self.base_num_variables();
+            Self::record_trace(&mut self.witness_traces, index - base);
+        }
+
+        if !self.is_in_setup_mode() {
+            let mut value = F::zero();
+            for (coeff, var) in lc.iter() {
+                value += *coeff
+                    * self
+                        .assigned_value(*var)
+                        .expect("all concrete variables should be assigned");
+            }
+            self.witness_assignment.push(value);
+        }
+        Variable::Witness(index)
     }
 
     /// This step must be called after constraint generation has completed, and
-    /// after all symbolic LCs have been inlined into the places that they
-    /// are used.
+    /// after all symbolic LCs have been inlined or outlined (see
+    /// `Self::finalize`).
     pub fn to_matrices(&self) -> Option<ConstraintMatrices<F>> {
-        if let SynthesisMode::Prove {
-            construct_matrices: false,
-        } = self.mode
-        {
+        if !self.should_construct_matrices() {
             None
         } else {
             let a: Vec<_> = self
@@ -309,15 +746,49 @@ impl<F: Field> ConstraintSystem<F> {
         }
     }
 
+    /// Outputs the evaluations `(⟨a_i, z⟩, ⟨b_i, z⟩, ⟨c_i, z⟩)` of all
+    /// constraints, in order, if `self.mode == SynthesisMode::WitnessOnly`.
+    pub fn constraint_evaluations(&self) -> Option<(&[F], &[F], &[F])> {
+        if self.is_witness_only() {
+            Some((
+                &self.a_evaluations,
+                &self.b_evaluations,
+                &self.c_evaluations,
+            ))
+        } else {
+            None
+        }
+    }
+
     fn eval_lc(&self, lc: LcIndex) -> Option<F> {
-        let lc = self.lc_map.get(&lc)?;
+        self.eval_linear_combination(self.lc_map.get(&lc)?)
+    }
+
+    fn eval_linear_combination(&self, lc: &LinearCombination<F>) -> Option<F> {
         let mut acc = F::zero();
         for (coeff, var) in lc.iter() {
-            acc += *coeff * &self.assigned_value(*var)?;
+            acc += *coeff * self.assigned_value(*var)?;
         }
         Some(acc)
     }
 
+    /// Evaluate the linear combinations of the `i`-th constraint.
+    fn eval_constraint(&self, i: usize) -> Option<(F, F, F)> {
+        if self.is_witness_only() {
+            Some((
+                self.a_evaluations[i],
+                self.b_evaluations[i],
+                self.c_evaluations[i],
+            ))
+        } else {
+            Some((
+                self.eval_lc(self.a_constraints[i])?,
+                self.eval_lc(self.b_constraints[i])?,
+                self.eval_lc(self.c_constraints[i])?,
+            ))
+        }
+    }
+
     /// If `self` is satisfied, outputs `Ok(true)`.
     /// If `self` is unsatisfied, outputs `Ok(false)`.
     /// If `self.is_in_setup_mode()`, outputs `Err(())`.
@@ -331,39 +802,236 @@ impl<F: Field> ConstraintSystem<F> {
     /// `Err(())`.
     pub fn which_is_unsatisfied(&self) -> Result<Option<String>, SynthesisError> {
         if self.is_in_setup_mode() {
-            Err(SynthesisError::AssignmentMissing)
+            return Err(SynthesisError::AssignmentMissing);
+        }
+        for i in 0..self.num_constraints {
+            if let Some(constraint) = self.unsatisfied_constraint(i)? {
+                let index = constraint.index;
+                return Ok(Some(
+                    constraint.trace.unwrap_or_else(|| format!("{}", index)),
+                ));
+            }
+        }
+        Ok(None)
+    }
+
+    /// If `self` is satisfied, outputs `Ok(vec![])`.
+    /// If `self` is unsatisfied, outputs a report for every unsatisfied
+    /// constraint, containing its trace, the values of its linear
+    /// combinations, and the values of the variables it depends on.
+    /// If `self.is_in_setup_mode()`, outputs `Err(())`.
+    pub fn unsatisfied_constraints(&self) -> Result<Vec<UnsatisfiedConstraint<F>>, SynthesisError> {
+        if self.is_in_setup_mode() {
+            return Err(SynthesisError::AssignmentMissing);
+        }
+        let mut unsatisfied = Vec::new();
+        for i in 0..self.num_constraints {
+            unsatisfied.extend(self.unsatisfied_constraint(i)?);
+        }
+        Ok(unsatisfied)
+    }
+
+    /// Outputs a report on the `i`-th constraint if it is unsatisfied.
+    fn unsatisfied_constraint(
+        &self,
+        i: usize,
+    ) -> Result<Option<UnsatisfiedConstraint<F>>, SynthesisError> {
+        let (a, b, c) = self
+            .eval_constraint(i)
+            .ok_or(SynthesisError::AssignmentMissing)?;
+        if a * b == c {
+            return Ok(None);
+        }
+        let variables = if self.is_witness_only() {
+            Vec::new()
         } else {
-            for i in 0..self.num_constraints {
-                let a = self
-                    .eval_lc(self.a_constraints[i])
-                    .ok_or(SynthesisError::AssignmentMissing)?;
-                let b = self
-                    .eval_lc(self.b_constraints[i])
-                    .ok_or(SynthesisError::AssignmentMissing)?;
-                let c = self
-                    .eval_lc(self.c_constraints[i])
-                    .ok_or(SynthesisError::AssignmentMissing)?;
-                if a * b != c {
-                    let trace;
-                    #[cfg(feature = "std")]
-                    {
-                        trace = self.constraint_traces[i].as_ref().map_or_else(
-                            || {
-                                eprintln!("Constraint trace requires enabling `ConstraintLayer`");
-                                format!("{}", i)
-                            },
-                            |t| format!("{}", t),
-                        );
-                    }
-                    #[cfg(not(feature = "std"))]
-                    {
-                        trace = format!("{}", i);
-                    }
-                    return Ok(Some(trace));
+            let lcs = [
+                self.a_constraints[i],
+                self.b_constraints[i],
+                self.c_constraints[i],
+            ];
+            self.lc_variables(&lcs)
+                .ok_or(SynthesisError::AssignmentMissing)?
+        };
+        Ok(Some(UnsatisfiedConstraint {
+            index: i,
+            trace: self.trace_of(TraceKind::Constraint, i),
+            a,
+            b,
+            c,
+            variables,
+        }))
+    }
+
+    /// Analyze `self` for common mistakes in constraint generation, such as
+    /// witness variables that are not constrained, instance variables that
+    /// are not used, duplicate constraints, and constraints that are satisfied
+    /// by every assignment. Symbolic LCs are inlined before the analysis.
+    ///
+    /// Outputs `None` if `self` does not construct matrices.
+    pub fn lint(&self) -> Option<Vec<Lint>> {
+        if !self.should_construct_matrices() {
+            return None;
+        }
+        let mut inlined_lcs: BTreeMap<LcIndex, Vec<(F, Variable)>> = BTreeMap::new();
+        for (&index, lc) in &self.lc_map {
+            let mut inlined_lc = LinearCombination::new();
+            for &(coeff, var) in lc.iter() {
+                match var.get_lc_index() {
+                    Some(lc_index) => inlined_lc
+                        .extend(inlined_lcs[&lc_index].iter().map(|(c, v)| (coeff * c, *v))),
+                    None => inlined_lc.push((coeff, var)),
+                }
+            }
+            inlined_lc.compactify();
+            inlined_lc.retain(|(coeff, var)| !coeff.is_zero() && !var.is_zero());
+            inlined_lcs.insert(index, inlined_lc.0);
+        }
+
+        let mut lints = Vec::new();
+        let mut used_instances = vec![false; self.num_instance_variables];
+        let mut used_witnesses = vec![false; self.num_witness_variables];
+        let mut seen_constraints = BTreeMap::new();
+        for i in 0..self.num_constraints {
+            let a = &inlined_lcs[&self.a_constraints[i]];
+            let b = &inlined_lcs[&self.b_constraints[i]];
+            let c = &inlined_lcs[&self.c_constraints[i]];
+            for (_, var) in a.iter().chain(b).chain(c) {
+                match var {
+                    Variable::Instance(j) => used_instances[*j] = true,
+                    Variable::Witness(j) => used_witnesses[*j] = true,
+                    _ => {},
+                }
+            }
+
+            let constant = |lc: &[(F, Variable)]| match lc {
+                [] => Some(F::zero()),
+                [(coeff, Variable::One)] => Some(*coeff),
+                _ => None,
+            };
+            let trivially_satisfied = ((a.is_empty() || b.is_empty()) && c.is_empty())
+                || match (constant(a), constant(b), constant(c)) {
+                    (Some(a), Some(b), Some(c)) => a * b == c,
+                    _ => false,
+                };
+            let kind = if trivially_satisfied {
+                Some(LintKind::TriviallySatisfiedConstraint(i))
+            } else {
+                // `a` and `b` commute, so we order them before comparing.
+                let key = if a <= b { (a, b, c) } else { (b, a, c) };
+                seen_constraints
+                    .insert(key, i)
+                    .map(|original| LintKind::DuplicateConstraint { index: i, original })
+            };
+            if let Some(kind) = kind {
+                lints.push(Lint {
+                    kind,
+                    trace: self.trace_of(TraceKind::Constraint, i),
+                });
+            }
+        }
+
+        for (i, used) in used_instances.into_iter().enumerate().skip(1) {
+            if !used {
+                lints.push(Lint {
+                    kind: LintKind::UnusedInstanceVariable(i),
+                    trace: self.trace_of(TraceKind::Instance, i),
+                });
+            }
+        }
+        for (i, used) in used_witnesses.into_iter().enumerate() {
+            if !used {
+                lints.push(Lint {
+                    kind: LintKind::UnconstrainedWitnessVariable(i),
+                    trace: self.trace_of(TraceKind::Witness, i),
+                });
+            }
+        }
+        Some(lints)
+    }
+
+    /// Obtain the namespace path at which the `index`-th constraint or variable
+    /// of the given kind was created, if it was recorded.
+    #[allow(unused_variables)]
+    fn trace_of(&self, kind: TraceKind, index: usize) -> Option<String> {
+        #[cfg(feature = "std")]
+        {
+            let traces = match kind {
+                TraceKind::Constraint => &self.constraint_traces,
+                TraceKind::Instance => &self.instance_traces,
+                TraceKind::Witness => &self.witness_traces,
+            };
+            traces
+                .get(index)?
+                .as_ref()
+                .map(|t| trace_path(t).iter().map(|s| ["/", s].join("")).collect())
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            None
+        }
+    }
+
+    /// Collect the instance and witness variables that the linear
+    /// combinations in `lcs` depend on, together with their assignments.
+    fn lc_variables(&self, lcs: &[LcIndex]) -> Option<Vec<(Variable, F)>> {
+        let mut variables = BTreeMap::new();
+        let mut visited = BTreeSet::new();
+        let mut stack = lcs.to_vec();
+        while let Some(index) = stack.pop() {
+            if !visited.insert(index) {
+                continue;
+            }
+            for (_, var) in self.lc_map.get(&index)?.iter() {
+                match var {
+                    Variable::SymbolicLc(i) => stack.push(*i),
+                    Variable::Instance(_) | Variable::Witness(_) => {
+                        variables.insert(*var, self.assigned_value(*var)?);
+                    },
+                    Variable::Zero | Variable::One => {},
                 }
             }
-            Ok(None)
         }
+        Some(variables.into_iter().collect())
+    }
+
+    /// Compute a `ConstraintProfile` that breaks down the number of
+    /// constraints, witness variables, and non-zero matrix entries by
+    /// namespace.
+    ///
+    /// Namespaces are only recorded when a `ConstraintLayer` is enabled;
+    /// everything else is attributed to the root of the profile. Non-zero
+    /// entries are only counted if `self` constructs matrices, and are exact
+    /// only after `self.finalize()` has been called.
+    #[cfg(feature = "std")]
+    pub fn profile(&self) -> ConstraintProfile {
+        let mut profile = ConstraintProfile::new();
+        for (i, trace) in self.constraint_traces.iter().enumerate() {
+            let num_nonzero_entries = if self.should_construct_matrices() {
+                [
+                    &self.a_constraints,
+                    &self.b_constraints,
+                    &self.c_constraints,
+                ]
+                .iter()
+                .map(|m| {
+                    self.lc_map[&m[i]]
+                        .iter()
+                        .filter(|(coeff, _)| !coeff.is_zero())
+                        .count()
+                })
+                .sum()
+            } else {
+                0
+            };
+            profile.add_constraint(trace.as_ref(), num_nonzero_entries);
+        }
+        for i in 0..self.num_witness_variables {
+            let trace = self.witness_traces.get(i).and_then(Option::as_ref);
+            profile.add_witness_variable(trace);
+        }
+        profile.compute_totals();
+        profile
     }
 
     /// Obtain the assignment corresponding to the `Variable` `v`.
@@ -371,25 +1039,160 @@ impl<F: Field> ConstraintSystem<F> {
         match v {
             Variable::One => Some(F::one()),
             Variable::Zero => Some(F::zero()),
-            Variable::Witness(idx) => self.witness_assignment.get(idx).copied(),
-            Variable::Instance(idx) => self.instance_assignment.get(idx).copied(),
-            Variable::SymbolicLc(idx) => {
-                let value = self.lc_assignment_cache.borrow().get(&idx).copied();
-                if value.is_some() {
-                    value
-                } else {
-                    let value = self.eval_lc(idx)?;
-                    self.lc_assignment_cache.borrow_mut().insert(idx, value);
-                    Some(value)
-                }
+            Variable::Witness(idx) => match &self.fork_base {
+                Some(base) if idx < base.num_witness_variables => {
+                    base.witness_assignment.get(idx).copied()
+                },
+                Some(base) => self
+                    .witness_assignment
+                    .get(idx - base.num_witness_variables)
+                    .copied(),
+                None => self.witness_assignment.get(idx).copied(),
+            },
+            Variable::Instance(idx) => match &self.fork_base {
+                Some(base) if idx < base.num_instance_variables => {
+                    base.instance_assignment.get(idx).copied()
+                },
+                Some(base) => self
+                    .instance_assignment
+                    .get(idx - base.num_instance_variables)
+                    .copied(),
+                None => self.instance_assignment.get(idx).copied(),
+            },
+            Variable::SymbolicLc(idx) => match &self.fork_base {
+                Some(base) if idx.0 < base.num_linear_combinations => {
+                    base.lc_values.get(idx.0).copied()
+                },
+                Some(base) if self.is_witness_only() => self
+                    .lc_values
+                    .get(idx.0 - base.num_linear_combinations)
+                    .copied(),
+                None if self.is_witness_only() => self.lc_values.get(idx.0).copied(),
+                _ => {
+                    let value = self.lc_assignment_cache.borrow().get(&idx).copied();
+                    if value.is_some() {
+                        value
+                    } else {
+                        let value = self.eval_lc(idx)?;
+                        self.lc_assignment_cache.borrow_mut().insert(idx, value);
+                        Some(value)
+                    }
+                },
+            },
+        }
+    }
+}
+#[derive(Copy, Clone)]
+enum TraceKind {
+    Constraint,
+    Instance,
+    Witness,
+}
+
+/// A potential problem in a `ConstraintSystem`, as found by
+/// `ConstraintSystem::lint`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    /// The kind of problem.
+    pub kind: LintKind,
+    /// The namespace path at which the offending constraint or variable was
+    /// created. This is `None` if `ConstraintLayer` was not enabled during
+    /// constraint generation.
+    pub trace: Option<String>,
+}
+
+/// The kinds of problems found by `ConstraintSystem::lint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    /// The witness variable with the given index does not appear in any
+    /// constraint, and so can be assigned arbitrarily.
+    UnconstrainedWitnessVariable(usize),
+    /// The instance variable with the given index does not appear in any
+    /// constraint, and so has no effect on the validity of proofs.
+    UnusedInstanceVariable(usize),
+    /// The constraint `index` is identical to the earlier constraint
+    /// `original`, up to swapping `a` and `b`.
+    DuplicateConstraint {
+        /// The index of the duplicate constraint.
+        index: usize,
+        /// The index of the first occurrence of the constraint.
+        original: usize,
+    },
+    /// The constraint with the given index is satisfied by every assignment,
+    /// such as `0 ⋅ x = 0`.
+    TriviallySatisfiedConstraint(usize),
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            LintKind::UnconstrainedWitnessVariable(i) => {
+                write!(f, "witness variable {} is unconstrained", i)?
+            },
+            LintKind::UnusedInstanceVariable(i) => write!(f, "instance variable {} is unused", i)?,
+            LintKind::DuplicateConstraint { index, original } => write!(
+                f,
+                "constraint {} is a duplicate of constraint {}",
+                index, original
+            )?,
+            LintKind::TriviallySatisfiedConstraint(i) => {
+                write!(f, "constraint {} is trivially satisfied", i)?
+            },
+        }
+        if let Some(trace) = &self.trace {
+            write!(f, " at {}", trace)?;
+        }
+        Ok(())
+    }
+}
+
+/// A report on a constraint `⟨a_i, z⟩ ⋅ ⟨b_i, z⟩ = ⟨c_i, z⟩` that is not
+/// satisfied by the assignment `z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsatisfiedConstraint<F: Field> {
+    /// The index `i` of the constraint.
+    pub index: usize,
+    /// The namespace path of the constraint. This is `None` if
+    /// `ConstraintLayer` was not enabled during constraint generation.
+    pub trace: Option<String>,
+    /// The value of `⟨a_i, z⟩`.
+    pub a: F,
+    /// The value of `⟨b_i, z⟩`.
+    pub b: F,
+    /// The value of `⟨c_i, z⟩`.
+    pub c: F,
+    /// The instance and witness variables that the constraint depends on,
+    /// together with their assignments. This is empty in witness-only mode,
+    /// where linear combinations are not stored.
+    pub variables: Vec<(Variable, F)>,
+}
+
+impl<F: Field> fmt::Display for UnsatisfiedConstraint<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "constraint {}", self.index)?;
+        if let Some(trace) = &self.trace {
+            write!(f, " at {}", trace)?;
+        }
+        writeln!(f, " is unsatisfied:")?;
+        writeln!(f, "    ⟨a, z⟩ = {}", self.a)?;
+        writeln!(f, "    ⟨b, z⟩ = {}", self.b)?;
+        writeln!(f, "    ⟨c, z⟩ = {}", self.c)?;
+        write!(f, "    ⟨a, z⟩ ⋅ ⟨b, z⟩ = {}", self.a * self.b)?;
+        for (var, value) in &self.variables {
+            match var {
+                Variable::Instance(i) => write!(f, "\n    instance {} = {}", i, value)?,
+                Variable::Witness(i) => write!(f, "\n    witness {} = {}", i, value)?,
+                _ => unreachable!("only instance and witness variables are reported"),
             }
         }
+        Ok(())
     }
 }
+
 /// The A, B and C matrices of a Rank-One `ConstraintSystem`.
 /// Also contains metadata on the structure of the constraint system
 /// and the matrices.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct ConstraintMatrices<F: Field> {
     /// The number of variables that are "public instances" to the constraint
     /// system.
@@ -547,6 +1350,21 @@ impl<F: Field> ConstraintSystemRef<F> {
             .map_or(false, |cs| cs.borrow().is_in_setup_mode())
     }
 
+    /// Set `self.optimization_goal` to `goal`. This must be called before
+    /// constraints are generated.
+    pub fn set_optimization_goal(&self, goal: OptimizationGoal) {
+        self.inner()
+            .map_or((), |cs| cs.borrow_mut().set_optimization_goal(goal))
+    }
+
+    /// Returns the parameter that `self.finalize()` will optimize for.
+    #[inline]
+    pub fn optimization_goal(&self) -> OptimizationGoal {
+        self.inner().map_or(OptimizationGoal::Constraints, |cs| {
+            cs.borrow().optimization_goal()
+        })
+    }
+
     /// Returns the number of constraints.
     #[inline]
     pub fn num_constraints(&self) -> usize {
@@ -654,15 +1472,23 @@ impl<F: Field> ConstraintSystemRef<F> {
     ///
     /// Useful for SNARKs like `Marlin` or `Fractal`, where where addition gates
     /// are not (entirely) free.
-    pub fn outline_lcs(&self) {
-        if let Some(cs) = self.inner() {
-            cs.borrow_mut().outline_lcs()
-        }
+    pub fn outline_lcs(&self) -> Result<(), SynthesisError> {
+        self.inner()
+            .map_or(Ok(()), |cs| cs.borrow_mut().outline_lcs())
+    }
+
+    /// Finalize the constraint system by either inlining or outlining symbolic
+    /// LCs, depending on `self.optimization_goal()`.
+    ///
+    /// This must be called after constraint generation has completed, and
+    /// before `self.to_matrices()`.
+    pub fn finalize(&self) -> Result<(), SynthesisError> {
+        self.inner().map_or(Ok(()), |cs| cs.borrow_mut().finalize())
     }
 
     /// This step must be called after constraint generation has completed, and
-    /// after all symbolic LCs have been inlined into the places that they
-    /// are used.
+    /// after all symbolic LCs have been inlined or outlined (see
+    /// `Self::finalize`).
     #[inline]
     pub fn to_matrices(&self) -> Option<ConstraintMatrices<F>> {
         self.inner().map_or(None, |cs| cs.borrow().to_matrices())
@@ -689,6 +1515,93 @@ impl<F: Field> ConstraintSystemRef<F> {
             })
     }
 
+    /// If `self` is satisfied, outputs `Ok(vec![])`.
+    /// If `self` is unsatisfied, outputs a report for every unsatisfied
+    /// constraint.
+    /// If `self.is_in_setup_mode()` or `self == None`, outputs `Err(())`.
+    pub fn unsatisfied_constraints(&self) -> Result<Vec<UnsatisfiedConstraint<F>>, SynthesisError> {
+        self.inner()
+            .map_or(Err(SynthesisError::AssignmentMissing), |cs| {
+                cs.borrow().unsatisfied_constraints()
+            })
+    }
+
+    /// If `self` is satisfied, outputs `Ok(())`.
+    /// If `self` is unsatisfied, outputs `Err(SynthesisError::Unsatisfiable)`;
+    /// the report on every unsatisfied constraint is available from
+    /// `self.unsatisfied_constraints()`.
+    /// If `self.is_in_setup_mode()` or `self == None`, outputs `Err(())`.
+    pub fn check_satisfied(&self) -> Result<(), SynthesisError> {
+        if self.unsatisfied_constraints()?.is_empty() {
+            Ok(())
+        } else {
+            Err(SynthesisError::Unsatisfiable)
+        }
+    }
+
+    /// Generate the constraints of independent sub-circuits, one for each
+    /// element of `inputs`, in parallel (if the `parallel` feature is
+    /// enabled).
+    ///
+    /// For each input, `f` is called with a fresh child constraint system
+    /// that continues from the current state of `self`: it can refer to the
+    /// variables of `self` and read their assignments, but cannot see
+    /// anything created by its siblings. After all children are done, they
+    /// are merged back into `self` in the order of `inputs`, so the resulting
+    /// variable layout does not depend on scheduling, and is the same in
+    /// setup and proving mode.
+    ///
+    /// `f` outputs the variables that `self` needs to refer to after merging.
+    /// These are translated into variables of `self`, and returned in the
+    /// order of `inputs`.
+    ///
+    /// Children read the assignment and the values of the symbolic LCs of
+    /// `self` from a snapshot taken before forking, which takes time linear
+    /// in the size of `self`.
+    pub fn fork_join<I, G>(
+        &self,
+        inputs: Vec<I>,
+        f: G,
+    ) -> Result<Vec<Vec<Variable>>, SynthesisError>
+    where
+        I: Send,
+        G: Fn(ConstraintSystemRef<F>, I) -> Result<Vec<Variable>, SynthesisError> + Sync,
+    {
+        let cs = self.inner().ok_or(SynthesisError::MissingCS)?;
+        let base = cs.borrow().fork_base();
+        #[cfg(feature = "std")]
+        let span = tracing::Span::current();
+
+        let generate = |input: I| {
+            // Keep the namespace of the parent in the traces of the child.
+            #[cfg(feature = "std")]
+            let _guard = span.enter();
+            let child = ConstraintSystemRef::new(ConstraintSystem::new_child(&base));
+            let outputs = f(child.clone(), input)?;
+            let fragment = child.borrow_mut().unwrap().take_fragment(outputs);
+            Ok(fragment)
+        };
+        #[cfg(feature = "parallel")]
+        let fragments: Result<Vec<_>, SynthesisError> =
+            inputs.into_par_iter().map(generate).collect();
+        #[cfg(not(feature = "parallel"))]
+        let fragments: Result<Vec<_>, SynthesisError> = inputs.into_iter().map(generate).collect();
+
+        let mut cs = cs.borrow_mut();
+        Ok(fragments?
+            .into_iter()
+            .map(|fragment| cs.merge_fragment(fragment))
+            .collect())
+    }
+
+    /// Analyze `self` for common mistakes in constraint generation (see
+    /// `ConstraintSystem::lint`).
+    /// Outputs `None` if `self == None` or if `self` does not construct
+    /// matrices.
+    pub fn lint(&self) -> Option<Vec<Lint>> {
+        self.inner().and_then(|cs| cs.borrow().lint())
+    }
+
     /// Obtain the assignment corresponding to the `Variable` `v`.
     pub fn assigned_value(&self, v: Variable) -> Option<F> {
         self.inner()
@@ -704,38 +1617,8 @@ impl<F: Field> ConstraintSystemRef<F> {
                     .constraint_traces
                     .iter()
                     .map(|trace| {
-                        let mut constraint_path = String::new();
-                        let mut prev_module_path = "";
-                        let mut prefixes = crate::BTreeSet::new();
-                        for step in trace.as_ref()?.path() {
-                            let module_path = if prev_module_path == step.module_path {
-                                prefixes.insert(step.module_path.to_string());
-                                String::new()
-                            } else {
-                                let mut parts = step
-                                    .module_path
-                                    .split("::")
-                                    .filter(|&part| part != "r1cs_std" && part != "constraints");
-                                let mut path_so_far = String::new();
-                                for part in parts.by_ref() {
-                                    if path_so_far.is_empty() {
-                                        path_so_far += part;
-                                    } else {
-                                        path_so_far += &["::", part].join("");
-                                    }
-                                    if prefixes.contains(&path_so_far) {
-                                        continue;
-                                    } else {
-                                        prefixes.insert(path_so_far.clone());
-                                        break;
-                                    }
-                                }
-                                parts.collect::<Vec<_>>().join("::") + "::"
-                            };
-                            prev_module_path = step.module_path;
-                            constraint_path += &["/", &module_path, step.name].join("");
-                        }
-                        Some(constraint_path)
+                        let path = trace_path(trace.as_ref()?);
+                        Some(path.iter().map(|step| ["/", step].join("")).collect())
                     })
                     .collect::<Option<Vec<_>>>()
             })
@@ -745,6 +1628,53 @@ impl<F: Field> ConstraintSystemRef<F> {
             None
         }
     }
+
+    /// Compute a `ConstraintProfile` that breaks down the number of
+    /// constraints, witness variables, and non-zero matrix entries by
+    /// namespace. Outputs `None` if `self == None`.
+    #[cfg(feature = "std")]
+    pub fn profile(&self) -> Option<ConstraintProfile> {
+        self.inner().map(|cs| cs.borrow().profile())
+    }
+}
+
+/// Compute the names of the steps of `trace`, starting from the root. Each name
+/// is prefixed by as much of its module path as is needed to distinguish it
+/// from its parents.
+#[cfg(feature = "std")]
+pub(crate) fn trace_path(trace: &ConstraintTrace) -> Vec<String> {
+    let mut path = Vec::new();
+    let mut prev_module_path = "";
+    let mut prefixes = BTreeSet::new();
+    for step in trace.path() {
+        let module_path = if prev_module_path == step.module_path {
+            prefixes.insert(step.module_path.to_string());
+            String::new()
+        } else {
+            let mut parts = step
+                .module_path
+                .split("::")
+                .filter(|&part| part != "r1cs_std" && part != "constraints");
+            let mut path_so_far = String::new();
+            for part in parts.by_ref() {
+                if path_so_far.is_empty() {
+                    path_so_far += part;
+                } else {
+                    path_so_far += &["::", part].join("");
+                }
+                if prefixes.contains(&path_so_far) {
+                    continue;
+                } else {
+                    prefixes.insert(path_so_far.clone());
+                    break;
+                }
+            }
+            parts.collect::<Vec<_>>().join("::") + "::"
+        };
+        prev_module_path = step.module_path;
+        path.push([&module_path, step.name].join(""));
+    }
+    path
 }
 
 #[cfg(test)]
@@ -779,4 +1709,338 @@ mod tests {
         assert_eq!(matrices.c[2], vec![(two, 1), (two, 2)]);
         Ok(())
     }
+
+    #[test]
+    fn unsatisfied_constraints() -> Result<(), SynthesisError> {
+        let two = Fr::one() + Fr::one();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let b = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let c = cs.new_witness_variable(|| Ok(two))?;
+        let d = cs.new_lc(lc!() + a + b)?;
+        cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + c)?;
+        cs.enforce_constraint(lc!() + a, lc!() + d, lc!() + c)?;
+        cs.enforce_constraint(lc!() + d, lc!() + Variable::One, lc!() + a)?;
+        let unsatisfied = cs.unsatisfied_constraints()?;
+        assert_eq!(unsatisfied.len(), 2);
+        assert_eq!(cs.check_satisfied(), Err(SynthesisError::Unsatisfiable));
+        assert_eq!(cs.which_is_unsatisfied()?, Some("0".to_string()));
+        assert_eq!(unsatisfied[0].index, 0);
+        assert_eq!(
+            (unsatisfied[0].a, unsatisfied[0].b, unsatisfied[0].c),
+            (Fr::one(), Fr::one(), two)
+        );
+        assert_eq!(
+            unsatisfied[0].variables,
+            vec![
+                (Variable::Instance(1), Fr::one()),
+                (Variable::Witness(0), Fr::one()),
+                (Variable::Witness(1), two)
+            ]
+        );
+        assert_eq!(unsatisfied[1].index, 2);
+        assert_eq!(
+            (unsatisfied[1].a, unsatisfied[1].b, unsatisfied[1].c),
+            (two, Fr::one(), Fr::one())
+        );
+        assert_eq!(
+            unsatisfied[1].variables,
+            vec![
+                (Variable::Instance(1), Fr::one()),
+                (Variable::Witness(0), Fr::one())
+            ]
+        );
+        assert!(format!("{}", unsatisfied[1]).starts_with("constraint 2 is unsatisfied"));
+        Ok(())
+    }
+
+    #[test]
+    fn lint() -> Result<(), SynthesisError> {
+        let two = Fr::one() + Fr::one();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let _unused = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let b = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let _unconstrained = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let c = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let d = cs.new_lc(lc!() + a + c)?;
+        cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + b)?;
+        // Duplicate of the first constraint, with `a` and `b` swapped.
+        cs.enforce_constraint(lc!() + b, lc!() + a, lc!() + (Fr::one(), b))?;
+        // `0 ⋅ c = 0`, once `d - a - c` is inlined.
+        cs.enforce_constraint(lc!() + d - a - c, lc!() + c, lc!())?;
+        cs.enforce_constraint(
+            lc!() + (two, Variable::One),
+            lc!() + Variable::One,
+            lc!() + (two, Variable::One),
+        )?;
+        cs.enforce_constraint(lc!() + a, lc!() + d, lc!() + (two, b))?;
+
+        let kinds = cs
+            .lint()
+            .unwrap()
+            .into_iter()
+            .map(|l| l.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                LintKind::DuplicateConstraint {
+                    index: 1,
+                    original: 0
+                },
+                LintKind::TriviallySatisfiedConstraint(2),
+                LintKind::TriviallySatisfiedConstraint(3),
+                LintKind::UnusedInstanceVariable(2),
+                LintKind::UnconstrainedWitnessVariable(1),
+            ]
+        );
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        cs.set_mode(SynthesisMode::Prove {
+            construct_matrices: false,
+        });
+        assert_eq!(cs.lint(), None);
+        Ok(())
+    }
+
+    fn witness_only_circuit(cs: &ConstraintSystemRef<Fr>, c: Fr) -> Result<(), SynthesisError> {
+        let two = Fr::one() + Fr::one();
+        let a = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let b = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let c = cs.new_witness_variable(|| Ok(c))?;
+        cs.enforce_constraint(lc!() + a, lc!() + (two, b), lc!() + c)?;
+        let d = cs.new_lc(lc!() + a + b)?;
+        cs.enforce_constraint(lc!() + a, lc!() + d, lc!() + d)?;
+        let e = cs.new_lc(lc!() + d + d)?;
+        cs.enforce_constraint(lc!() + Variable::One, lc!() + e, lc!() + (two, c))
+    }
+
+    #[test]
+    fn witness_only() -> Result<(), SynthesisError> {
+        let two = Fr::one() + Fr::one();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        witness_only_circuit(&cs, two)?;
+        cs.inline_all_lcs();
+        let matrices = cs.to_matrices().unwrap();
+
+        let witness_cs = ConstraintSystem::<Fr>::new_ref();
+        witness_cs.set_mode(SynthesisMode::WitnessOnly);
+        witness_only_circuit(&witness_cs, two)?;
+        witness_cs.inline_all_lcs();
+        assert!(witness_cs.is_satisfied()?);
+        assert!(witness_cs.to_matrices().is_none());
+        assert_eq!(witness_cs.outline_lcs(), Err(SynthesisError::LcsNotStored));
+        assert_eq!(witness_cs.num_constraints(), 3);
+        assert_eq!(
+            witness_cs.assigned_value(Variable::SymbolicLc(LcIndex(1))),
+            Some(two + two)
+        );
+
+        let witness_cs = witness_cs.borrow().unwrap();
+        let cs = cs.borrow().unwrap();
+        assert_eq!(witness_cs.witness_assignment, cs.witness_assignment);
+        assert!(witness_cs.lc_map.is_empty());
+        assert!(witness_cs.lc_assignment_cache.borrow().is_empty());
+        assert_eq!(witness_cs.lc_values, vec![two, two + two]);
+        let z = [&cs.instance_assignment[..], &cs.witness_assignment[..]].concat();
+        let evaluate = |row: &Vec<(Fr, usize)>| row.iter().map(|(c, i)| *c * z[*i]).sum::<Fr>();
+        let (a, b, c) = witness_cs.constraint_evaluations().unwrap();
+        assert_eq!(a, &matrices.a.iter().map(evaluate).collect::<Vec<_>>()[..]);
+        assert_eq!(b, &matrices.b.iter().map(evaluate).collect::<Vec<_>>()[..]);
+        assert_eq!(c, &matrices.c.iter().map(evaluate).collect::<Vec<_>>()[..]);
+        assert!(cs.constraint_evaluations().is_none());
+
+        let unsatisfied_cs = ConstraintSystem::<Fr>::new_ref();
+        unsatisfied_cs.set_mode(SynthesisMode::WitnessOnly);
+        witness_only_circuit(&unsatisfied_cs, Fr::one())?;
+        let unsatisfied = unsatisfied_cs.unsatisfied_constraints()?;
+        assert_eq!(unsatisfied.len(), 2);
+        assert_eq!(unsatisfied[0].index, 0);
+        assert_eq!(unsatisfied[1].index, 2);
+        assert!(unsatisfied[1].variables.is_empty());
+        Ok(())
+    }
+
+    fn multiple(
+        cs: &ConstraintSystemRef<Fr>,
+        x: Variable,
+        k: u64,
+    ) -> Result<Variable, SynthesisError> {
+        let k = Fr::from(k);
+        let y = cs.new_witness_variable(|| Ok(cs.assigned_value(x).unwrap() * k))?;
+        let kx = cs.new_lc(lc!() + (k, x))?;
+        cs.enforce_constraint(lc!() + kx, lc!() + Variable::One, lc!() + y)?;
+        Ok(y)
+    }
+
+    fn forking_circuit(cs: &ConstraintSystemRef<Fr>, fork: bool) -> Result<(), SynthesisError> {
+        let x = cs.new_input_variable(|| Ok(Fr::from(3u64)))?;
+        let ks = vec![1u64, 2, 3, 4];
+        let ys = if fork {
+            cs.fork_join(ks, |cs, k| {
+                let y = multiple(&cs, x, k)?;
+                let z = multiple(&cs, y, k)?;
+                Ok(vec![z])
+            })?
+            .into_iter()
+            .flatten()
+            .collect()
+        } else {
+            let mut ys = vec![];
+            for k in ks {
+                let y = multiple(cs, x, k)?;
+                ys.push(multiple(cs, y, k)?);
+            }
+            ys
+        };
+        let sum = cs.new_witness_variable(|| Ok(Fr::from(90u64)))?;
+        let lc = ys.into_iter().fold(lc!(), |lc, y| lc + y);
+        cs.enforce_constraint(lc, lc!() + Variable::One, lc!() + sum)
+    }
+
+    #[test]
+    fn fork_join() -> Result<(), SynthesisError> {
+        for &mode in &[
+            SynthesisMode::Setup,
+            SynthesisMode::Prove {
+                construct_matrices: true,
+            },
+            SynthesisMode::WitnessOnly,
+        ] {
+            let sequential = ConstraintSystem::<Fr>::new_ref();
+            sequential.set_mode(mode);
+            forking_circuit(&sequential, false)?;
+            let forked = ConstraintSystem::<Fr>::new_ref();
+            forked.set_mode(mode);
+            forking_circuit(&forked, true)?;
+
+            assert_eq!(forked.num_constraints(), 9);
+            assert_eq!(forked.num_witness_variables(), 9);
+            if mode != SynthesisMode::Setup {
+                assert!(forked.is_satisfied()?);
+                assert_eq!(
+                    forked.borrow().unwrap().witness_assignment,
+                    sequential.borrow().unwrap().witness_assignment
+                );
+            }
+            sequential.finalize()?;
+            forked.finalize()?;
+            assert_eq!(forked.to_matrices(), sequential.to_matrices());
+        }
+        Ok(())
+    }
+
+    fn forking_lc_circuit(cs: &ConstraintSystemRef<Fr>, fork: bool) -> Result<(), SynthesisError> {
+        let x = cs.new_input_variable(|| Ok(Fr::from(3u64)))?;
+        let two_x = cs.new_lc(lc!() + x + x)?;
+        let ks = vec![1u64, 2, 3, 4];
+        let ys = if fork {
+            cs.fork_join(ks, |cs, k| Ok(vec![multiple(&cs, two_x, k)?]))?
+                .into_iter()
+                .flatten()
+                .collect()
+        } else {
+            ks.into_iter()
+                .map(|k| multiple(cs, two_x, k))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let sum = cs.new_witness_variable(|| Ok(Fr::from(60u64)))?;
+        let lc = ys.into_iter().fold(lc!(), |lc, y| lc + y);
+        cs.enforce_constraint(lc, lc!() + Variable::One, lc!() + sum)
+    }
+
+    #[test]
+    fn fork_join_reads_parent_lcs() -> Result<(), SynthesisError> {
+        for &mode in &[
+            SynthesisMode::Setup,
+            SynthesisMode::Prove {
+                construct_matrices: true,
+            },
+            SynthesisMode::WitnessOnly,
+        ] {
+            let sequential = ConstraintSystem::<Fr>::new_ref();
+            sequential.set_mode(mode);
+            forking_lc_circuit(&sequential, false)?;
+            let forked = ConstraintSystem::<Fr>::new_ref();
+            forked.set_mode(mode);
+            forking_lc_circuit(&forked, true)?;
+
+            if mode != SynthesisMode::Setup {
+                assert!(forked.is_satisfied()?);
+                assert_eq!(
+                    forked.borrow().unwrap().witness_assignment,
+                    sequential.borrow().unwrap().witness_assignment
+                );
+            }
+            sequential.finalize()?;
+            forked.finalize()?;
+            assert_eq!(forked.to_matrices(), sequential.to_matrices());
+        }
+        Ok(())
+    }
+
+    fn outlining_circuit(cs: &ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let two = Fr::one() + Fr::one();
+        let a = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let b = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let c = cs.new_witness_variable(|| Ok(two))?;
+        // `d` is used three times, `e` only once.
+        let d = cs.new_lc(lc!() + a + b)?;
+        let e = cs.new_lc(lc!() + d + c)?;
+        cs.enforce_constraint(lc!() + a, lc!() + d, lc!() + c)?;
+        cs.enforce_constraint(lc!() + Variable::One, lc!() + d, lc!() + (two, a))?;
+        cs.enforce_constraint(lc!() + Variable::One, lc!() + e, lc!() + (two, c))
+    }
+
+    #[test]
+    fn outline_lcs() -> Result<(), SynthesisError> {
+        let two = Fr::one() + Fr::one();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        cs.set_optimization_goal(OptimizationGoal::Weight);
+        outlining_circuit(&cs)?;
+        cs.finalize()?;
+        assert!(cs.is_satisfied()?);
+        assert_eq!(cs.num_witness_variables(), 3);
+        assert_eq!(cs.num_constraints(), 4);
+        assert_eq!(cs.assigned_value(Variable::Witness(2)), Some(two));
+        // No traces are captured without a `ConstraintLayer`.
+        assert!(cs.borrow().unwrap().witness_traces.is_empty());
+
+        let matrices = cs.to_matrices().unwrap();
+        // Every use of `d` is replaced by the new witness variable.
+        assert_eq!(matrices.b[0], vec![(Fr::one(), 4)]);
+        assert_eq!(matrices.b[1], vec![(Fr::one(), 4)]);
+        assert_eq!(matrices.b[2], vec![(Fr::one(), 3), (Fr::one(), 4)]);
+        // The new constraint enforces that the variable equals `d`.
+        assert_eq!(matrices.a[3], vec![(Fr::one(), 4)]);
+        assert_eq!(matrices.b[3], vec![(Fr::one(), 0)]);
+        assert_eq!(matrices.c[3], vec![(Fr::one(), 1), (Fr::one(), 2)]);
+
+        let setup_cs = ConstraintSystem::<Fr>::new_ref();
+        setup_cs.set_mode(SynthesisMode::Setup);
+        setup_cs.set_optimization_goal(OptimizationGoal::Weight);
+        outlining_circuit(&setup_cs)?;
+        setup_cs.finalize()?;
+        assert_eq!(setup_cs.to_matrices().unwrap(), matrices);
+
+        let prove_cs = ConstraintSystem::<Fr>::new_ref();
+        prove_cs.set_mode(SynthesisMode::Prove {
+            construct_matrices: false,
+        });
+        prove_cs.set_optimization_goal(OptimizationGoal::Weight);
+        outlining_circuit(&prove_cs)?;
+        prove_cs.finalize()?;
+        assert_eq!(prove_cs.num_witness_variables(), 3);
+        assert_eq!(
+            prove_cs.borrow().unwrap().witness_assignment,
+            cs.borrow().unwrap().witness_assignment
+        );
+
+        // Uses are not counted when minimizing constraints.
+        let inline_cs = ConstraintSystem::<Fr>::new_ref();
+        outlining_circuit(&inline_cs)?;
+        assert!(inline_cs.borrow().unwrap().lc_num_times_used.is_empty());
+        Ok(())
+    }
 }
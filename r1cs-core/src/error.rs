@@ -21,6 +21,12 @@ pub enum SynthesisError {
     MalformedVerifyingKey,
     /// During CRS generation, we observed an unconstrained auxiliary variable
     UnconstrainedVariable,
+    /// During synthesis, we needed the symbolic LCs, which are not stored in
+    /// `SynthesisMode::WitnessOnly`.
+    LcsNotStored,
+    /// During synthesis, we were asked to decompose a field element into more
+    /// bits than the field can hold without wrapping around its modulus.
+    BitLengthTooLarge,
 }
 
 #[cfg(feature = "std")]
@@ -47,6 +53,12 @@ impl fmt::Display for SynthesisError {
             SynthesisError::UnconstrainedVariable => {
                 write!(f, "auxiliary variable was unconstrained")
             }
+            SynthesisError::LcsNotStored => {
+                write!(f, "symbolic LCs are not stored in witness-only mode")
+            }
+            SynthesisError::BitLengthTooLarge => {
+                write!(f, "number of bits exceeds the capacity of the field")
+            }
         }
     }
 }
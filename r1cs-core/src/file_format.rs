@@ -0,0 +1,658 @@
+//! Reading and writing constraint systems and their assignments in the binary
+//! `.r1cs` and `.wtns` formats used by [circom](https://github.com/iden3/circom)
+//! and [snarkjs](https://github.com/iden3/snarkjs).
+//!
+//! Both formats number variables ("wires") in the same way as
+//! `ConstraintMatrices`: wire `0` is the constant one, followed by the public
+//! instance variables, followed by the private witness variables.
+
+use crate::{
+    vec, BTreeMap, ConstraintMatrices, ConstraintSynthesizer, ConstraintSystemRef,
+    LinearCombination, Matrix, SynthesisError, Variable, Vec,
+};
+use algebra_core::{
+    io::{self, Read, Write},
+    BigInteger, FromBytes, PrimeField, ToBytes,
+};
+use core::{convert::TryFrom, fmt};
+
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const R1CS_VERSION: u32 = 1;
+const R1CS_HEADER_SECTION: u32 = 1;
+const R1CS_CONSTRAINTS_SECTION: u32 = 2;
+const R1CS_WIRE_TO_LABEL_SECTION: u32 = 3;
+
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+const WTNS_VERSION: u32 = 2;
+const WTNS_HEADER_SECTION: u32 = 1;
+const WTNS_WITNESS_SECTION: u32 = 2;
+
+/// An error that can occur while reading or writing a `.r1cs` or `.wtns` file.
+#[derive(Debug)]
+pub enum FormatError {
+    /// Reading from or writing to the underlying stream failed.
+    Io(io::Error),
+    /// The file does not start with the expected magic bytes.
+    InvalidMagic,
+    /// The file uses an unsupported version of the format.
+    UnsupportedVersion(u32),
+    /// The file is defined over a field with a different modulus than the
+    /// requested one.
+    FieldMismatch,
+    /// The file does not contain a required section.
+    MissingSection(u32),
+    /// The declared size of a section does not match the size of its
+    /// contents.
+    SectionSizeMismatch(u32),
+    /// The file is malformed, or the data to be written cannot be represented
+    /// in the format.
+    InvalidData,
+    /// The assignment has a different number of values than the constraint
+    /// system has variables.
+    AssignmentLengthMismatch {
+        /// The number of variables in the constraint system.
+        expected: usize,
+        /// The number of values in the assignment.
+        found: usize,
+    },
+}
+
+impl From<io::Error> for FormatError {
+    fn from(e: io::Error) -> Self {
+        FormatError::Io(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FormatError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Io(e) => write!(f, "I/O error: {}", e),
+            FormatError::InvalidMagic => write!(f, "the file has an unexpected magic number"),
+            FormatError::UnsupportedVersion(v) => write!(f, "unsupported file version {}", v),
+            FormatError::FieldMismatch => write!(
+                f,
+                "the file is defined over a field with a different modulus than the requested one"
+            ),
+            FormatError::MissingSection(s) => write!(f, "the file is missing section {}", s),
+            FormatError::SectionSizeMismatch(s) => {
+                write!(f, "section {} does not have its declared size", s)
+            },
+            FormatError::InvalidData => write!(f, "the file is malformed"),
+            FormatError::AssignmentLengthMismatch { expected, found } => write!(
+                f,
+                "the assignment has {} values, but the constraint system has {} variables",
+                found, expected
+            ),
+        }
+    }
+}
+
+/// Write `matrices` to `writer` in the `.r1cs` format.
+pub fn write_r1cs<F: PrimeField, W: Write>(
+    matrices: &ConstraintMatrices<F>,
+    mut writer: W,
+) -> Result<(), FormatError> {
+    let field_size = field_size::<F>();
+    let num_wires = matrices.num_instance_variables + matrices.num_witness_variables;
+
+    writer.write_all(R1CS_MAGIC)?;
+    write_u32(&mut writer, R1CS_VERSION)?;
+    write_u32(&mut writer, 3)?;
+
+    write_section_header(&mut writer, R1CS_HEADER_SECTION, 4 + field_size + 28)?;
+    write_u32(&mut writer, field_size as u32)?;
+    F::modulus().write(&mut writer)?;
+    write_u32(&mut writer, to_u32(num_wires)?)?;
+    // We do not distinguish public outputs from public inputs, nor private
+    // inputs from intermediate values.
+    write_u32(&mut writer, 0)?;
+    write_u32(&mut writer, to_u32(matrices.num_instance_variables - 1)?)?;
+    write_u32(&mut writer, to_u32(matrices.num_witness_variables)?)?;
+    write_u64(&mut writer, num_wires as u64)?;
+    write_u32(&mut writer, to_u32(matrices.num_constraints)?)?;
+
+    let num_non_zero = matrices.a_num_non_zero + matrices.b_num_non_zero + matrices.c_num_non_zero;
+    let constraints_size = 12 * matrices.num_constraints + (4 + field_size) * num_non_zero;
+    write_section_header(&mut writer, R1CS_CONSTRAINTS_SECTION, constraints_size)?;
+    for ((a, b), c) in matrices.a.iter().zip(&matrices.b).zip(&matrices.c) {
+        for row in &[a, b, c] {
+            write_u32(&mut writer, to_u32(row.len())?)?;
+            for (coeff, index) in row.iter() {
+                write_u32(&mut writer, to_u32(*index)?)?;
+                coeff.into_repr().write(&mut writer)?;
+            }
+        }
+    }
+
+    write_section_header(&mut writer, R1CS_WIRE_TO_LABEL_SECTION, 8 * num_wires)?;
+    for i in 0..num_wires {
+        write_u64(&mut writer, i as u64)?;
+    }
+    Ok(())
+}
+
+/// Read `ConstraintMatrices` in the `.r1cs` format from `reader`.
+///
+/// Outputs `Err(FormatError::FieldMismatch)` if the file is not defined over
+/// `F`.
+pub fn read_r1cs<F: PrimeField, R: Read>(
+    mut reader: R,
+) -> Result<ConstraintMatrices<F>, FormatError> {
+    let sections = read_sections(&mut reader, R1CS_MAGIC, R1CS_VERSION)?;
+
+    let (num_wires, num_public_outputs, num_public_inputs, num_constraints) =
+        read_section(&sections, R1CS_HEADER_SECTION, |header| {
+            read_field_header::<F, _>(&mut *header)?;
+            let num_wires = read_u32(&mut *header)? as usize;
+            let num_public_outputs = read_u32(&mut *header)? as usize;
+            let num_public_inputs = read_u32(&mut *header)? as usize;
+            let _num_private_inputs = read_u32(&mut *header)?;
+            let _num_labels = read_u64(&mut *header)?;
+            let num_constraints = read_u32(&mut *header)? as usize;
+            Ok((
+                num_wires,
+                num_public_outputs,
+                num_public_inputs,
+                num_constraints,
+            ))
+        })?;
+
+    let num_instance_variables = 1 + num_public_outputs + num_public_inputs;
+    if num_instance_variables > num_wires {
+        return Err(FormatError::InvalidData);
+    }
+
+    let (a, b, c) = read_section(&sections, R1CS_CONSTRAINTS_SECTION, |constraints| {
+        let mut read_row = || -> Result<Vec<(F, usize)>, FormatError> {
+            let num_entries = read_u32(&mut *constraints)? as usize;
+            let mut row = Vec::with_capacity(num_entries.min(constraints.len()));
+            for _ in 0..num_entries {
+                let index = read_u32(&mut *constraints)? as usize;
+                if index >= num_wires {
+                    return Err(FormatError::InvalidData);
+                }
+                row.push((read_field_element(&mut *constraints)?, index));
+            }
+            Ok(row)
+        };
+        let (mut a, mut b, mut c): (Matrix<F>, Matrix<F>, Matrix<F>) = (vec![], vec![], vec![]);
+        for _ in 0..num_constraints {
+            a.push(read_row()?);
+            b.push(read_row()?);
+            c.push(read_row()?);
+        }
+        Ok((a, b, c))
+    })?;
+
+    Ok(ConstraintMatrices {
+        num_instance_variables,
+        num_witness_variables: num_wires - num_instance_variables,
+        num_constraints,
+        a_num_non_zero: a.iter().map(|row| row.len()).sum(),
+        b_num_non_zero: b.iter().map(|row| row.len()).sum(),
+        c_num_non_zero: c.iter().map(|row| row.len()).sum(),
+        a,
+        b,
+        c,
+    })
+}
+
+/// Write the full assignment of a constraint system to `writer` in the
+/// `.wtns` format. `assignment` should consist of the instance assignment
+/// (starting with the constant one), followed by the witness assignment.
+pub fn write_wtns<F: PrimeField, W: Write>(
+    assignment: &[F],
+    mut writer: W,
+) -> Result<(), FormatError> {
+    let field_size = field_size::<F>();
+
+    writer.write_all(WTNS_MAGIC)?;
+    write_u32(&mut writer, WTNS_VERSION)?;
+    write_u32(&mut writer, 2)?;
+
+    write_section_header(&mut writer, WTNS_HEADER_SECTION, 4 + field_size + 4)?;
+    write_u32(&mut writer, field_size as u32)?;
+    F::modulus().write(&mut writer)?;
+    write_u32(&mut writer, to_u32(assignment.len())?)?;
+
+    write_section_header(
+        &mut writer,
+        WTNS_WITNESS_SECTION,
+        field_size * assignment.len(),
+    )?;
+    for value in assignment {
+        value.into_repr().write(&mut writer)?;
+    }
+    Ok(())
+}
+
+/// Read the full assignment of a constraint system in the `.wtns` format from
+/// `reader`. The output consists of the instance assignment (starting with the
+/// constant one), followed by the witness assignment.
+///
+/// Outputs `Err(FormatError::FieldMismatch)` if the file is not defined over
+/// `F`.
+pub fn read_wtns<F: PrimeField, R: Read>(mut reader: R) -> Result<Vec<F>, FormatError> {
+    let sections = read_sections(&mut reader, WTNS_MAGIC, WTNS_VERSION)?;
+
+    let num_values = read_section(&sections, WTNS_HEADER_SECTION, |header| {
+        read_field_header::<F, _>(&mut *header)?;
+        Ok(read_u32(&mut *header)? as usize)
+    })?;
+
+    // Check the size up front, so that a corrupted count does not cause us to
+    // allocate a huge vector.
+    if section(&sections, WTNS_WITNESS_SECTION)?.len() != num_values * field_size::<F>() {
+        return Err(FormatError::SectionSizeMismatch(WTNS_WITNESS_SECTION));
+    }
+    read_section(&sections, WTNS_WITNESS_SECTION, |values| {
+        (0..num_values)
+            .map(|_| read_field_element(&mut *values))
+            .collect()
+    })
+}
+
+/// A circuit whose constraints, and optionally whose assignment, were loaded
+/// from `.r1cs` and `.wtns` files.
+///
+/// Generating constraints for a `CircomCircuit` allocates the instance and
+/// witness variables of the loaded constraint system in order, and then
+/// replays its constraints, so that the resulting `ConstraintSystem` has the
+/// same matrices as the loaded one. This allows circuits written in circom to
+/// be used with any proof system that accepts a `ConstraintSynthesizer`.
+#[derive(Clone, Debug)]
+pub struct CircomCircuit<F: PrimeField> {
+    matrices: ConstraintMatrices<F>,
+    assignment: Option<Vec<F>>,
+}
+
+impl<F: PrimeField> CircomCircuit<F> {
+    /// Construct a circuit from `matrices` and, if proving, the full
+    /// `assignment` (starting with the constant one).
+    pub fn new(
+        matrices: ConstraintMatrices<F>,
+        assignment: Option<Vec<F>>,
+    ) -> Result<Self, FormatError> {
+        if let Some(assignment) = &assignment {
+            let expected = matrices.num_instance_variables + matrices.num_witness_variables;
+            if assignment.len() != expected {
+                return Err(FormatError::AssignmentLengthMismatch {
+                    expected,
+                    found: assignment.len(),
+                });
+            }
+            if !assignment[0].is_one() {
+                return Err(FormatError::InvalidData);
+            }
+        }
+        Ok(Self {
+            matrices,
+            assignment,
+        })
+    }
+
+    /// Read a circuit from a `.r1cs` file and, if proving, a `.wtns` file.
+    ///
+    /// Outputs `Err(FormatError::FieldMismatch)` if either file is not
+    /// defined over `F`.
+    pub fn read<R1: Read, R2: Read>(r1cs: R1, wtns: Option<R2>) -> Result<Self, FormatError> {
+        let matrices = read_r1cs(r1cs)?;
+        let assignment = wtns.map(read_wtns).transpose()?;
+        Self::new(matrices, assignment)
+    }
+
+    /// Obtain the loaded constraint matrices.
+    pub fn matrices(&self) -> &ConstraintMatrices<F> {
+        &self.matrices
+    }
+
+    /// Obtain the assignment to the public instance variables, excluding the
+    /// constant one. This is the public input expected by verifiers.
+    pub fn public_inputs(&self) -> Option<&[F]> {
+        self.assignment
+            .as_ref()
+            .map(|a| &a[1..self.matrices.num_instance_variables])
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for CircomCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let num_instance_variables = self.matrices.num_instance_variables;
+        let assignment = self.assignment.as_ref();
+        let value = |i: usize| {
+            assignment
+                .map(|a| a[i])
+                .ok_or(SynthesisError::AssignmentMissing)
+        };
+
+        let mut variables =
+            Vec::with_capacity(num_instance_variables + self.matrices.num_witness_variables);
+        variables.push(Variable::One);
+        for i in 1..num_instance_variables {
+            variables.push(cs.new_input_variable(|| value(i))?);
+        }
+        for i in 0..self.matrices.num_witness_variables {
+            variables.push(cs.new_witness_variable(|| value(num_instance_variables + i))?);
+        }
+
+        let to_lc = |row: &[(F, usize)]| {
+            LinearCombination(
+                row.iter()
+                    .map(|(coeff, index)| (*coeff, variables[*index]))
+                    .collect(),
+            )
+        };
+        let rows = self
+            .matrices
+            .a
+            .iter()
+            .zip(&self.matrices.b)
+            .zip(&self.matrices.c);
+        for ((a, b), c) in rows {
+            cs.enforce_constraint(to_lc(a), to_lc(b), to_lc(c))?;
+        }
+        Ok(())
+    }
+}
+
+fn field_size<F: PrimeField>() -> usize {
+    <F::BigInt as BigInteger>::NUM_LIMBS * 8
+}
+
+fn to_u32(n: usize) -> Result<u32, FormatError> {
+    u32::try_from(n).map_err(|_| FormatError::InvalidData)
+}
+
+fn write_u32<W: Write>(mut writer: W, n: u32) -> Result<(), FormatError> {
+    Ok(writer.write_all(&n.to_le_bytes())?)
+}
+
+fn write_u64<W: Write>(mut writer: W, n: u64) -> Result<(), FormatError> {
+    Ok(writer.write_all(&n.to_le_bytes())?)
+}
+
+fn write_section_header<W: Write>(
+    mut writer: W,
+    section_type: u32,
+    size: usize,
+) -> Result<(), FormatError> {
+    write_u32(&mut writer, section_type)?;
+    write_u64(&mut writer, size as u64)
+}
+
+fn read_u32<R: Read>(mut reader: R) -> Result<u32, FormatError> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64<R: Read>(mut reader: R) -> Result<u64, FormatError> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_field_element<F: PrimeField, R: Read>(reader: R) -> Result<F, FormatError> {
+    F::from_repr(F::BigInt::read(reader)?).ok_or(FormatError::InvalidData)
+}
+
+/// Read the field size and modulus at the start of a header section, and check
+/// that they match `F`.
+fn read_field_header<F: PrimeField, R: Read>(mut reader: R) -> Result<(), FormatError> {
+    let field_size = read_u32(&mut reader)? as usize;
+    if field_size != self::field_size::<F>() {
+        return Err(FormatError::FieldMismatch);
+    }
+    if F::BigInt::read(&mut reader)? != F::modulus() {
+        return Err(FormatError::FieldMismatch);
+    }
+    Ok(())
+}
+
+/// Read the magic number, version and sections of a file. Sections that
+/// appear more than once are rejected.
+fn read_sections<R: Read>(
+    mut reader: R,
+    magic: &[u8; 4],
+    version: u32,
+) -> Result<BTreeMap<u32, Vec<u8>>, FormatError> {
+    let mut file_magic = [0u8; 4];
+    reader.read_exact(&mut file_magic)?;
+    if &file_magic != magic {
+        return Err(FormatError::InvalidMagic);
+    }
+    let file_version = read_u32(&mut reader)?;
+    if file_version != version {
+        return Err(FormatError::UnsupportedVersion(file_version));
+    }
+
+    let num_sections = read_u32(&mut reader)?;
+    let mut sections = BTreeMap::new();
+    for _ in 0..num_sections {
+        let section_type = read_u32(&mut reader)?;
+        let size = read_u64(&mut reader)? as usize;
+        // Read in chunks, so that a corrupted size does not cause us to
+        // allocate a huge buffer up front.
+        let mut contents = Vec::new();
+        let mut chunk = [0u8; 4096];
+        while contents.len() < size {
+            let len = chunk.len().min(size - contents.len());
+            reader.read_exact(&mut chunk[..len])?;
+            contents.extend_from_slice(&chunk[..len]);
+        }
+        if sections.insert(section_type, contents).is_some() {
+            return Err(FormatError::InvalidData);
+        }
+    }
+    Ok(sections)
+}
+
+fn section(sections: &BTreeMap<u32, Vec<u8>>, section_type: u32) -> Result<&[u8], FormatError> {
+    sections
+        .get(&section_type)
+        .map(|s| s.as_slice())
+        .ok_or(FormatError::MissingSection(section_type))
+}
+
+/// Parse a section with `f`, and check that `f` consumes exactly its
+/// contents. Reading from a section can only fail by running out of bytes, so
+/// I/O errors are reported as `FormatError::SectionSizeMismatch`.
+fn read_section<T>(
+    sections: &BTreeMap<u32, Vec<u8>>,
+    section_type: u32,
+    f: impl FnOnce(&mut &[u8]) -> Result<T, FormatError>,
+) -> Result<T, FormatError> {
+    let mut contents = section(sections, section_type)?;
+    match f(&mut contents) {
+        Ok(_) if !contents.is_empty() => Err(FormatError::SectionSizeMismatch(section_type)),
+        Err(FormatError::Io(_)) => Err(FormatError::SectionSizeMismatch(section_type)),
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use algebra::{bls12_377, bls12_381::Fr, bn254, CanonicalDeserialize, CanonicalSerialize, One};
+
+    // The `Multiplier` circuit from the circom documentation (`c <== a * b`,
+    // with private inputs `a` and `b` and the public output `c`) over BN254,
+    // for `a = 3` and `b = 11`. These files were assembled by hand from the
+    // iden3 format specifications, using the wire order and constraint layout
+    // that circom emits for this circuit; they were not written by circom.
+    // See `test-data/README.md` for how to regenerate them with circom.
+    const MULTIPLIER_R1CS: &[u8] = include_bytes!("../test-data/multiplier.r1cs");
+    const MULTIPLIER_WTNS: &[u8] = include_bytes!("../test-data/multiplier.wtns");
+
+    fn circuit() -> Result<ConstraintSystemRef<Fr>, SynthesisError> {
+        let two = Fr::one() + Fr::one();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a = cs.new_input_variable(|| Ok(Fr::one()))?;
+        let b = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        let c = cs.new_witness_variable(|| Ok(two))?;
+        let d = cs.new_lc(lc!() + a + b)?;
+        cs.enforce_constraint(lc!() + a, lc!() + (two, b), lc!() + c)?;
+        cs.enforce_constraint(lc!() + Variable::One, lc!() + d, lc!() + c)?;
+        cs.finalize()?;
+        Ok(cs)
+    }
+
+    #[test]
+    fn r1cs_round_trip() -> Result<(), SynthesisError> {
+        let matrices = circuit()?.to_matrices().unwrap();
+        let mut bytes = Vec::new();
+        write_r1cs(&matrices, &mut bytes).unwrap();
+        assert_eq!(&bytes[..4], b"r1cs");
+        assert_eq!(read_r1cs::<Fr, _>(&bytes[..]).unwrap(), matrices);
+
+        let mut serialized = Vec::new();
+        matrices.serialize(&mut serialized).unwrap();
+        assert_eq!(
+            ConstraintMatrices::<Fr>::deserialize(&serialized[..]).unwrap(),
+            matrices
+        );
+
+        assert!(matches!(
+            read_r1cs::<bls12_377::Fr, _>(&bytes[..]),
+            Err(FormatError::FieldMismatch)
+        ));
+        assert!(matches!(
+            read_r1cs::<Fr, _>(&bytes[..bytes.len() - 1]),
+            Err(FormatError::Io(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn circom_circuit() -> Result<(), SynthesisError> {
+        let cs = circuit()?;
+        let matrices = cs.to_matrices().unwrap();
+        let assignment = {
+            let cs = cs.borrow().unwrap();
+            [&cs.instance_assignment[..], &cs.witness_assignment[..]].concat()
+        };
+        let (mut r1cs, mut wtns) = (Vec::new(), Vec::new());
+        write_r1cs(&matrices, &mut r1cs).unwrap();
+        write_wtns(&assignment, &mut wtns).unwrap();
+
+        let circuit = CircomCircuit::<Fr>::read(&r1cs[..], Some(&wtns[..])).unwrap();
+        assert_eq!(circuit.public_inputs(), Some(&[Fr::one()][..]));
+        let replayed = ConstraintSystem::new_ref();
+        circuit.generate_constraints(replayed.clone())?;
+        replayed.finalize()?;
+        assert!(replayed.is_satisfied()?);
+        assert_eq!(replayed.to_matrices().unwrap(), matrices);
+
+        let setup = ConstraintSystem::new_ref();
+        setup.set_mode(SynthesisMode::Setup);
+        let circuit = CircomCircuit::<Fr>::read(&r1cs[..], None::<&[u8]>).unwrap();
+        circuit.generate_constraints(setup.clone())?;
+        setup.finalize()?;
+        assert_eq!(setup.to_matrices().unwrap(), matrices);
+
+        assert!(matches!(
+            CircomCircuit::new(matrices.clone(), Some(assignment[1..].to_vec())),
+            Err(FormatError::AssignmentLengthMismatch {
+                expected: 4,
+                found: 3
+            })
+        ));
+        assert!(matches!(
+            CircomCircuit::<bls12_377::Fr>::read(&r1cs[..], Some(&wtns[..])),
+            Err(FormatError::FieldMismatch)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn wtns_round_trip() -> Result<(), SynthesisError> {
+        let cs = circuit()?;
+        let cs = cs.borrow().unwrap();
+        let assignment = [&cs.instance_assignment[..], &cs.witness_assignment[..]].concat();
+        let mut bytes = Vec::new();
+        write_wtns(&assignment, &mut bytes).unwrap();
+        assert_eq!(&bytes[..4], b"wtns");
+        assert_eq!(read_wtns::<Fr, _>(&bytes[..]).unwrap(), assignment);
+
+        assert!(matches!(
+            read_wtns::<bls12_377::Fr, _>(&bytes[..]),
+            Err(FormatError::FieldMismatch)
+        ));
+        assert!(matches!(
+            read_r1cs::<Fr, _>(&bytes[..]),
+            Err(FormatError::InvalidMagic)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn read_circom_files() {
+        let one = bn254::Fr::one();
+        let matrices = read_r1cs::<bn254::Fr, _>(MULTIPLIER_R1CS).unwrap();
+        assert_eq!(matrices.num_instance_variables, 2);
+        assert_eq!(matrices.num_witness_variables, 2);
+        assert_eq!(matrices.num_constraints, 1);
+        assert_eq!(matrices.a, vec![vec![(-one, 2)]]);
+        assert_eq!(matrices.b, vec![vec![(one, 3)]]);
+        assert_eq!(matrices.c, vec![vec![(-one, 1)]]);
+
+        let assignment = read_wtns::<bn254::Fr, _>(MULTIPLIER_WTNS).unwrap();
+        let expected = [1u64, 33, 3, 11]
+            .iter()
+            .map(|&v| bn254::Fr::from(v))
+            .collect::<Vec<_>>();
+        assert_eq!(assignment, expected);
+    }
+
+    #[test]
+    fn section_size_mismatch() {
+        // The number of constraints is the last field of the `.r1cs` header.
+        let mut r1cs = MULTIPLIER_R1CS.to_vec();
+        for &num_constraints in &[0u32, 2] {
+            r1cs[84..88].copy_from_slice(&num_constraints.to_le_bytes());
+            assert!(matches!(
+                read_r1cs::<bn254::Fr, _>(&r1cs[..]),
+                Err(FormatError::SectionSizeMismatch(2))
+            ));
+        }
+
+        // The number of values is the last field of the `.wtns` header.
+        let mut wtns = MULTIPLIER_WTNS.to_vec();
+        wtns[60..64].copy_from_slice(&3u32.to_le_bytes());
+        assert!(matches!(
+            read_wtns::<bn254::Fr, _>(&wtns[..]),
+            Err(FormatError::SectionSizeMismatch(2))
+        ));
+    }
+
+    #[test]
+    fn circom_multiplier() -> Result<(), SynthesisError> {
+        let circuit =
+            CircomCircuit::<bn254::Fr>::read(MULTIPLIER_R1CS, Some(MULTIPLIER_WTNS)).unwrap();
+        assert_eq!(circuit.public_inputs(), Some(&[bn254::Fr::from(33u64)][..]));
+        let cs = ConstraintSystem::new_ref();
+        circuit.clone().generate_constraints(cs.clone())?;
+        cs.finalize()?;
+        assert!(cs.is_satisfied()?);
+        assert_eq!(&cs.to_matrices().unwrap(), circuit.matrices());
+
+        // Claim that 3 * 11 = 34.
+        let mut assignment = read_wtns::<bn254::Fr, _>(MULTIPLIER_WTNS).unwrap();
+        assignment[1] = bn254::Fr::from(34u64);
+        let circuit = CircomCircuit::new(circuit.matrices().clone(), Some(assignment)).unwrap();
+        let cs = ConstraintSystem::new_ref();
+        circuit.generate_constraints(cs.clone())?;
+        assert!(!cs.is_satisfied()?);
+        Ok(())
+    }
+}
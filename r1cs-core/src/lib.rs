@@ -18,6 +18,7 @@ pub use alloc::{
     format,
     rc::Rc,
     string::{String, ToString},
+    sync::Arc,
     vec,
     vec::Vec,
 };
@@ -28,16 +29,22 @@ pub use std::{
     format,
     rc::Rc,
     string::{String, ToString},
+    sync::Arc,
     vec,
     vec::Vec,
 };
 
 mod constraint_system;
 mod error;
+mod file_format;
 mod impl_lc;
 #[cfg(feature = "std")]
+mod profile;
+#[cfg(feature = "std")]
 mod trace;
 
+#[cfg(feature = "std")]
+pub use crate::profile::{ConstraintProfile, ProfileCounts, ProfileMetric, ProfileNode};
 #[cfg(feature = "std")]
 pub use crate::trace::{ConstraintLayer, ConstraintTrace, TraceStep, TracingMode};
 #[cfg(feature = "std")]
@@ -45,10 +52,11 @@ pub use tracing::info_span;
 
 pub use algebra_core::{Field, ToConstraintField};
 pub use constraint_system::{
-    ConstraintMatrices, ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, Namespace,
-    SynthesisMode,
+    ConstraintMatrices, ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, Lint,
+    LintKind, Namespace, OptimizationGoal, SynthesisMode, UnsatisfiedConstraint,
 };
 pub use error::SynthesisError;
+pub use file_format::{read_r1cs, read_wtns, write_r1cs, write_wtns, CircomCircuit, FormatError};
 
 use core::cmp::Ordering;
 
@@ -0,0 +1,317 @@
+use crate::{constraint_system::trace_path, ConstraintTrace};
+use core::fmt::{self, Write};
+
+/// Counts of the resources used by (a part of) a constraint system.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct ProfileCounts {
+    /// The number of constraints.
+    pub num_constraints: usize,
+    /// The number of witness variables.
+    pub num_witness_variables: usize,
+    /// The number of non-zero entries in the A, B and C matrices.
+    pub num_nonzero_entries: usize,
+}
+
+impl ProfileCounts {
+    /// Obtain the count corresponding to `metric`.
+    pub fn get(&self, metric: ProfileMetric) -> usize {
+        match metric {
+            ProfileMetric::Constraints => self.num_constraints,
+            ProfileMetric::WitnessVariables => self.num_witness_variables,
+            ProfileMetric::NonZeroEntries => self.num_nonzero_entries,
+        }
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        self.num_constraints += other.num_constraints;
+        self.num_witness_variables += other.num_witness_variables;
+        self.num_nonzero_entries += other.num_nonzero_entries;
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        write!(
+            out,
+            "{{\"constraints\":{},\"witness_variables\":{},\"nonzero_entries\":{}}}",
+            self.num_constraints, self.num_witness_variables, self.num_nonzero_entries
+        )
+    }
+}
+
+/// Selects which of the `ProfileCounts` to report.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProfileMetric {
+    /// The number of constraints.
+    Constraints,
+    /// The number of witness variables.
+    WitnessVariables,
+    /// The number of non-zero entries in the A, B and C matrices.
+    NonZeroEntries,
+}
+
+/// A namespace in a `ConstraintProfile`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileNode {
+    /// The name of the namespace.
+    pub name: String,
+    /// The resources allocated directly in this namespace.
+    pub own: ProfileCounts,
+    /// The resources allocated in this namespace and all of its children.
+    pub total: ProfileCounts,
+    /// The namespaces nested in this one, in order of first use.
+    pub children: Vec<ProfileNode>,
+}
+
+impl ProfileNode {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            own: ProfileCounts::default(),
+            total: ProfileCounts::default(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Obtain the child namespace named `name`, if it exists.
+    pub fn child(&self, name: &str) -> Option<&ProfileNode> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    /// Obtain the namespace reached by following `path` from `self`, if it
+    /// exists.
+    pub fn get(&self, path: &[&str]) -> Option<&ProfileNode> {
+        path.iter().try_fold(self, |node, name| node.child(name))
+    }
+
+    fn child_mut(&mut self, name: String) -> &mut ProfileNode {
+        let index = match self.children.iter().position(|c| c.name == name) {
+            Some(index) => index,
+            None => {
+                self.children.push(ProfileNode::new(name));
+                self.children.len() - 1
+            },
+        };
+        &mut self.children[index]
+    }
+
+    fn compute_totals(&mut self) -> ProfileCounts {
+        let mut total = self.own;
+        for child in &mut self.children {
+            total.add_assign(&child.compute_totals());
+        }
+        self.total = total;
+        total
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        out.push_str("{\"name\":");
+        write_json_string(&self.name, out)?;
+        out.push_str(",\"own\":");
+        self.own.write_json(out)?;
+        out.push_str(",\"total\":");
+        self.total.write_json(out)?;
+        out.push_str(",\"children\":[");
+        for (i, child) in self.children.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            child.write_json(out)?;
+        }
+        out.push_str("]}");
+        Ok(())
+    }
+
+    fn write_folded_stacks(
+        &self,
+        prefix: &str,
+        metric: ProfileMetric,
+        out: &mut String,
+    ) -> fmt::Result {
+        let stack = if prefix.is_empty() {
+            self.name.clone()
+        } else {
+            [prefix, ";", &self.name].join("")
+        };
+        let count = self.own.get(metric);
+        if count > 0 {
+            writeln!(out, "{} {}", stack, count)?;
+        }
+        for child in &self.children {
+            child.write_folded_stacks(&stack, metric, out)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_with_depth(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        writeln!(
+            f,
+            "{:indent$}{}: {} constraints, {} witness variables, {} non-zero entries",
+            "",
+            self.name,
+            self.total.num_constraints,
+            self.total.num_witness_variables,
+            self.total.num_nonzero_entries,
+            indent = 2 * depth
+        )?;
+        for child in &self.children {
+            child.fmt_with_depth(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// A breakdown of the constraints, witness variables, and non-zero matrix
+/// entries of a `ConstraintSystem` by the namespaces they were created in.
+///
+/// Namespaces are recovered from the `tracing` spans recorded by
+/// `ConstraintLayer`, and so are only available if such a layer is enabled
+/// while generating constraints.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintProfile {
+    /// The root namespace, which contains the entire constraint system.
+    pub root: ProfileNode,
+}
+
+impl ConstraintProfile {
+    pub(crate) fn new() -> Self {
+        Self {
+            root: ProfileNode::new("root".to_string()),
+        }
+    }
+
+    fn node_mut(&mut self, trace: Option<&ConstraintTrace>) -> &mut ProfileNode {
+        let path = trace.map(trace_path).unwrap_or_default();
+        path.into_iter()
+            .fold(&mut self.root, |node, name| node.child_mut(name))
+    }
+
+    pub(crate) fn add_constraint(
+        &mut self,
+        trace: Option<&ConstraintTrace>,
+        num_nonzero_entries: usize,
+    ) {
+        let node = self.node_mut(trace);
+        node.own.num_constraints += 1;
+        node.own.num_nonzero_entries += num_nonzero_entries;
+    }
+
+    pub(crate) fn add_witness_variable(&mut self, trace: Option<&ConstraintTrace>) {
+        self.node_mut(trace).own.num_witness_variables += 1;
+    }
+
+    pub(crate) fn compute_totals(&mut self) {
+        self.root.compute_totals();
+    }
+
+    /// Serialize `self` as a JSON tree. Every namespace is an object with the
+    /// fields `name`, `own`, `total` and `children`.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.root
+            .write_json(&mut out)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Serialize `self` in the "folded stacks" format consumed by flamegraph
+    /// tools, with one line per namespace of the form
+    /// `root;parent;child <count>`, where `<count>` is the number of
+    /// resources of type `metric` allocated directly in that namespace.
+    pub fn to_folded_stacks(&self, metric: ProfileMetric) -> String {
+        let mut out = String::new();
+        self.root
+            .write_folded_stacks("", metric, &mut out)
+            .expect("writing to a String cannot fail");
+        out
+    }
+}
+
+impl fmt::Display for ConstraintProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.root.fmt_with_depth(f, 0)
+    }
+}
+
+fn write_json_string(s: &str, out: &mut String) -> fmt::Result {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use algebra::{bls12_381::Fr, One};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn circuit(cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let a = cs.new_witness_variable(|| Ok(Fr::one()))?;
+        cs.enforce_constraint(lc!() + a, lc!() + a, lc!() + a)?;
+        {
+            let outer = ns!(cs, "outer");
+            let cs = outer.cs();
+            let b = cs.new_witness_variable(|| Ok(Fr::one()))?;
+            cs.enforce_constraint(
+                lc!() + a + b,
+                lc!() + Variable::One,
+                lc!() + (Fr::one() + Fr::one(), b),
+            )?;
+            {
+                let inner = ns!(cs, "inner");
+                let cs = inner.cs();
+                let c = cs.new_witness_variable(|| Ok(Fr::one()))?;
+                cs.enforce_constraint(lc!() + c, lc!() + c, lc!() + c)?;
+                cs.enforce_constraint(lc!() + c, lc!() + b, lc!() + a)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn profile() -> Result<(), SynthesisError> {
+        let subscriber = tracing_subscriber::Registry::default().with(ConstraintLayer::default());
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        tracing::subscriber::with_default(subscriber, || circuit(cs.clone()))?;
+        cs.finalize()?;
+        assert!(cs.is_satisfied()?);
+
+        let profile = cs.profile().unwrap();
+        let root = &profile.root;
+        assert_eq!(root.total.num_constraints, 4);
+        assert_eq!(root.total.num_witness_variables, 3);
+        assert_eq!(root.total.num_nonzero_entries, 13);
+        assert_eq!(root.own.num_constraints, 1);
+        assert_eq!(root.own.num_witness_variables, 1);
+
+        let outer = root.get(&["profile::tests::outer"]).unwrap();
+        assert_eq!(outer.own.num_constraints, 1);
+        assert_eq!(outer.own.num_nonzero_entries, 4);
+        assert_eq!(outer.total.num_constraints, 3);
+        assert_eq!(outer.total.num_witness_variables, 2);
+
+        let inner = outer.child("inner").unwrap();
+        assert_eq!(inner.own.num_constraints, 2);
+        assert_eq!(inner.own.num_witness_variables, 1);
+        assert!(inner.children.is_empty());
+
+        assert_eq!(
+            profile.to_folded_stacks(ProfileMetric::Constraints),
+            "root 1\nroot;profile::tests::outer 1\nroot;profile::tests::outer;inner 2\n"
+        );
+        let json = profile.to_json();
+        assert!(json.starts_with(
+            "{\"name\":\"root\",\"own\":{\"constraints\":1,\"witness_variables\":1,\"nonzero_entries\":3},\
+             \"total\":{\"constraints\":4,\"witness_variables\":3,\"nonzero_entries\":13},\
+             \"children\":[{\"name\":\"profile::tests::outer\""
+        ));
+        assert!(format!("{}", profile).contains("\n    inner: 2 constraints"));
+        Ok(())
+    }
+}
@@ -228,6 +228,12 @@ impl ConstraintTrace {
         }
     }
 
+    /// Returns whether a `ConstraintLayer` is enabled in the current
+    /// dispatcher, i.e. whether captured traces would record any namespaces.
+    pub(crate) fn is_enabled() -> bool {
+        tracing::dispatcher::get_default(|dispatch| dispatch.is::<WithContext>())
+    }
+
     /// Apply a function to all captured spans in the trace until it returns
     /// `false`.
     ///
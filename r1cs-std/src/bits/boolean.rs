@@ -43,6 +43,15 @@ impl<F: Field> AllocatedBit<F> {
         self.variable
     }
 
+    /// Wrap `variable`, which must already be constrained to be boolean, as
+    /// an `AllocatedBit` of `cs`. No constraints are generated.
+    ///
+    /// This is meant for bits that are passed into or returned from
+    /// `ConstraintSystemRef::fork_join` as plain variables.
+    pub fn from_variable_unchecked(cs: ConstraintSystemRef<F>, variable: Variable) -> Self {
+        Self { variable, cs }
+    }
+
     /// Allocate a witness variable without a booleanity check.
     fn new_witness_without_booleanity_check<T: Borrow<bool>>(
         cs: ConstraintSystemRef<F>,
@@ -588,6 +597,56 @@ impl<F: Field> Boolean<F> {
         Ok(Self::kary_and(bits)?.not())
     }
 
+    /// Outputs `(a AND b) XOR ((NOT a) AND c)`, that is, `b` if `a` is `true`
+    /// and `c` otherwise. This is the `Ch` function of SHA-256.
+    ///
+    /// If none of the inputs are constants, this method uses a single
+    /// constraint.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn sha256_ch(a: &Self, b: &Self, c: &Self) -> Result<Self, SynthesisError> {
+        if a.is_constant() || b.is_constant() || c.is_constant() {
+            return c.xor(&a.and(&b.xor(c)?)?);
+        }
+        let cs = a.cs().or(b.cs()).or(c.cs());
+        let result = AllocatedBit::new_witness_without_booleanity_check(cs.clone(), || {
+            let (a, b, c) = (a.value()?, b.value()?, c.value()?);
+            Ok((a & b) ^ (!a & c))
+        })?;
+
+        // Constrain a * (b - c) = result - c. If a is 1, then result = b,
+        // and otherwise result = c, so result is boolean.
+        cs.enforce_constraint(a.lc(), b.lc() - c.lc(), lc!() + result.variable - c.lc())?;
+        Ok(Boolean::Is(result))
+    }
+
+    /// Outputs `(a AND b) XOR (a AND c) XOR (b AND c)`, that is, the value
+    /// held by the majority of the inputs. This is the `Maj` function of
+    /// SHA-256.
+    ///
+    /// If none of the inputs are constants, this method uses two constraints.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn sha256_maj(a: &Self, b: &Self, c: &Self) -> Result<Self, SynthesisError> {
+        if a.is_constant() || b.is_constant() || c.is_constant() {
+            return a.and(b)?.xor(&c.and(&a.xor(b)?)?);
+        }
+        let cs = a.cs().or(b.cs()).or(c.cs());
+        let bc = b.and(c)?;
+        let result = AllocatedBit::new_witness_without_booleanity_check(cs.clone(), || {
+            let (a, b, c) = (a.value()?, b.value()?, c.value()?);
+            Ok((a & b) ^ (a & c) ^ (b & c))
+        })?;
+
+        // Constrain a * (b + c - 2bc) = result - bc. If a is 1, then
+        // result = b OR c = b + c - bc, and otherwise result = bc, so result
+        // is boolean.
+        cs.enforce_constraint(
+            a.lc(),
+            b.lc() + c.lc() + (-F::one().double(), bc.lc()),
+            lc!() + result.variable - bc.lc(),
+        )?;
+        Ok(Boolean::Is(result))
+    }
+
     /// Enforces that `Self::kary_nand(bits).is_eq(&Boolean::TRUE)`.
     ///
     /// Informally, this means that at least one element in `bits` must be
@@ -628,6 +687,10 @@ impl<F: Field> Boolean<F> {
 
     /// Enforces that `bits` is less than or equal to `element`,
     /// when both are interpreted as (little-endian) integers.
+    ///
+    /// This handles arbitrary bounds such as `p - 1`. To check that a field
+    /// element is smaller than a power of two, use
+    /// `FpVar::enforce_smaller_than_power_of_two`, which is cheaper.
     #[tracing::instrument(target = "r1cs", skip(element))]
     pub fn enforce_smaller_or_equal_than_le<'a>(
         bits: &[Self],
@@ -965,6 +1028,43 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_sha256_ch_and_maj() -> Result<(), SynthesisError> {
+        let modes = [
+            AllocationMode::Constant,
+            AllocationMode::Witness,
+            AllocationMode::Input,
+        ];
+        for a_val in [false, true].iter().copied() {
+            for b_val in [false, true].iter().copied() {
+                for c_val in [false, true].iter().copied() {
+                    for a_mode in modes.iter().copied() {
+                        for b_mode in modes.iter().copied() {
+                            for c_mode in modes.iter().copied() {
+                                let cs = ConstraintSystem::<Fr>::new_ref();
+                                let a = Boolean::new_variable(cs.clone(), || Ok(a_val), a_mode)?;
+                                let b = Boolean::new_variable(cs.clone(), || Ok(b_val), b_mode)?;
+                                let c = Boolean::new_variable(cs.clone(), || Ok(c_val), c_mode)?;
+                                let not_a = a.not();
+
+                                let ch = Boolean::sha256_ch(&not_a, &b, &c)?;
+                                let maj = Boolean::sha256_maj(&not_a, &b, &c)?;
+                                let a_val = !a_val;
+                                assert_eq!(ch.value()?, (a_val & b_val) ^ (!a_val & c_val));
+                                assert_eq!(
+                                    maj.value()?,
+                                    (a_val & b_val) ^ (a_val & c_val) ^ (b_val & c_val)
+                                );
+                                assert!(cs.is_satisfied().unwrap());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_and() -> Result<(), SynthesisError> {
         for a_val in [false, true].iter().copied() {
@@ -7,12 +7,12 @@ use r1cs_core::SynthesisError;
 
 /// This module contains `Boolean`, a R1CS equivalent of the `bool` type.
 pub mod boolean;
-/// This module contains `UInt8`, a R1CS equivalent of the `u8` type.
-pub mod uint8;
 /// This module contains a macro for generating `UIntN` types, which are R1CS
 /// equivalents of `N`-bit unsigned integers.
 #[macro_use]
 pub mod uint;
+/// This module contains `UInt8`, a R1CS equivalent of the `u8` type.
+pub mod uint8;
 
 make_uint!(UInt16, 16, u16, uint16, "16");
 make_uint!(UInt32, 32, u32, uint32, "32");
@@ -1,3 +1,352 @@
+/// Implements the bitwise, shift, conversion, subtraction, multiplication
+/// and comparison methods shared by `UInt8` and the `make_uint!` types. The
+/// names used here must be in scope where the macro is invoked.
+macro_rules! impl_uint_ops {
+    ($name:ident, $size:expr, $native:ident, $native_doc_name:expr) => {
+        impl<F: Field> $name<F> {
+            /// Outputs `self & other`.
+            ///
+            /// If at least one of `self` and `other` are constants, then this method
+            /// *does not* create any constraints or variables.
+            #[tracing::instrument(target = "r1cs", skip(self, other))]
+            pub fn and(&self, other: &Self) -> Result<Self, SynthesisError> {
+                let new_value = match (self.value, other.value) {
+                    (Some(a), Some(b)) => Some(a & b),
+                    _ => None,
+                };
+
+                let bits = self
+                    .bits
+                    .iter()
+                    .zip(other.bits.iter())
+                    .map(|(a, b)| a.and(b))
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Self {
+                    bits,
+                    value: new_value,
+                })
+            }
+
+            /// Outputs `self | other`.
+            ///
+            /// If at least one of `self` and `other` are constants, then this method
+            /// *does not* create any constraints or variables.
+            #[tracing::instrument(target = "r1cs", skip(self, other))]
+            pub fn or(&self, other: &Self) -> Result<Self, SynthesisError> {
+                let new_value = match (self.value, other.value) {
+                    (Some(a), Some(b)) => Some(a | b),
+                    _ => None,
+                };
+
+                let bits = self
+                    .bits
+                    .iter()
+                    .zip(other.bits.iter())
+                    .map(|(a, b)| a.or(b))
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Self {
+                    bits,
+                    value: new_value,
+                })
+            }
+
+            /// Outputs `!self`.
+            ///
+            /// This *does not* create any constraints or variables.
+            #[tracing::instrument(target = "r1cs", skip(self))]
+            pub fn not(&self) -> Self {
+                Self {
+                    bits: self.bits.iter().map(Boolean::not).collect(),
+                    value: self.value.map(|v| !v),
+                }
+            }
+
+            /// Shifts `self` to the left by `by` steps, filling in zeros.
+            /// Shifting by at least
+            #[doc = $native_doc_name]
+            /// steps outputs zero.
+            ///
+            /// This *does not* create any constraints or variables.
+            #[tracing::instrument(target = "r1cs", skip(self))]
+            pub fn shl(&self, by: usize) -> Self {
+                let new_bits = core::iter::repeat(Boolean::FALSE)
+                    .take(by)
+                    .chain(self.bits.iter().cloned())
+                    .take($size)
+                    .collect();
+
+                Self {
+                    bits: new_bits,
+                    value: self.value.map(|v| {
+                        u32::try_from(by)
+                            .ok()
+                            .and_then(|by| v.checked_shl(by))
+                            .unwrap_or(0)
+                    }),
+                }
+            }
+
+            /// Shifts `self` to the right by `by` steps, filling in zeros.
+            /// Shifting by at least
+            #[doc = $native_doc_name]
+            /// steps outputs zero.
+            ///
+            /// This *does not* create any constraints or variables.
+            #[tracing::instrument(target = "r1cs", skip(self))]
+            pub fn shr(&self, by: usize) -> Self {
+                let new_bits = self
+                    .bits
+                    .iter()
+                    .skip(by)
+                    .cloned()
+                    .chain(core::iter::repeat(Boolean::FALSE))
+                    .take($size)
+                    .collect();
+
+                Self {
+                    bits: new_bits,
+                    value: self.value.map(|v| {
+                        u32::try_from(by)
+                            .ok()
+                            .and_then(|by| v.checked_shr(by))
+                            .unwrap_or(0)
+                    }),
+                }
+            }
+
+            /// Packs the bits of `self` into a single field element.
+            ///
+            /// This *does not* create any constraints.
+            #[tracing::instrument(target = "r1cs", skip(self))]
+            pub fn to_fp(&self) -> Result<FpVar<F>, SynthesisError>
+            where
+                F: PrimeField,
+            {
+                let value = self.value.map(|v| F::from(u64::from(v)));
+                if self.bits.is_constant() {
+                    return value.get().map(FpVar::Constant);
+                }
+
+                let mut lc = LinearCombination::zero();
+                let mut coeff = F::one();
+                for bit in &self.bits {
+                    lc = &lc + bit.lc() * coeff;
+                    coeff.double_in_place();
+                }
+                let cs = self.cs();
+                let variable = cs.new_lc(lc)?;
+                Ok(FpVar::Var(AllocatedFp::new(value, variable, cs)))
+            }
+
+            /// Converts `other` into `Self`, enforcing that `other` is smaller than
+            /// `2^`
+            #[doc = $native_doc_name]
+            /// `.`
+            ///
+            /// This costs one constraint per bit, plus one packing constraint.
+            #[tracing::instrument(target = "r1cs")]
+            pub fn from_fp(other: &FpVar<F>) -> Result<Self, SynthesisError>
+            where
+                F: PrimeField,
+            {
+                let bits = other.enforce_smaller_than_power_of_two($size)?;
+                Ok(Self::from_bits_le(&bits))
+            }
+
+            /// Outputs `(self - other mod 2^N, borrow)`, where `borrow` is set if and
+            /// only if `other > self`.
+            #[tracing::instrument(target = "r1cs", skip(self, other))]
+            pub fn sub_with_borrow(
+                &self,
+                other: &Self,
+            ) -> Result<(Self, Boolean<F>), SynthesisError>
+            where
+                F: PrimeField,
+            {
+                // `self - other + 2^N` lies in `[1, 2^(N + 1))`, and its most
+                // significant bit is set if and only if there is no borrow.
+                let offset = F::from(u64::from($native::MAX)) + F::one();
+                let difference = self.to_fp()? - other.to_fp()? + offset;
+                let mut bits = difference.enforce_smaller_than_power_of_two($size + 1)?;
+                let no_borrow = bits.pop().unwrap();
+                Ok((Self::from_bits_le(&bits), no_borrow.not()))
+            }
+
+            /// Outputs `self - other`, wrapping around on underflow.
+            #[tracing::instrument(target = "r1cs", skip(self, other))]
+            pub fn wrapping_sub(&self, other: &Self) -> Result<Self, SynthesisError>
+            where
+                F: PrimeField,
+            {
+                self.sub_with_borrow(other).map(|(result, _)| result)
+            }
+
+            /// Outputs `self * other`, wrapping around on overflow.
+            #[tracing::instrument(target = "r1cs", skip(self, other))]
+            pub fn wrapping_mul(&self, other: &Self) -> Result<Self, SynthesisError>
+            where
+                F: PrimeField,
+            {
+                let product = self.to_fp()? * other.to_fp()?;
+                let bits = product.enforce_smaller_than_power_of_two(2 * $size)?;
+                Ok(Self::from_bits_le(&bits[..$size]))
+            }
+
+            /// Outputs `self * other`, and enforces that the product does not
+            /// overflow.
+            ///
+            /// This is cheaper than `wrapping_mul`, but the constraint system is not
+            /// satisfied if the product overflows. If both `self` and `other` are
+            /// constants, then an overflow results in
+            /// `SynthesisError::Unsatisfiable`.
+            #[tracing::instrument(target = "r1cs", skip(self, other))]
+            pub fn checked_mul(&self, other: &Self) -> Result<Self, SynthesisError>
+            where
+                F: PrimeField,
+            {
+                let product = self.to_fp()? * other.to_fp()?;
+                Self::from_fp(&product)
+            }
+
+            /// Outputs `self < other`.
+            #[tracing::instrument(target = "r1cs", skip(self, other))]
+            pub fn is_lt(&self, other: &Self) -> Result<Boolean<F>, SynthesisError>
+            where
+                F: PrimeField,
+            {
+                self.sub_with_borrow(other).map(|(_, borrow)| borrow)
+            }
+
+            /// Outputs `self <= other`.
+            #[tracing::instrument(target = "r1cs", skip(self, other))]
+            pub fn is_le(&self, other: &Self) -> Result<Boolean<F>, SynthesisError>
+            where
+                F: PrimeField,
+            {
+                other.is_lt(self).map(|gt| gt.not())
+            }
+        }
+    };
+}
+
+/// Generates the tests for the methods implemented by `impl_uint_ops!`.
+#[cfg(test)]
+macro_rules! impl_uint_ops_tests {
+    ($name:ident, $size:expr, $native:ident) => {
+            #[test]
+            fn test_bitwise() -> Result<(), SynthesisError> {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for _ in 0..100 {
+                    let cs = ConstraintSystem::<Fr>::new_ref();
+
+                    let a: $native = rng.gen();
+                    let b: $native = rng.gen();
+                    let by = rng.gen_range(0, $size + 2);
+
+                    let a_var = $name::new_witness(cs.clone(), || Ok(a))?;
+                    let b_var = $name::new_witness(cs.clone(), || Ok(b))?;
+
+                    assert_eq!(a_var.and(&b_var)?.value()?, a & b);
+                    assert_eq!(a_var.or(&b_var)?.value()?, a | b);
+                    assert_eq!(a_var.not().value()?, !a);
+                    assert_eq!(a_var.shl(by).value()?, a.checked_shl(by as u32).unwrap_or(0));
+                    assert_eq!(a_var.shr(by).value()?, a.checked_shr(by as u32).unwrap_or(0));
+                    assert_eq!(a_var.and(&$name::constant(b))?.value()?, a & b);
+                    assert!(cs.is_satisfied().unwrap());
+                }
+                Ok(())
+            }
+
+            #[test]
+            fn test_fp_conversion() -> Result<(), SynthesisError> {
+                use crate::fields::fp::FpVar;
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for _ in 0..100 {
+                    let cs = ConstraintSystem::<Fr>::new_ref();
+
+                    let a: $native = rng.gen();
+                    let a_var = $name::new_witness(cs.clone(), || Ok(a))?;
+                    let num_constraints = cs.num_constraints();
+                    let a_fp = a_var.to_fp()?;
+                    assert_eq!(a_fp.value()?, Fr::from(u64::from(a)));
+                    assert_eq!(cs.num_constraints(), num_constraints);
+
+                    let b = $name::from_fp(&a_fp)?;
+                    assert_eq!(b.value()?, a);
+                    b.enforce_equal(&a_var)?;
+                    assert!(cs.is_satisfied().unwrap());
+
+                    let too_big = FpVar::new_witness(cs.clone(), || {
+                        Ok(Fr::from(u64::from($native::MAX)) + Fr::from(1u64))
+                    })?;
+                    let _ = $name::from_fp(&too_big)?;
+                    assert!(!cs.is_satisfied().unwrap());
+                }
+                Ok(())
+            }
+
+            #[test]
+            fn test_sub_and_cmp() -> Result<(), SynthesisError> {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for i in 0..100 {
+                    let cs = ConstraintSystem::<Fr>::new_ref();
+
+                    let a: $native = rng.gen();
+                    let b: $native = if i % 10 == 0 { a } else { rng.gen() };
+
+                    let a_var = $name::new_witness(cs.clone(), || Ok(a))?;
+                    let b_var = $name::new_witness(cs.clone(), || Ok(b))?;
+
+                    let (diff, borrow) = a_var.sub_with_borrow(&b_var)?;
+                    assert_eq!(diff.value()?, a.wrapping_sub(b));
+                    assert_eq!(borrow.value()?, a < b);
+                    let b_const = $name::constant(b);
+                    assert_eq!(a_var.wrapping_sub(&b_const)?.value()?, a.wrapping_sub(b));
+                    assert_eq!(a_var.is_lt(&b_var)?.value()?, a < b);
+                    assert_eq!(a_var.is_le(&b_var)?.value()?, a <= b);
+                    assert_eq!(b_var.is_le(&a_var)?.value()?, b <= a);
+                    assert!(cs.is_satisfied().unwrap());
+                }
+                Ok(())
+            }
+
+            #[test]
+            fn test_mul() -> Result<(), SynthesisError> {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for _ in 0..100 {
+                    let cs = ConstraintSystem::<Fr>::new_ref();
+
+                    let a: $native = rng.gen();
+                    let b: $native = rng.gen();
+                    let a_var = $name::new_witness(cs.clone(), || Ok(a))?;
+                    let b_var = $name::new_witness(cs.clone(), || Ok(b))?;
+
+                    assert_eq!(a_var.wrapping_mul(&b_var)?.value()?, a.wrapping_mul(b));
+                    assert!(cs.is_satisfied().unwrap());
+
+                    let small: $native = rng.gen_range(0, 16);
+                    let small_var = $name::new_witness(cs.clone(), || Ok(small))?;
+                    let half = a >> 4;
+                    let half_var = $name::new_witness(cs.clone(), || Ok(half))?;
+                    assert_eq!(half_var.checked_mul(&small_var)?.value()?, half * small);
+                    assert!(cs.is_satisfied().unwrap());
+
+                    if a.checked_mul(b).is_none() {
+                        let _ = a_var.checked_mul(&b_var)?;
+                        assert!(!cs.is_satisfied().unwrap());
+                    }
+                }
+                Ok(())
+            }
+    };
+}
+
 macro_rules! make_uint {
     ($name:ident, $size:expr, $native:ident, $mod_name:ident, $native_doc_name:expr) => {
         #[doc = "This module contains a `UInt"]
@@ -16,6 +365,7 @@ macro_rules! make_uint {
 
             use crate::{
                 boolean::{AllocatedBit, Boolean},
+                fields::fp::{AllocatedFp, FpVar},
                 prelude::*,
                 Assignment, Vec,
             };
@@ -292,6 +642,8 @@ macro_rules! make_uint {
                 }
             }
 
+            impl_uint_ops!($name, $size, $native, $native_doc_name);
+
             impl<ConstraintF: Field> ToBytesGadget<ConstraintF> for $name<ConstraintF> {
                 #[tracing::instrument(target = "r1cs", skip(self))]
                 fn to_bytes(&self) -> Result<Vec<UInt8<ConstraintF>>, SynthesisError> {
@@ -506,6 +858,8 @@ macro_rules! make_uint {
                     Ok(())
                 }
 
+                impl_uint_ops_tests!($name, $size, $native);
+
                 #[test]
                 fn test_rotr() -> Result<(), SynthesisError> {
                     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -1,9 +1,13 @@
 use algebra::{Field, FpParameters, PrimeField, ToConstraintField};
 
-use r1cs_core::{ConstraintSystemRef, Namespace, SynthesisError};
+use r1cs_core::{ConstraintSystemRef, LinearCombination, Namespace, SynthesisError};
 
-use crate::{fields::fp::AllocatedFp, prelude::*, Assignment, Vec};
-use core::borrow::Borrow;
+use crate::{
+    fields::fp::{AllocatedFp, FpVar},
+    prelude::*,
+    Assignment, Vec,
+};
+use core::{borrow::Borrow, convert::TryFrom};
 
 /// Represents an interpretation of 8 `Boolean` objects as an
 /// unsigned integer.
@@ -258,6 +262,8 @@ impl<F: Field> UInt8<F> {
     }
 }
 
+impl_uint_ops!(UInt8, 8, u8, "8");
+
 impl<ConstraintF: Field> EqGadget<ConstraintF> for UInt8<ConstraintF> {
     #[tracing::instrument(target = "r1cs")]
     fn is_eq(&self, other: &Self) -> Result<Boolean<ConstraintF>, SynthesisError> {
@@ -417,4 +423,6 @@ mod test {
         }
         Ok(())
     }
+
+    impl_uint_ops_tests!(UInt8, 8, u8);
 }
@@ -2,11 +2,11 @@ use crate::{
     boolean::Boolean,
     fields::{fp::FpVar, FieldVar},
     prelude::*,
-    ToBitsGadget,
+    Assignment, ToBitsGadget, Vec,
 };
-use algebra::PrimeField;
+use algebra::{BitIteratorLE, FpParameters, PrimeField};
 use core::cmp::Ordering;
-use r1cs_core::{lc, SynthesisError, Variable};
+use r1cs_core::{lc, LinearCombination, SynthesisError, Variable};
 
 impl<F: PrimeField> FpVar<F> {
     /// This function enforces the ordering between `self` and `other`. The
@@ -102,15 +102,72 @@ impl<F: PrimeField> FpVar<F> {
     pub fn enforce_smaller_or_equal_than_mod_minus_one_div_two(
         &self,
     ) -> Result<(), SynthesisError> {
-        // It's okay to use `to_non_unique_bits` bits here because we're enforcing
-        // self <= (p-1)/2, which implies self < p.
-        let _ = Boolean::enforce_smaller_or_equal_than_le(
-            &self.to_non_unique_bits_le()?,
-            F::modulus_minus_one_div_two(),
-        )?;
+        // `(p-1)/2` has exactly `CAPACITY` bits, so first decompose `self`
+        // into that many bits, and then compare the bits against `(p-1)/2`.
+        let bits = self.enforce_smaller_than_power_of_two(F::Params::CAPACITY as usize)?;
+        let _ = Boolean::enforce_smaller_or_equal_than_le(&bits, F::modulus_minus_one_div_two())?;
         Ok(())
     }
 
+    /// Enforces that `self < 2^num_bits`, and outputs the little-endian
+    /// decomposition of `self` into `num_bits` bits so that callers can reuse
+    /// it.
+    ///
+    /// This costs `num_bits + 1` constraints: one to check that each bit is
+    /// boolean, and a single one that packs all of the bits back into `self`.
+    /// If `self` is a constant, no constraints are generated, and
+    /// `SynthesisError::Unsatisfiable` is returned if it is out of range.
+    ///
+    /// `SynthesisError::BitLengthTooLarge` is returned if
+    /// `num_bits > F::Params::CAPACITY`, because the packed bits could then
+    /// wrap around the modulus.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn enforce_smaller_than_power_of_two(
+        &self,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean<F>>, SynthesisError> {
+        if num_bits > F::Params::CAPACITY as usize {
+            return Err(SynthesisError::BitLengthTooLarge);
+        }
+        match self {
+            Self::Constant(c) => {
+                let mut bits = BitIteratorLE::without_trailing_zeros(c.into_repr())
+                    .map(Boolean::constant)
+                    .collect::<Vec<_>>();
+                if bits.len() > num_bits {
+                    return Err(SynthesisError::Unsatisfiable);
+                }
+                bits.resize(num_bits, Boolean::FALSE);
+                Ok(bits)
+            },
+            Self::Var(v) => {
+                let cs = v.cs.clone();
+                // If `self` is out of range, these are only the low bits of
+                // `self`, and the packing constraint below is not satisfied.
+                let values = v.value.map(|value| {
+                    BitIteratorLE::new(value.into_repr())
+                        .take(num_bits)
+                        .collect::<Vec<_>>()
+                });
+                let bits = (0..num_bits)
+                    .map(|i| {
+                        Boolean::new_witness(cs.clone(), || values.as_ref().map(|b| b[i]).get())
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let mut lc = LinearCombination::zero();
+                let mut coeff = F::one();
+                for bit in &bits {
+                    lc = &lc + bit.lc() * coeff;
+                    coeff.double_in_place();
+                }
+                lc = lc - v.variable;
+                cs.enforce_constraint(lc!(), lc!(), lc)?;
+                Ok(bits)
+            },
+        }
+    }
+
     /// Helper function to check `self < other` and output a result bit. This
     /// function verifies `self` and `other` are `<= (p-1)/2`.
     fn is_smaller_than(&self, other: &FpVar<F>) -> Result<Boolean<F>, SynthesisError> {
@@ -122,6 +179,9 @@ impl<F: PrimeField> FpVar<F> {
     /// Helper function to check `self < other` and output a result bit. This
     /// function assumes `self` and `other` are `<= (p-1)/2` and does not
     /// generate constraints to verify that.
+    ///
+    /// `2 * (self - other)` can be any element of the field, so this needs its
+    /// full in-field decomposition rather than a power-of-two range check.
     fn is_smaller_than_unchecked(&self, other: &FpVar<F>) -> Result<Boolean<F>, SynthesisError> {
         Ok((self - other)
             .double()?
@@ -158,8 +218,8 @@ mod test {
     use std::cmp::Ordering;
 
     use crate::{alloc::AllocVar, fields::fp::FpVar};
-    use algebra::{bls12_381::Fr, PrimeField, UniformRand};
-    use r1cs_core::ConstraintSystem;
+    use algebra::{bls12_381::Fr, FpParameters, One, PrimeField, UniformRand, Zero};
+    use r1cs_core::{ConstraintSystem, SynthesisError};
 
     #[test]
     fn test_cmp() {
@@ -243,4 +303,62 @@ mod test {
             assert!(cs.is_satisfied().unwrap());
         }
     }
+
+    #[test]
+    fn test_smaller_than_power_of_two() {
+        use crate::R1CSVar;
+
+        for (value, num_bits, expected) in [
+            (0u64, 0, true),
+            (5, 3, true),
+            (8, 3, false),
+            (255, 8, true),
+            (256, 8, false),
+        ]
+        .iter()
+        {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let a_var = FpVar::<Fr>::new_witness(cs.clone(), || Ok(Fr::from(*value))).unwrap();
+            let bits = a_var.enforce_smaller_than_power_of_two(*num_bits).unwrap();
+            assert_eq!(bits.len(), *num_bits);
+            assert_eq!(cs.num_constraints(), num_bits + 1);
+            assert_eq!(cs.is_satisfied().unwrap(), *expected);
+            if *expected {
+                let recomposed = bits
+                    .iter()
+                    .rev()
+                    .fold(0u64, |acc, b| (acc << 1) + u64::from(b.value().unwrap()));
+                assert_eq!(recomposed, *value);
+            }
+
+            let constant = FpVar::<Fr>::Constant(Fr::from(*value));
+            let bits = constant.enforce_smaller_than_power_of_two(*num_bits);
+            assert_eq!(bits.is_ok(), *expected);
+        }
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a_var = FpVar::<Fr>::new_witness(cs.clone(), || Ok(Fr::one())).unwrap();
+        let capacity = <Fr as PrimeField>::Params::CAPACITY as usize;
+        assert_eq!(
+            a_var
+                .enforce_smaller_than_power_of_two(capacity + 1)
+                .unwrap_err(),
+            SynthesisError::BitLengthTooLarge
+        );
+        assert_eq!(cs.num_constraints(), 0);
+    }
+
+    #[test]
+    fn test_smaller_or_equal_than_mod_minus_one_div_two() {
+        let half: Fr = Fr::modulus_minus_one_div_two().into();
+        let cases = [(Fr::zero(), true), (half, true), (half + Fr::one(), false)];
+        for (value, expected) in cases.iter() {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let a_var = FpVar::<Fr>::new_witness(cs.clone(), || Ok(*value)).unwrap();
+            a_var
+                .enforce_smaller_or_equal_than_mod_minus_one_div_two()
+                .unwrap();
+            assert_eq!(cs.is_satisfied().unwrap(), *expected);
+        }
+    }
 }
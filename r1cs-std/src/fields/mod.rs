@@ -20,6 +20,11 @@ pub mod quadratic_extension;
 /// That is, it implements the R1CS equivalent of `algebra_core::Fp*`.
 pub mod fp;
 
+/// This module contains a generic implementation of "non-native" prime field
+/// variables. It emulates arithmetic in a prime field `TargetF` using
+/// arithmetic in a different prime field `BaseF`.
+pub mod nonnative;
+
 /// This module contains a generic implementation of the degree-12 tower
 /// extension field. That is, it implements the R1CS equivalent of
 /// `algebra_core::Fp12`
@@ -0,0 +1,466 @@
+use algebra::{BigInteger, PrimeField};
+use core::{borrow::Borrow, cmp::max, marker::PhantomData};
+use r1cs_core::{ConstraintSystemRef, Namespace, SynthesisError};
+
+use super::{
+    params::NonNativeFieldParams,
+    reduce::{
+        alloc_normal_limbs, biguint_to_field, biguint_to_limbs, enforce_bit_length,
+        enforce_quotient, field_to_biguint, limbs_to_biguint, modulus, normal_limb_widths, params,
+        reduce,
+    },
+};
+use crate::{fields::fp::FpVar, prelude::*, ToConstraintFieldGadget, Vec};
+
+/// Represents a variable in the constraint system whose value is an
+/// element of `TargetF`, but where the constraint system is defined over
+/// `BaseF`.
+///
+/// The value is stored as little-endian limbs of
+/// `Self::params().bits_per_limb` bits. Additions and subtractions are
+/// lazy: they let the limbs grow, and the result is only reduced modulo the
+/// characteristic of `TargetF` when necessary.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct AllocatedNonNativeFieldVar<TargetF: PrimeField, BaseF: PrimeField> {
+    /// The constraint system that `self` was allocated in.
+    pub cs: ConstraintSystemRef<BaseF>,
+    /// The limbs of `self`, in little-endian order.
+    pub limbs: Vec<FpVar<BaseF>>,
+    /// Every limb of `self` is smaller than `2^limb_bits`. When this is equal
+    /// to `bits_per_limb`, `self` is reduced.
+    pub(crate) limb_bits: usize,
+    _target: PhantomData<TargetF>,
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> AllocatedNonNativeFieldVar<TargetF, BaseF> {
+    /// Returns the parameters used to represent `TargetF` elements over
+    /// `BaseF`.
+    pub fn params() -> NonNativeFieldParams {
+        params::<TargetF, BaseF>()
+    }
+
+    fn bits_per_limb() -> usize {
+        Self::params().bits_per_limb
+    }
+
+    fn max_limb_bits() -> usize {
+        NonNativeFieldParams::max_limb_bits(BaseF::size_in_bits())
+    }
+
+    fn new_reduced(cs: ConstraintSystemRef<BaseF>, limbs: Vec<FpVar<BaseF>>) -> Self {
+        Self {
+            cs,
+            limbs,
+            limb_bits: Self::bits_per_limb(),
+            _target: PhantomData,
+        }
+    }
+
+    /// Constructs a variable in `cs` whose limbs are the constant limbs of
+    /// `value`. This does not allocate any variables.
+    pub fn constant(cs: ConstraintSystemRef<BaseF>, value: TargetF) -> Self {
+        let params = Self::params();
+        let limbs = biguint_to_limbs(
+            &field_to_biguint(&value),
+            params.bits_per_limb,
+            params.num_limbs,
+        )
+        .iter()
+        .map(|limb| FpVar::Constant(biguint_to_field(limb)))
+        .collect();
+        Self::new_reduced(cs, limbs)
+    }
+
+    /// Returns the value assigned to `self` in the underlying constraint
+    /// system.
+    pub fn value(&self) -> Result<TargetF, SynthesisError> {
+        let value = limbs_to_biguint(&self.limbs, Self::bits_per_limb())?;
+        Ok(biguint_to_field(&(value % modulus::<TargetF>())))
+    }
+
+    fn is_reduced(&self) -> bool {
+        self.limb_bits == Self::bits_per_limb()
+    }
+
+    /// Outputs a variable with the same value as `self` whose limbs are in
+    /// normal form, i.e. whose limbs represent an integer smaller than
+    /// `2^{TargetF::size_in_bits()}`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn reduce(&self) -> Result<Self, SynthesisError> {
+        if self.is_reduced() {
+            return Ok(self.clone());
+        }
+        let limbs = reduce::<TargetF, BaseF>(&self.cs, &self.limbs, self.limb_bits)?;
+        Ok(Self::new_reduced(self.cs.clone(), limbs))
+    }
+
+    /// Outputs `self + other`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn add(&self, other: &Self) -> Result<Self, SynthesisError> {
+        let limb_bits = max(self.limb_bits, other.limb_bits) + 1;
+        if limb_bits > Self::max_limb_bits() {
+            return self.reduce()?.add(&other.reduce()?);
+        }
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(&other.limbs)
+            .map(|(a, b)| a + b)
+            .collect();
+        Ok(Self {
+            cs: self.cs.clone().or(other.cs.clone()),
+            limbs,
+            limb_bits,
+            _target: PhantomData,
+        })
+    }
+
+    /// Outputs `self + other`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn add_constant(&self, other: &TargetF) -> Result<Self, SynthesisError> {
+        self.add(&Self::constant(self.cs.clone(), *other))
+    }
+
+    /// Outputs `self - other`.
+    ///
+    /// To avoid negative limbs, this adds to `self` a multiple of the
+    /// characteristic of `TargetF` whose limbs are all larger than those of
+    /// `other`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn sub(&self, other: &Self) -> Result<Self, SynthesisError> {
+        let limb_bits = max(self.limb_bits, other.limb_bits + 1) + 1;
+        if limb_bits > Self::max_limb_bits() {
+            return self.reduce()?.sub(&other.reduce()?);
+        }
+        let params = Self::params();
+        let b = params.bits_per_limb;
+        let p = modulus::<TargetF>();
+
+        // `pad` has every limb equal to `2^other.limb_bits`. We add to it the
+        // reduced limbs of `-pad mod p`, so that the result is divisible by
+        // `p`.
+        let pad_limb = num_bigint::BigUint::from(1u64) << other.limb_bits;
+        let pad = (0..params.num_limbs).fold(num_bigint::BigUint::from(0u64), |acc, _| {
+            (acc << b) + &pad_limb
+        });
+        let correction = (&p - (pad % &p)) % &p;
+        let pad_limbs = biguint_to_limbs(&correction, b, params.num_limbs)
+            .into_iter()
+            .map(|c| biguint_to_field::<BaseF>(&(c + &pad_limb)));
+
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(&other.limbs)
+            .zip(pad_limbs)
+            .map(|((a, b), c)| a + c - b)
+            .collect();
+        Ok(Self {
+            cs: self.cs.clone().or(other.cs.clone()),
+            limbs,
+            limb_bits,
+            _target: PhantomData,
+        })
+    }
+
+    /// Outputs `self - other`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn sub_constant(&self, other: &TargetF) -> Result<Self, SynthesisError> {
+        self.sub(&Self::constant(self.cs.clone(), *other))
+    }
+
+    /// Outputs `-self`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn negate(&self) -> Result<Self, SynthesisError> {
+        Self::constant(self.cs.clone(), TargetF::zero()).sub(self)
+    }
+
+    /// Outputs the limbs of the integer product of `self` and `other`,
+    /// together with a bound on their bit size. Both operands are reduced
+    /// first.
+    fn product_limbs(&self, other: &Self) -> Result<(Vec<FpVar<BaseF>>, usize), SynthesisError> {
+        let params = Self::params();
+        let a = self.reduce()?;
+        let b = other.reduce()?;
+        let num_limbs = 2 * params.num_limbs - 1;
+        let bits = 2 * params.bits_per_limb + algebra::log2(params.num_limbs) as usize;
+
+        if a.limbs.is_constant() || b.limbs.is_constant() {
+            // Every product below involves a constant, and so is free.
+            let mut product = vec![FpVar::zero(); num_limbs];
+            for (i, a_i) in a.limbs.iter().enumerate() {
+                for (j, b_j) in b.limbs.iter().enumerate() {
+                    product[i + j] += a_i * b_j;
+                }
+            }
+            return Ok((product, bits));
+        }
+
+        // Allocate the limbs of the product, and check them by evaluating
+        // the polynomials with coefficients `a`, `b` and `product` at
+        // `num_limbs` distinct points. Since the limbs are small, the
+        // product of the first two polynomials is computed without
+        // wrapping around the characteristic of `BaseF`.
+        let cs = a.cs.clone().or(b.cs.clone());
+        let product = (0..num_limbs)
+            .map(|k| {
+                FpVar::new_witness(r1cs_core::ns!(cs, "product limb"), || {
+                    let mut sum = BaseF::zero();
+                    for (i, a_i) in a.limbs.iter().enumerate() {
+                        if k >= i && k - i < b.limbs.len() {
+                            sum += a_i.value()? * b.limbs[k - i].value()?;
+                        }
+                    }
+                    Ok(sum)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let evaluate = |coeffs: &[FpVar<BaseF>], point: BaseF| {
+            let mut result = FpVar::zero();
+            let mut power = BaseF::one();
+            for c in coeffs {
+                result += c * power;
+                power *= &point;
+            }
+            result
+        };
+        for point in 0..num_limbs {
+            let point = BaseF::from(point as u64);
+            evaluate(&a.limbs, point)
+                .mul_equals(&evaluate(&b.limbs, point), &evaluate(&product, point))?;
+        }
+        Ok((product, bits))
+    }
+
+    /// Outputs `self * other`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn mul(&self, other: &Self) -> Result<Self, SynthesisError> {
+        let (product, bits) = self.product_limbs(other)?;
+        let cs = self.cs.clone().or(other.cs.clone());
+        let limbs = reduce::<TargetF, BaseF>(&cs, &product, bits)?;
+        Ok(Self::new_reduced(cs, limbs))
+    }
+
+    /// Outputs `self * other`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn mul_constant(&self, other: &TargetF) -> Result<Self, SynthesisError> {
+        self.mul(&Self::constant(self.cs.clone(), *other))
+    }
+
+    /// Enforces that `self * other == result`.
+    ///
+    /// This is cheaper than computing `self * other` and comparing it with
+    /// `result`, since the product does not have to be reduced.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn mul_equals(&self, other: &Self, result: &Self) -> Result<(), SynthesisError> {
+        let (product, bits) = self.product_limbs(other)?;
+        let result = result.reduce()?;
+        let cs = self.cs.clone().or(other.cs.clone()).or(result.cs.clone());
+        enforce_quotient::<TargetF, BaseF>(&cs, &product, bits, &result.limbs)
+    }
+
+    /// Outputs `self^{-1}`. If `self` is zero, the constraint system is
+    /// unsatisfied.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn inverse(&self) -> Result<Self, SynthesisError> {
+        let inverse = Self::new_witness(self.cs.clone(), || {
+            Ok(self.value()?.inverse().unwrap_or_else(TargetF::zero))
+        })?;
+        let one = Self::constant(self.cs.clone(), TargetF::one());
+        self.mul_equals(&inverse, &one)?;
+        Ok(inverse)
+    }
+
+    /// Outputs a `Boolean` that is `true` iff `self == other`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn is_eq(&self, other: &Self) -> Result<Boolean<BaseF>, SynthesisError> {
+        // The reduced difference is smaller than `2^{TargetF::size_in_bits()}`,
+        // and so is either `0` or `p` when `self == other`.
+        let params = Self::params();
+        let difference = self.sub(other)?.reduce()?;
+        let is_zero = difference
+            .limbs
+            .iter()
+            .map(|limb| limb.is_zero())
+            .collect::<Result<Vec<_>, _>>()?;
+        let is_modulus = difference
+            .limbs
+            .iter()
+            .zip(biguint_to_limbs(
+                &modulus::<TargetF>(),
+                params.bits_per_limb,
+                params.num_limbs,
+            ))
+            .map(|(limb, p)| limb.is_eq(&FpVar::Constant(biguint_to_field(&p))))
+            .collect::<Result<Vec<_>, _>>()?;
+        Boolean::kary_and(&is_zero)?.or(&Boolean::kary_and(&is_modulus)?)
+    }
+
+    /// If `should_enforce == true`, enforces that `self == other`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn conditional_enforce_equal(
+        &self,
+        other: &Self,
+        should_enforce: &Boolean<BaseF>,
+    ) -> Result<(), SynthesisError> {
+        let difference = self.sub(other)?;
+        let limbs = difference
+            .limbs
+            .iter()
+            .map(|limb| should_enforce.select(limb, &FpVar::zero()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let cs = difference.cs.or(should_enforce.cs());
+        enforce_quotient::<TargetF, BaseF>(&cs, &limbs, difference.limb_bits, &[])
+    }
+
+    /// If `should_enforce == true`, enforces that `self != other`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn conditional_enforce_not_equal(
+        &self,
+        other: &Self,
+        should_enforce: &Boolean<BaseF>,
+    ) -> Result<(), SynthesisError> {
+        self.is_eq(other)?
+            .and(should_enforce)?
+            .enforce_equal(&Boolean::FALSE)
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> ToBitsGadget<BaseF>
+    for AllocatedNonNativeFieldVar<TargetF, BaseF>
+{
+    /// Outputs the unique bit-wise decomposition of `self` in *little-endian*
+    /// form.
+    ///
+    /// This method enforces that the output represents an integer smaller
+    /// than the characteristic of `TargetF`.
+    #[tracing::instrument(target = "r1cs")]
+    fn to_bits_le(&self) -> Result<Vec<Boolean<BaseF>>, SynthesisError> {
+        let bits = self.to_non_unique_bits_le()?;
+        let modulus_minus_one = (modulus::<TargetF>() - 1u64).to_u64_digits();
+        Boolean::enforce_smaller_or_equal_than_le(&bits, &modulus_minus_one)?;
+        Ok(bits)
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn to_non_unique_bits_le(&self) -> Result<Vec<Boolean<BaseF>>, SynthesisError> {
+        let reduced = self.reduce()?;
+        let mut bits = Vec::with_capacity(TargetF::size_in_bits());
+        for (limb, width) in reduced
+            .limbs
+            .iter()
+            .zip(normal_limb_widths::<TargetF, BaseF>())
+        {
+            bits.extend(enforce_bit_length(limb, width)?);
+        }
+        Ok(bits)
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> ToBytesGadget<BaseF>
+    for AllocatedNonNativeFieldVar<TargetF, BaseF>
+{
+    /// Outputs the unique byte decomposition of `self` in *little-endian*
+    /// form.
+    #[tracing::instrument(target = "r1cs")]
+    fn to_bytes(&self) -> Result<Vec<UInt8<BaseF>>, SynthesisError> {
+        let mut bits = self.to_bits_le()?;
+        bits.resize(TargetF::BigInt::NUM_LIMBS * 64, Boolean::FALSE);
+        Ok(bits.chunks(8).map(UInt8::from_bits_le).collect())
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn to_non_unique_bytes(&self) -> Result<Vec<UInt8<BaseF>>, SynthesisError> {
+        let mut bits = self.to_non_unique_bits_le()?;
+        bits.resize(TargetF::BigInt::NUM_LIMBS * 64, Boolean::FALSE);
+        Ok(bits.chunks(8).map(UInt8::from_bits_le).collect())
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> ToConstraintFieldGadget<BaseF>
+    for AllocatedNonNativeFieldVar<TargetF, BaseF>
+{
+    /// Outputs the limbs of the reduced form of `self`.
+    #[tracing::instrument(target = "r1cs")]
+    fn to_constraint_field(&self) -> Result<Vec<FpVar<BaseF>>, SynthesisError> {
+        Ok(self.reduce()?.limbs)
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> CondSelectGadget<BaseF>
+    for AllocatedNonNativeFieldVar<TargetF, BaseF>
+{
+    #[tracing::instrument(target = "r1cs")]
+    fn conditionally_select(
+        cond: &Boolean<BaseF>,
+        true_value: &Self,
+        false_value: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let limbs = true_value
+            .limbs
+            .iter()
+            .zip(&false_value.limbs)
+            .map(|(t, f)| cond.select(t, f))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            cs: cond
+                .cs()
+                .or(true_value.cs.clone())
+                .or(false_value.cs.clone()),
+            limbs,
+            limb_bits: max(true_value.limb_bits, false_value.limb_bits),
+            _target: PhantomData,
+        })
+    }
+}
+
+/// Uses two bits to perform a lookup into a table
+/// `b` is little-endian: `b[0]` is LSB.
+impl<TargetF: PrimeField, BaseF: PrimeField> TwoBitLookupGadget<BaseF>
+    for AllocatedNonNativeFieldVar<TargetF, BaseF>
+{
+    type TableConstant = TargetF;
+
+    #[tracing::instrument(target = "r1cs")]
+    fn two_bit_lookup(
+        b: &[Boolean<BaseF>],
+        c: &[Self::TableConstant],
+    ) -> Result<Self, SynthesisError> {
+        debug_assert_eq!(c.len(), 4);
+        let cs = b.cs();
+        let table = c
+            .iter()
+            .map(|c| Self::constant(cs.clone(), *c).limbs)
+            .collect::<Vec<_>>();
+        let limbs = (0..Self::params().num_limbs)
+            .map(|i| {
+                let column = table
+                    .iter()
+                    .map(|limbs| limbs[i].value())
+                    .collect::<Result<Vec<_>, _>>()?;
+                FpVar::two_bit_lookup(b, &column)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new_reduced(cs, limbs))
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> AllocVar<TargetF, BaseF>
+    for AllocatedNonNativeFieldVar<TargetF, BaseF>
+{
+    fn new_variable<T: Borrow<TargetF>>(
+        cs: impl Into<Namespace<BaseF>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        if mode == AllocationMode::Constant {
+            return Ok(Self::constant(cs, *f()?.borrow()));
+        }
+        let value = f().ok().map(|v| field_to_biguint(v.borrow()));
+        let limbs = alloc_normal_limbs::<TargetF, BaseF>(&cs, value.as_ref(), mode)?;
+        Ok(Self::new_reduced(cs, limbs))
+    }
+}
@@ -0,0 +1,375 @@
+use algebra::{BitIteratorLE, PrimeField};
+use core::borrow::Borrow;
+use r1cs_core::{ConstraintSystemRef, Namespace, SynthesisError};
+
+use super::AllocatedNonNativeFieldVar;
+use crate::{
+    fields::{fp::FpVar, FieldOpsBounds, FieldVar},
+    prelude::*,
+    ToConstraintFieldGadget, Vec,
+};
+
+/// A gadget for representing non-native (`TargetF`) field elements over the
+/// constraint field (`BaseF`).
+#[derive(Clone, Debug)]
+#[must_use]
+pub enum NonNativeFieldVar<TargetF: PrimeField, BaseF: PrimeField> {
+    /// Constant
+    Constant(TargetF),
+    /// Allocated gadget
+    Var(AllocatedNonNativeFieldVar<TargetF, BaseF>),
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> R1CSVar<BaseF> for NonNativeFieldVar<TargetF, BaseF> {
+    type Value = TargetF;
+
+    fn cs(&self) -> ConstraintSystemRef<BaseF> {
+        match self {
+            Self::Constant(_) => ConstraintSystemRef::None,
+            Self::Var(a) => a.cs.clone(),
+        }
+    }
+
+    fn value(&self) -> Result<Self::Value, SynthesisError> {
+        match self {
+            Self::Constant(v) => Ok(*v),
+            Self::Var(v) => v.value(),
+        }
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> From<Boolean<BaseF>>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+    fn from(other: Boolean<BaseF>) -> Self {
+        if let Boolean::Constant(b) = other {
+            Self::Constant(TargetF::from(b as u128))
+        } else {
+            let cs = other.cs();
+            let one = AllocatedNonNativeFieldVar::constant(cs.clone(), TargetF::one());
+            let zero = AllocatedNonNativeFieldVar::constant(cs, TargetF::zero());
+            // Selecting between two constants is free.
+            Self::Var(other.select(&one, &zero).unwrap())
+        }
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> From<AllocatedNonNativeFieldVar<TargetF, BaseF>>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+    fn from(other: AllocatedNonNativeFieldVar<TargetF, BaseF>) -> Self {
+        Self::Var(other)
+    }
+}
+
+impl<'a, TargetF: PrimeField, BaseF: PrimeField> FieldOpsBounds<'a, TargetF, Self>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+}
+
+impl<'a, TargetF: PrimeField, BaseF: PrimeField>
+    FieldOpsBounds<'a, TargetF, NonNativeFieldVar<TargetF, BaseF>>
+    for &'a NonNativeFieldVar<TargetF, BaseF>
+{
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> FieldVar<TargetF, BaseF>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+    fn zero() -> Self {
+        Self::Constant(TargetF::zero())
+    }
+
+    fn one() -> Self {
+        Self::Constant(TargetF::one())
+    }
+
+    fn constant(v: TargetF) -> Self {
+        Self::Constant(v)
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn negate(&self) -> Result<Self, SynthesisError> {
+        match self {
+            Self::Constant(c) => Ok(Self::Constant(-*c)),
+            Self::Var(v) => Ok(Self::Var(v.negate()?)),
+        }
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn inverse(&self) -> Result<Self, SynthesisError> {
+        match self {
+            Self::Constant(c) => Ok(Self::Constant(c.inverse().unwrap_or_else(TargetF::zero))),
+            Self::Var(v) => Ok(Self::Var(v.inverse()?)),
+        }
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn mul_equals(&self, other: &Self, result: &Self) -> Result<(), SynthesisError> {
+        match (self, other, result) {
+            (Self::Constant(_), Self::Constant(_), _) | (_, _, Self::Constant(_)) => {
+                result.enforce_equal(&(self * other))
+            },
+            (..) => {
+                let cs = self.cs().or(other.cs()).or(result.cs());
+                let to_allocated = |v: &Self| match v {
+                    Self::Constant(c) => AllocatedNonNativeFieldVar::constant(cs.clone(), *c),
+                    Self::Var(v) => v.clone(),
+                };
+                to_allocated(self).mul_equals(&to_allocated(other), &to_allocated(result))
+            },
+        }
+    }
+
+    /// Since `TargetF` is a prime field, the Frobenius map is the identity.
+    fn frobenius_map(&self, _power: usize) -> Result<Self, SynthesisError> {
+        Ok(self.clone())
+    }
+}
+
+impl_bounded_ops!(
+    NonNativeFieldVar<TargetF, BaseF>,
+    TargetF,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    |this: &'a NonNativeFieldVar<TargetF, BaseF>, other: &'a NonNativeFieldVar<TargetF, BaseF>| {
+        use NonNativeFieldVar::*;
+        match (this, other) {
+            (Constant(c1), Constant(c2)) => Constant(*c1 + c2),
+            (Constant(c), Var(v)) | (Var(v), Constant(c)) => Var(v.add_constant(c).unwrap()),
+            (Var(v1), Var(v2)) => Var(v1.add(v2).unwrap()),
+        }
+    },
+    |this: &'a NonNativeFieldVar<TargetF, BaseF>, other: TargetF| { this + &NonNativeFieldVar::Constant(other) },
+    (TargetF: PrimeField, BaseF: PrimeField),
+);
+
+impl_bounded_ops!(
+    NonNativeFieldVar<TargetF, BaseF>,
+    TargetF,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    |this: &'a NonNativeFieldVar<TargetF, BaseF>, other: &'a NonNativeFieldVar<TargetF, BaseF>| {
+        use NonNativeFieldVar::*;
+        match (this, other) {
+            (Constant(c1), Constant(c2)) => Constant(*c1 - c2),
+            (Var(v), Constant(c)) => Var(v.sub_constant(c).unwrap()),
+            (Constant(c), Var(v)) => Var(v.negate().unwrap().add_constant(c).unwrap()),
+            (Var(v1), Var(v2)) => Var(v1.sub(v2).unwrap()),
+        }
+    },
+    |this: &'a NonNativeFieldVar<TargetF, BaseF>, other: TargetF| { this - &NonNativeFieldVar::Constant(other) },
+    (TargetF: PrimeField, BaseF: PrimeField),
+);
+
+impl_bounded_ops!(
+    NonNativeFieldVar<TargetF, BaseF>,
+    TargetF,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    |this: &'a NonNativeFieldVar<TargetF, BaseF>, other: &'a NonNativeFieldVar<TargetF, BaseF>| {
+        use NonNativeFieldVar::*;
+        match (this, other) {
+            (Constant(c1), Constant(c2)) => Constant(*c1 * c2),
+            (Constant(c), Var(v)) | (Var(v), Constant(c)) => {
+                if c.is_zero() {
+                    Constant(TargetF::zero())
+                } else if c.is_one() {
+                    Var(v.clone())
+                } else {
+                    Var(v.mul_constant(c).unwrap())
+                }
+            }
+            (Var(v1), Var(v2)) => Var(v1.mul(v2).unwrap()),
+        }
+    },
+    |this: &'a NonNativeFieldVar<TargetF, BaseF>, other: TargetF| { this * &NonNativeFieldVar::Constant(other) },
+    (TargetF: PrimeField, BaseF: PrimeField),
+);
+
+impl<TargetF: PrimeField, BaseF: PrimeField> EqGadget<BaseF> for NonNativeFieldVar<TargetF, BaseF> {
+    #[tracing::instrument(target = "r1cs")]
+    fn is_eq(&self, other: &Self) -> Result<Boolean<BaseF>, SynthesisError> {
+        match (self, other) {
+            (Self::Constant(c1), Self::Constant(c2)) => Ok(Boolean::Constant(c1 == c2)),
+            (Self::Constant(c), Self::Var(v)) | (Self::Var(v), Self::Constant(c)) => {
+                let c = AllocatedNonNativeFieldVar::constant(v.cs.clone(), *c);
+                c.is_eq(v)
+            },
+            (Self::Var(v1), Self::Var(v2)) => v1.is_eq(v2),
+        }
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn conditional_enforce_equal(
+        &self,
+        other: &Self,
+        should_enforce: &Boolean<BaseF>,
+    ) -> Result<(), SynthesisError> {
+        match (self, other) {
+            (Self::Constant(c1), Self::Constant(c2)) => {
+                if c1 != c2 {
+                    should_enforce.enforce_equal(&Boolean::FALSE)?;
+                }
+                Ok(())
+            },
+            (Self::Constant(c), Self::Var(v)) | (Self::Var(v), Self::Constant(c)) => {
+                let c = AllocatedNonNativeFieldVar::constant(v.cs.clone(), *c);
+                c.conditional_enforce_equal(v, should_enforce)
+            },
+            (Self::Var(v1), Self::Var(v2)) => v1.conditional_enforce_equal(v2, should_enforce),
+        }
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn conditional_enforce_not_equal(
+        &self,
+        other: &Self,
+        should_enforce: &Boolean<BaseF>,
+    ) -> Result<(), SynthesisError> {
+        match (self, other) {
+            (Self::Constant(c1), Self::Constant(c2)) => {
+                if c1 == c2 {
+                    should_enforce.enforce_equal(&Boolean::FALSE)?;
+                }
+                Ok(())
+            },
+            (Self::Constant(c), Self::Var(v)) | (Self::Var(v), Self::Constant(c)) => {
+                let c = AllocatedNonNativeFieldVar::constant(v.cs.clone(), *c);
+                c.conditional_enforce_not_equal(v, should_enforce)
+            },
+            (Self::Var(v1), Self::Var(v2)) => v1.conditional_enforce_not_equal(v2, should_enforce),
+        }
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> ToBitsGadget<BaseF>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+    #[tracing::instrument(target = "r1cs")]
+    fn to_bits_le(&self) -> Result<Vec<Boolean<BaseF>>, SynthesisError> {
+        match self {
+            Self::Constant(_) => self.to_non_unique_bits_le(),
+            Self::Var(v) => v.to_bits_le(),
+        }
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn to_non_unique_bits_le(&self) -> Result<Vec<Boolean<BaseF>>, SynthesisError> {
+        match self {
+            Self::Constant(c) => Ok(BitIteratorLE::new(&c.into_repr())
+                .take(TargetF::size_in_bits())
+                .map(Boolean::constant)
+                .collect()),
+            Self::Var(v) => v.to_non_unique_bits_le(),
+        }
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> ToBytesGadget<BaseF>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+    /// Outputs the unique byte decomposition of `self` in *little-endian*
+    /// form.
+    #[tracing::instrument(target = "r1cs")]
+    fn to_bytes(&self) -> Result<Vec<UInt8<BaseF>>, SynthesisError> {
+        match self {
+            Self::Constant(c) => Ok(UInt8::constant_vec(&to_bytes![c].unwrap())),
+            Self::Var(v) => v.to_bytes(),
+        }
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn to_non_unique_bytes(&self) -> Result<Vec<UInt8<BaseF>>, SynthesisError> {
+        match self {
+            Self::Constant(c) => Ok(UInt8::constant_vec(&to_bytes![c].unwrap())),
+            Self::Var(v) => v.to_non_unique_bytes(),
+        }
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> ToConstraintFieldGadget<BaseF>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+    #[tracing::instrument(target = "r1cs")]
+    fn to_constraint_field(&self) -> Result<Vec<FpVar<BaseF>>, SynthesisError> {
+        match self {
+            Self::Constant(c) => {
+                Ok(AllocatedNonNativeFieldVar::constant(ConstraintSystemRef::None, *c).limbs)
+            },
+            Self::Var(v) => v.to_constraint_field(),
+        }
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> CondSelectGadget<BaseF>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+    #[tracing::instrument(target = "r1cs")]
+    fn conditionally_select(
+        cond: &Boolean<BaseF>,
+        true_value: &Self,
+        false_value: &Self,
+    ) -> Result<Self, SynthesisError> {
+        match cond {
+            Boolean::Constant(true) => Ok(true_value.clone()),
+            Boolean::Constant(false) => Ok(false_value.clone()),
+            _ => {
+                let cs = cond.cs();
+                let to_allocated = |v: &Self| match v {
+                    Self::Constant(c) => AllocatedNonNativeFieldVar::constant(cs.clone(), *c),
+                    Self::Var(v) => v.clone(),
+                };
+                cond.select(&to_allocated(true_value), &to_allocated(false_value))
+                    .map(Self::Var)
+            },
+        }
+    }
+}
+
+/// Uses two bits to perform a lookup into a table
+/// `b` is little-endian: `b[0]` is LSB.
+impl<TargetF: PrimeField, BaseF: PrimeField> TwoBitLookupGadget<BaseF>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+    type TableConstant = TargetF;
+
+    #[tracing::instrument(target = "r1cs")]
+    fn two_bit_lookup(
+        b: &[Boolean<BaseF>],
+        c: &[Self::TableConstant],
+    ) -> Result<Self, SynthesisError> {
+        debug_assert_eq!(b.len(), 2);
+        debug_assert_eq!(c.len(), 4);
+        if b.is_constant() {
+            let lsb = usize::from(b[0].value()?);
+            let msb = usize::from(b[1].value()?);
+            let index = lsb + (msb << 1);
+            Ok(Self::Constant(c[index]))
+        } else {
+            AllocatedNonNativeFieldVar::two_bit_lookup(b, c).map(Self::Var)
+        }
+    }
+}
+
+impl<TargetF: PrimeField, BaseF: PrimeField> AllocVar<TargetF, BaseF>
+    for NonNativeFieldVar<TargetF, BaseF>
+{
+    fn new_variable<T: Borrow<TargetF>>(
+        cs: impl Into<Namespace<BaseF>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        if mode == AllocationMode::Constant {
+            Ok(Self::Constant(*f()?.borrow()))
+        } else {
+            AllocatedNonNativeFieldVar::new_variable(cs, f, mode).map(Self::Var)
+        }
+    }
+}
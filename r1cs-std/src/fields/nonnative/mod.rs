@@ -0,0 +1,142 @@
+//! Elements of a prime field `TargetF` are represented by
+//! `AllocatedNonNativeFieldVar::params().num_limbs` limbs, each of which is an element of the
+//! constraint field `BaseF`. Every limb holds `bits_per_limb` bits of the
+//! integer representation of the element, in little-endian order.
+//!
+//! Additions and subtractions are performed limb-wise, and let the limbs
+//! grow beyond `bits_per_limb` bits. Multiplications, equality checks and
+//! bit decompositions first reduce their operands modulo the characteristic
+//! of `TargetF`. Reducing allocates the quotient and the remainder as
+//! witnesses, and checks the division by comparing limbs while propagating
+//! carries from each limb to the next.
+
+mod allocated_field_var;
+pub use allocated_field_var::AllocatedNonNativeFieldVar;
+
+mod field_var;
+pub use field_var::NonNativeFieldVar;
+
+mod params;
+pub use params::{get_params, NonNativeFieldParams};
+
+mod reduce;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        fields::tests::{field_test, frobenius_tests},
+        groups::curves::short_weierstrass::ProjectiveVar,
+        prelude::*,
+    };
+    use algebra::{
+        bls12_381::{g1::Parameters as G1Parameters, Fq, Fr, G1Projective},
+        test_rng, Field, One, ProjectiveCurve, UniformRand,
+    };
+    use r1cs_core::ConstraintSystem;
+
+    #[test]
+    fn params_test() {
+        assert_eq!(
+            get_params(381, 255),
+            NonNativeFieldParams {
+                num_limbs: 4,
+                bits_per_limb: 96
+            }
+        );
+        assert_eq!(
+            get_params(255, 381),
+            NonNativeFieldParams {
+                num_limbs: 2,
+                bits_per_limb: 128
+            }
+        );
+    }
+
+    #[test]
+    fn constant_bit_length_test() {
+        use crate::fields::fp::FpVar;
+        use r1cs_core::SynthesisError;
+
+        let x = FpVar::<Fr>::constant(Fr::from(256u64));
+        assert_eq!(reduce::enforce_bit_length(&x, 9).unwrap().len(), 9);
+        assert_eq!(
+            reduce::enforce_bit_length(&x, 8).unwrap_err(),
+            SynthesisError::Unsatisfiable
+        );
+    }
+
+    #[test]
+    fn bls12_381_fq_over_fr_test() {
+        field_test::<Fq, Fr, NonNativeFieldVar<Fq, Fr>>().unwrap();
+        frobenius_tests::<Fq, Fr, NonNativeFieldVar<Fq, Fr>>(13).unwrap();
+    }
+
+    #[test]
+    fn bls12_381_fr_over_fq_test() {
+        field_test::<Fr, Fq, NonNativeFieldVar<Fr, Fq>>().unwrap();
+    }
+
+    #[test]
+    fn lazy_reduction_test() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let mut rng = test_rng();
+        let a_native = Fq::rand(&mut rng);
+        let a = NonNativeFieldVar::<Fq, Fr>::new_witness(cs.clone(), || Ok(a_native)).unwrap();
+
+        let mut sum = a.clone();
+        let mut sum_native = a_native;
+        for _ in 0..300 {
+            sum = &sum + &a - &a.double().unwrap();
+            sum_native += a_native - a_native.double();
+        }
+        assert_eq!(sum.value().unwrap(), sum_native);
+        sum.enforce_equal(&NonNativeFieldVar::constant(sum_native))
+            .unwrap();
+        sum.enforce_not_equal(&NonNativeFieldVar::constant(sum_native + Fq::one()))
+            .unwrap();
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn reject_wrong_inverse_test() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let mut rng = test_rng();
+        let a_native = Fq::rand(&mut rng);
+        let a = NonNativeFieldVar::<Fq, Fr>::new_witness(cs.clone(), || Ok(a_native)).unwrap();
+        let b =
+            NonNativeFieldVar::<Fq, Fr>::new_witness(cs.clone(), || Ok(a_native.double())).unwrap();
+        a.mul_equals(&b, &NonNativeFieldVar::one()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn projective_var_over_nonnative_test() {
+        type G1Var = ProjectiveVar<G1Parameters, NonNativeFieldVar<Fq, Fr>, Fr>;
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let mut rng = test_rng();
+        let a_native = G1Projective::rand(&mut rng);
+        let b_native = G1Projective::rand(&mut rng);
+        let a = G1Var::new_variable_omit_prime_order_check(
+            cs.clone(),
+            || Ok(a_native),
+            AllocationMode::Witness,
+        )
+        .unwrap();
+        let b = G1Var::new_variable_omit_prime_order_check(
+            cs.clone(),
+            || Ok(b_native),
+            AllocationMode::Witness,
+        )
+        .unwrap();
+
+        let sum = &a + &b;
+        let double = a.double().unwrap();
+        assert_eq!(sum.value().unwrap(), a_native + b_native);
+        assert_eq!(double.value().unwrap(), a_native.double());
+        sum.enforce_equal(&G1Var::constant(a_native + b_native))
+            .unwrap();
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
@@ -0,0 +1,46 @@
+/// The parameters used to represent elements of a target field as limbs of a
+/// base field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NonNativeFieldParams {
+    /// The number of limbs.
+    pub num_limbs: usize,
+    /// The number of bits in every limb, except possibly the most
+    /// significant one, which may be shorter.
+    pub bits_per_limb: usize,
+}
+
+impl NonNativeFieldParams {
+    /// Returns the number of bits in the most significant limb of an element
+    /// of a field with `target_field_size` bits.
+    pub fn top_limb_bits(&self, target_field_size: usize) -> usize {
+        target_field_size - self.bits_per_limb * (self.num_limbs - 1)
+    }
+
+    /// Returns the maximum number of bits that a limb may have before it must
+    /// be reduced, for a base field with `base_field_size` bits.
+    pub fn max_limb_bits(base_field_size: usize) -> usize {
+        base_field_size - 4
+    }
+}
+
+/// Returns the parameters for representing elements of a field with
+/// `target_field_size` bits as limbs of a field with `base_field_size` bits.
+///
+/// We pick the smallest number of limbs `L` such that the product of two
+/// reduced elements, which has `2L - 1` limbs of at most
+/// `2 * bits_per_limb + log2(L)` bits each, can be reduced without any of
+/// the intermediate values wrapping around the base field modulus.
+pub fn get_params(target_field_size: usize, base_field_size: usize) -> NonNativeFieldParams {
+    let mut num_limbs = 1;
+    loop {
+        let bits_per_limb = (target_field_size + num_limbs - 1) / num_limbs;
+        let product_bits = 2 * bits_per_limb + algebra::log2(num_limbs + 1) as usize;
+        if product_bits + 4 <= base_field_size {
+            return NonNativeFieldParams {
+                num_limbs,
+                bits_per_limb,
+            };
+        }
+        num_limbs += 1;
+    }
+}
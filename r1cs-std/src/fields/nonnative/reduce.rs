@@ -0,0 +1,295 @@
+use algebra::{BigInteger, PrimeField};
+use core::cmp::{max, min};
+use num_bigint::{BigInt, BigUint, Sign};
+use r1cs_core::{ConstraintSystemRef, SynthesisError};
+
+use super::params::{get_params, NonNativeFieldParams};
+use crate::{fields::fp::FpVar, prelude::*, Assignment, Vec};
+
+/// Returns the parameters for representing `TargetF` elements over `BaseF`.
+pub(crate) fn params<TargetF: PrimeField, BaseF: PrimeField>() -> NonNativeFieldParams {
+    get_params(TargetF::size_in_bits(), BaseF::size_in_bits())
+}
+
+/// Returns the bit widths of the limbs of a reduced `TargetF` element.
+pub(crate) fn normal_limb_widths<TargetF: PrimeField, BaseF: PrimeField>() -> Vec<usize> {
+    let params = params::<TargetF, BaseF>();
+    let mut widths = vec![params.bits_per_limb; params.num_limbs];
+    widths[params.num_limbs - 1] = params.top_limb_bits(TargetF::size_in_bits());
+    widths
+}
+
+/// Converts a field element into the integer that it represents.
+pub(crate) fn field_to_biguint<F: PrimeField>(f: &F) -> BigUint {
+    u64s_to_biguint(f.into_repr().as_ref())
+}
+
+/// Returns the modulus of `F` as an integer.
+pub(crate) fn modulus<F: PrimeField>() -> BigUint {
+    u64s_to_biguint(F::modulus().as_ref())
+}
+
+fn u64s_to_biguint(limbs: &[u64]) -> BigUint {
+    limbs
+        .iter()
+        .rev()
+        .fold(BigUint::from(0u64), |acc, limb| (acc << 64usize) + *limb)
+}
+
+/// Converts an integer into a field element, reducing it modulo the
+/// characteristic of `F`.
+pub(crate) fn biguint_to_field<F: PrimeField>(n: &BigUint) -> F {
+    let two_to_64 = F::from(1u128 << 64);
+    n.to_u64_digits()
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, digit| acc * two_to_64 + F::from(*digit))
+}
+
+fn bigint_to_field<F: PrimeField>(n: &BigInt) -> F {
+    let magnitude = biguint_to_field::<F>(n.magnitude());
+    if n.sign() == Sign::Minus {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Splits `n` into `num_limbs` little-endian limbs of `bits_per_limb` bits
+/// each. The last limb contains all the remaining high bits of `n`.
+pub(crate) fn biguint_to_limbs(
+    n: &BigUint,
+    bits_per_limb: usize,
+    num_limbs: usize,
+) -> Vec<BigUint> {
+    let mask = (BigUint::from(1u64) << bits_per_limb) - 1u64;
+    (0..num_limbs)
+        .map(|i| {
+            let limb = n >> (i * bits_per_limb);
+            if i == num_limbs - 1 {
+                limb
+            } else {
+                limb & &mask
+            }
+        })
+        .collect()
+}
+
+/// Returns the integer `Σ limbs[i] * 2^{bits_per_limb * i}`.
+pub(crate) fn limbs_to_biguint<F: PrimeField>(
+    limbs: &[FpVar<F>],
+    bits_per_limb: usize,
+) -> Result<BigUint, SynthesisError> {
+    let mut result = BigUint::from(0u64);
+    for limb in limbs.iter().rev() {
+        result = (result << bits_per_limb) + field_to_biguint(&limb.value()?);
+    }
+    Ok(result)
+}
+
+/// Enforces that `x < 2^num_bits`, and returns the little-endian bits of
+/// `x`. If `x` is a constant that does not fit, this returns
+/// `SynthesisError::Unsatisfiable`.
+pub(crate) fn enforce_bit_length<F: PrimeField>(
+    x: &FpVar<F>,
+    num_bits: usize,
+) -> Result<Vec<Boolean<F>>, SynthesisError> {
+    if let FpVar::Constant(c) = x {
+        let c = c.into_repr();
+        if c.num_bits() as usize > num_bits {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        return Ok((0..num_bits)
+            .map(|i| Boolean::constant(c.get_bit(i)))
+            .collect());
+    }
+    let cs = x.cs();
+    let value = x.value().ok().map(|v| v.into_repr());
+    let bits = (0..num_bits)
+        .map(|i| {
+            Boolean::new_witness(r1cs_core::ns!(cs, "bit"), || {
+                value.get().map(|v| v.get_bit(i))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut sum = FpVar::zero();
+    let mut coeff = F::one();
+    for bit in &bits {
+        sum += FpVar::from(bit.clone()) * coeff;
+        coeff.double_in_place();
+    }
+    sum.enforce_equal(x)?;
+    Ok(bits)
+}
+
+/// Allocates the limbs of a reduced `TargetF` element with value `value`,
+/// and enforces that every limb is within its bit width.
+pub(crate) fn alloc_normal_limbs<TargetF: PrimeField, BaseF: PrimeField>(
+    cs: &ConstraintSystemRef<BaseF>,
+    value: Option<&BigUint>,
+    mode: AllocationMode,
+) -> Result<Vec<FpVar<BaseF>>, SynthesisError> {
+    let params = params::<TargetF, BaseF>();
+    let limb_values = value.map(|v| biguint_to_limbs(v, params.bits_per_limb, params.num_limbs));
+    normal_limb_widths::<TargetF, BaseF>()
+        .into_iter()
+        .enumerate()
+        .map(|(i, width)| {
+            let limb = FpVar::new_variable(
+                r1cs_core::ns!(cs, "limb"),
+                || {
+                    limb_values
+                        .as_ref()
+                        .map(|l| biguint_to_field::<BaseF>(&l[i]))
+                        .get()
+                },
+                mode,
+            )?;
+            enforce_bit_length(&limb, width)?;
+            Ok(limb)
+        })
+        .collect()
+}
+
+/// Computes `X mod p`, where `X` is the integer represented by the limbs `x`
+/// (each of which is smaller than `2^x_bits`) and `p` is the characteristic
+/// of `TargetF`. Returns the limbs of the result in normal form.
+pub(crate) fn reduce<TargetF: PrimeField, BaseF: PrimeField>(
+    cs: &ConstraintSystemRef<BaseF>,
+    x: &[FpVar<BaseF>],
+    x_bits: usize,
+) -> Result<Vec<FpVar<BaseF>>, SynthesisError> {
+    let params = params::<TargetF, BaseF>();
+    let remainder = limbs_to_biguint(x, params.bits_per_limb)
+        .ok()
+        .map(|x| x % modulus::<TargetF>());
+    let r = alloc_normal_limbs::<TargetF, BaseF>(cs, remainder.as_ref(), AllocationMode::Witness)?;
+    enforce_quotient::<TargetF, BaseF>(cs, x, x_bits, &r)?;
+    Ok(r)
+}
+
+/// Enforces that `Σ x[i] 2^{b i} = q * p + Σ r[i] 2^{b i}` over the
+/// integers, for some quotient `q` that is allocated as a witness. Here `b`
+/// is the number of bits per limb and `p` is the characteristic of
+/// `TargetF`.
+///
+/// The limbs of `x` must be smaller than `2^x_bits`, and those of `r` must
+/// be in normal form.
+pub(crate) fn enforce_quotient<TargetF: PrimeField, BaseF: PrimeField>(
+    cs: &ConstraintSystemRef<BaseF>,
+    x: &[FpVar<BaseF>],
+    x_bits: usize,
+    r: &[FpVar<BaseF>],
+) -> Result<(), SynthesisError> {
+    let params = params::<TargetF, BaseF>();
+    let b = params.bits_per_limb;
+    let p = modulus::<TargetF>();
+
+    // Since `p >= 2^{T - 1}`, the quotient is smaller than
+    // `2^{x_bits + b (n - 1) + 1 - (T - 1)}`.
+    let q_bits = (x_bits + b * (x.len() - 1) + 2)
+        .saturating_sub(TargetF::size_in_bits())
+        .max(1);
+    let q_num_limbs = (q_bits + b - 1) / b;
+    let q_value = limbs_to_biguint(x, b)
+        .and_then(|x| Ok((x, limbs_to_biguint(r, b)?)))
+        .ok()
+        .map(|(x, r)| {
+            if x >= r {
+                (x - r) / &p
+            } else {
+                BigUint::from(0u64)
+            }
+        });
+    let q_limb_values = q_value.map(|q| biguint_to_limbs(&q, b, q_num_limbs));
+    let q = (0..q_num_limbs)
+        .map(|i| {
+            let limb = FpVar::new_witness(r1cs_core::ns!(cs, "quotient limb"), || {
+                q_limb_values
+                    .as_ref()
+                    .map(|l| biguint_to_field::<BaseF>(&l[i]))
+                    .get()
+            })?;
+            enforce_bit_length(&limb, min(b, q_bits - b * i))?;
+            Ok(limb)
+        })
+        .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+    let p_limbs = biguint_to_limbs(&p, b, params.num_limbs)
+        .iter()
+        .map(biguint_to_field::<BaseF>)
+        .collect::<Vec<_>>();
+    let num_limbs = max(x.len(), q_num_limbs + params.num_limbs - 1).max(r.len());
+    let mut y = vec![FpVar::zero(); num_limbs];
+    for (i, q_i) in q.iter().enumerate() {
+        for (j, p_j) in p_limbs.iter().enumerate() {
+            y[i + j] += q_i * *p_j;
+        }
+    }
+    for (y_k, r_k) in y.iter_mut().zip(r) {
+        *y_k += r_k;
+    }
+    let mut x = x.to_vec();
+    x.resize(num_limbs, FpVar::zero());
+
+    // Every limb of `y` is the sum of at most `min(q_num_limbs, L)` products
+    // of two `b`-bit numbers and one limb of `r`.
+    let y_bits = 2 * b + algebra::log2(min(q_num_limbs, params.num_limbs) + 1) as usize;
+    enforce_limbs_equal(cs, &x, &y, max(x_bits, y_bits), b)
+}
+
+/// Enforces that `Σ left[i] 2^{b i} = Σ right[i] 2^{b i}` over the integers,
+/// where all limbs are smaller than `2^max_bits`.
+///
+/// This is done by propagating a signed carry from each limb to the next, so
+/// that every intermediate value stays smaller than `2^{max_bits + 3}`.
+fn enforce_limbs_equal<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    left: &[FpVar<F>],
+    right: &[FpVar<F>],
+    max_bits: usize,
+    bits_per_limb: usize,
+) -> Result<(), SynthesisError> {
+    assert_eq!(left.len(), right.len());
+    assert!(
+        max_bits + 3 < F::size_in_bits(),
+        "limbs are too large to be compared"
+    );
+    // Every carry `c` satisfies `|c| < 2^{max_bits - bits_per_limb + 1}`, so
+    // we shift it by this amount to range check it.
+    let carry_bits = max_bits - bits_per_limb + 2;
+    let offset_bits = carry_bits - 1;
+    let offset = F::from(2u64).pow([offset_bits as u64]);
+    let shift = F::from(2u64).pow([bits_per_limb as u64]);
+
+    let carry_values = (|| -> Result<Vec<BigInt>, SynthesisError> {
+        let mut carry = BigInt::from(0u64);
+        let mut carries = Vec::with_capacity(left.len());
+        for (l, r) in left.iter().zip(right) {
+            let l = BigInt::from(field_to_biguint(&l.value()?));
+            let r = BigInt::from(field_to_biguint(&r.value()?));
+            carry = (l - r + carry) >> bits_per_limb;
+            carries.push(carry.clone());
+        }
+        Ok(carries)
+    })()
+    .ok();
+
+    let mut carry = FpVar::zero();
+    for (k, (l, r)) in left.iter().zip(right).enumerate() {
+        let diff = l - r + &carry;
+        if k == left.len() - 1 {
+            diff.enforce_equal(&FpVar::zero())?;
+        } else {
+            carry = FpVar::new_witness(r1cs_core::ns!(cs, "carry"), || {
+                carry_values
+                    .as_ref()
+                    .map(|c| bigint_to_field::<F>(&c[k]))
+                    .get()
+            })?;
+            enforce_bit_length(&(&carry + offset), carry_bits)?;
+            diff.enforce_equal(&(&carry * shift))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,289 @@
+use algebra::{
+    curves::{
+        bn::{BnParameters, G1Prepared, G2Prepared, TwistType},
+        short_weierstrass_jacobian::GroupAffine,
+    },
+    fields::Field,
+    One,
+};
+use r1cs_core::{Namespace, SynthesisError};
+
+use crate::{
+    fields::{fp::FpVar, fp2::Fp2Var, FieldVar},
+    groups::curves::short_weierstrass::*,
+    Vec,
+};
+
+use core::fmt::Debug;
+
+/// Represents a projective point in G1.
+pub type G1Var<P> =
+    ProjectiveVar<<P as BnParameters>::G1Parameters, FpVar<<P as BnParameters>::Fp>>;
+
+/// Represents an affine point on G1. Should be used only for comparison and
+/// when a canonical representation of a point is required, and not for
+/// arithmetic.
+pub type G1AffineVar<P> =
+    AffineVar<<P as BnParameters>::G1Parameters, FpVar<<P as BnParameters>::Fp>>;
+
+/// Represents a projective point in G2.
+pub type G2Var<P> = ProjectiveVar<<P as BnParameters>::G2Parameters, Fp2G<P>>;
+/// Represents an affine point on G2. Should be used only for comparison and
+/// when a canonical representation of a point is required, and not for
+/// arithmetic.
+pub type G2AffineVar<P> = AffineVar<<P as BnParameters>::G2Parameters, Fp2G<P>>;
+
+/// Represents the cached precomputation that can be performed on a G1 element
+/// which enables speeding up pairing computation.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "G1Var<P>: Clone"), Debug(bound = "G1Var<P>: Debug"))]
+pub struct G1PreparedVar<P: BnParameters>(pub AffineVar<P::G1Parameters, FpVar<P::Fp>>);
+
+impl<P: BnParameters> G1PreparedVar<P> {
+    /// Returns the value assigned to `self` in the underlying constraint
+    /// system.
+    pub fn value(&self) -> Result<G1Prepared<P>, SynthesisError> {
+        let x = self.0.x.value()?;
+        let y = self.0.y.value()?;
+        let infinity = self.0.infinity.value()?;
+        let g = GroupAffine::new(x, y, infinity);
+        Ok(g.into())
+    }
+
+    /// Constructs `Self` from a `G1Var`.
+    pub fn from_group_var(q: &G1Var<P>) -> Result<Self, SynthesisError> {
+        let g = q.to_affine()?;
+        Ok(Self(g))
+    }
+}
+
+impl<P: BnParameters> AllocVar<G1Prepared<P>, P::Fp> for G1PreparedVar<P> {
+    fn new_variable<T: Borrow<G1Prepared<P>>>(
+        cs: impl Into<Namespace<P::Fp>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let g1_prep = f().map(|b| b.borrow().0);
+
+        let x = FpVar::new_variable(r1cs_core::ns!(cs, "x"), || g1_prep.map(|g| g.x), mode)?;
+        let y = FpVar::new_variable(r1cs_core::ns!(cs, "y"), || g1_prep.map(|g| g.y), mode)?;
+        let infinity = Boolean::new_variable(
+            r1cs_core::ns!(cs, "inf"),
+            || g1_prep.map(|g| g.infinity),
+            mode,
+        )?;
+        let g = AffineVar::new(x, y, infinity);
+        Ok(Self(g))
+    }
+}
+
+impl<P: BnParameters> ToBytesGadget<P::Fp> for G1PreparedVar<P> {
+    #[inline]
+    #[tracing::instrument(target = "r1cs")]
+    fn to_bytes(&self) -> Result<Vec<UInt8<P::Fp>>, SynthesisError> {
+        let mut bytes = self.0.x.to_bytes()?;
+        let y_bytes = self.0.y.to_bytes()?;
+        let inf_bytes = self.0.infinity.to_bytes()?;
+        bytes.extend_from_slice(&y_bytes);
+        bytes.extend_from_slice(&inf_bytes);
+        Ok(bytes)
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn to_non_unique_bytes(&self) -> Result<Vec<UInt8<P::Fp>>, SynthesisError> {
+        let mut bytes = self.0.x.to_bytes()?;
+        let y_bytes = self.0.y.to_bytes()?;
+        let inf_bytes = self.0.infinity.to_bytes()?;
+        bytes.extend_from_slice(&y_bytes);
+        bytes.extend_from_slice(&inf_bytes);
+        Ok(bytes)
+    }
+}
+
+type Fp2G<P> = Fp2Var<<P as BnParameters>::Fp2Params>;
+type LCoeff<P> = (Fp2G<P>, Fp2G<P>);
+/// Represents the cached precomputation that can be performed on a G2 element
+/// which enables speeding up pairing computation.
+///
+/// Each line is stored with the coefficient of `p.y` normalized to one. For a
+/// `TwistType::M` curve the pair holds the constant term followed by the
+/// coefficient of `p.x`, while for a `TwistType::D` curve the order is
+/// reversed.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "Fp2Var<P::Fp2Params>: Clone"),
+    Debug(bound = "Fp2Var<P::Fp2Params>: Debug")
+)]
+pub struct G2PreparedVar<P: BnParameters> {
+    #[doc(hidden)]
+    pub ell_coeffs: Vec<LCoeff<P>>,
+}
+
+impl<P: BnParameters> AllocVar<G2Prepared<P>, P::Fp> for G2PreparedVar<P> {
+    #[tracing::instrument(target = "r1cs", skip(cs, f, mode))]
+    fn new_variable<T: Borrow<G2Prepared<P>>>(
+        cs: impl Into<Namespace<P::Fp>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let g2_prep = f().map(|b| {
+            // The native coefficients are in homogeneous projective form;
+            // divide through by the coefficient of `p.y`.
+            let projective_coeffs = &b.borrow().ell_coeffs;
+            let mut y_coeffs = projective_coeffs
+                .iter()
+                .map(|(c0, _, c2)| match P::TWIST_TYPE {
+                    TwistType::M => *c2,
+                    TwistType::D => *c0,
+                })
+                .collect::<Vec<_>>();
+            algebra::fields::batch_inversion(&mut y_coeffs);
+            projective_coeffs
+                .iter()
+                .zip(y_coeffs)
+                .map(|((c0, c1, c2), y_inv)| match P::TWIST_TYPE {
+                    TwistType::M => (*c0 * y_inv, *c1 * y_inv),
+                    TwistType::D => (*c1 * y_inv, *c2 * y_inv),
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let l = Vec::new_variable(
+            r1cs_core::ns!(cs, "l"),
+            || {
+                g2_prep
+                    .clone()
+                    .map(|c| c.iter().map(|(l, _)| *l).collect::<Vec<_>>())
+            },
+            mode,
+        )?;
+        let r = Vec::new_variable(
+            r1cs_core::ns!(cs, "r"),
+            || g2_prep.map(|c| c.iter().map(|(_, r)| *r).collect::<Vec<_>>()),
+            mode,
+        )?;
+        let ell_coeffs = l.into_iter().zip(r).collect();
+        Ok(Self { ell_coeffs })
+    }
+}
+
+impl<P: BnParameters> ToBytesGadget<P::Fp> for G2PreparedVar<P> {
+    #[inline]
+    #[tracing::instrument(target = "r1cs")]
+    fn to_bytes(&self) -> Result<Vec<UInt8<P::Fp>>, SynthesisError> {
+        let mut bytes = Vec::new();
+        for coeffs in &self.ell_coeffs {
+            bytes.extend_from_slice(&coeffs.0.to_bytes()?);
+            bytes.extend_from_slice(&coeffs.1.to_bytes()?);
+        }
+        Ok(bytes)
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn to_non_unique_bytes(&self) -> Result<Vec<UInt8<P::Fp>>, SynthesisError> {
+        let mut bytes = Vec::new();
+        for coeffs in &self.ell_coeffs {
+            bytes.extend_from_slice(&coeffs.0.to_non_unique_bytes()?);
+            bytes.extend_from_slice(&coeffs.1.to_non_unique_bytes()?);
+        }
+        Ok(bytes)
+    }
+}
+
+impl<P: BnParameters> G2PreparedVar<P> {
+    /// Constructs `Self` from a `G2Var`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn from_group_var(q: &G2Var<P>) -> Result<Self, SynthesisError> {
+        let q = q.to_affine()?;
+        let two_inv = P::Fp::one().double().inverse().unwrap();
+        // Enforce that `q` is not the point at infinity.
+        q.infinity.enforce_not_equal(&Boolean::Constant(true))?;
+        let mut ell_coeffs = vec![];
+        let mut r = q.clone();
+        let mut neg_q = q.clone();
+        neg_q.y.negate_in_place()?;
+
+        for i in (1..P::ATE_LOOP_COUNT.len()).rev() {
+            ell_coeffs.push(Self::double(&mut r, &two_inv)?);
+
+            match P::ATE_LOOP_COUNT[i - 1] {
+                1 => ell_coeffs.push(Self::add(&mut r, &q)?),
+                -1 => ell_coeffs.push(Self::add(&mut r, &neg_q)?),
+                _ => continue,
+            }
+        }
+
+        // The two extra line evaluations use the images of `q` under the
+        // Frobenius endomorphism.
+        let q1 = Self::mul_by_char(&q)?;
+        let mut q2 = Self::mul_by_char(&q1)?;
+
+        if P::ATE_LOOP_COUNT_IS_NEGATIVE {
+            r.y.negate_in_place()?;
+        }
+
+        q2.y.negate_in_place()?;
+
+        ell_coeffs.push(Self::add(&mut r, &q1)?);
+        ell_coeffs.push(Self::add(&mut r, &q2)?);
+
+        Ok(Self { ell_coeffs })
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn mul_by_char(q: &G2AffineVar<P>) -> Result<G2AffineVar<P>, SynthesisError> {
+        let mut s = q.clone();
+        s.x = s.x.frobenius_map(1)? * P::TWIST_MUL_BY_Q_X;
+        s.y = s.y.frobenius_map(1)? * P::TWIST_MUL_BY_Q_Y;
+        Ok(s)
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn double(r: &mut G2AffineVar<P>, two_inv: &P::Fp) -> Result<LCoeff<P>, SynthesisError> {
+        let a = r.y.inverse()?;
+        let mut b = r.x.square()?;
+        let b_tmp = b.clone();
+        b.mul_assign_by_base_field_constant(*two_inv);
+        b += &b_tmp;
+
+        let c = &a * &b;
+        let d = r.x.double()?;
+        let x3 = c.square()? - &d;
+        let e = &c * &r.x - &r.y;
+        let c_x3 = &c * &x3;
+        let y3 = &e - &c_x3;
+        let mut f = c;
+        f.negate_in_place()?;
+        r.x = x3;
+        r.y = y3;
+        match P::TWIST_TYPE {
+            TwistType::M => Ok((e, f)),
+            TwistType::D => Ok((f, e)),
+        }
+    }
+
+    #[tracing::instrument(target = "r1cs")]
+    fn add(r: &mut G2AffineVar<P>, q: &G2AffineVar<P>) -> Result<LCoeff<P>, SynthesisError> {
+        let a = (&q.x - &r.x).inverse()?;
+        let b = &q.y - &r.y;
+        let c = &a * &b;
+        let d = &r.x + &q.x;
+        let x3 = c.square()? - &d;
+
+        let e = (&r.x - &x3) * &c;
+        let y3 = e - &r.y;
+        let g = &c * &r.x - &r.y;
+        let mut f = c;
+        f.negate_in_place()?;
+        r.x = x3;
+        r.y = y3;
+        match P::TWIST_TYPE {
+            TwistType::M => Ok((g, f)),
+            TwistType::D => Ok((f, g)),
+        }
+    }
+}
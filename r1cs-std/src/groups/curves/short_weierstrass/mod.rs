@@ -1,7 +1,7 @@
 use algebra::{
     curves::{
         short_weierstrass_jacobian::{GroupAffine as SWAffine, GroupProjective as SWProjective},
-        SWModelParameters,
+        ModelParameters, SWModelParameters,
     },
     AffineCurve, BigInteger, BitIteratorBE, Field, One, PrimeField, ProjectiveCurve, Zero,
 };
@@ -14,6 +14,10 @@ use crate::{fields::fp::FpVar, prelude::*, ToConstraintFieldGadget, Vec};
 /// the [[BLS12]](https://eprint.iacr.org/2002/088.pdf) family of bilinear groups.
 pub mod bls12;
 
+/// This module provides a generic implementation of G1 and G2 for
+/// the [[BN]](https://eprint.iacr.org/2005/133.pdf) family of bilinear groups.
+pub mod bn;
+
 /// This module provides a generic implementation of G1 and G2 for
 /// the [[MNT4]](https://citeseerx.ist.psu.edu/viewdoc/download?doi=10.1.1.20.8113&rep=rep1&type=pdf)
 ///  family of bilinear groups.
@@ -31,7 +35,8 @@ pub mod mnt6;
 #[must_use]
 pub struct ProjectiveVar<
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    F: FieldVar<P::BaseField, CF>,
+    CF: PrimeField = <<P as ModelParameters>::BaseField as Field>::BasePrimeField,
 > where
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
@@ -42,7 +47,7 @@ pub struct ProjectiveVar<
     /// The z-coordinate.
     pub z: F,
     #[derivative(Debug = "ignore")]
-    _params: PhantomData<P>,
+    _params: PhantomData<(P, CF)>,
 }
 
 /// An affine representation of a curve point.
@@ -51,7 +56,8 @@ pub struct ProjectiveVar<
 #[must_use]
 pub struct AffineVar<
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    F: FieldVar<P::BaseField, CF>,
+    CF: PrimeField = <<P as ModelParameters>::BaseField as Field>::BasePrimeField,
 > where
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
@@ -60,18 +66,19 @@ pub struct AffineVar<
     /// The y-coordinate.
     pub y: F,
     /// Is `self` the point at infinity.
-    pub infinity: Boolean<<P::BaseField as Field>::BasePrimeField>,
+    pub infinity: Boolean<CF>,
     #[derivative(Debug = "ignore")]
-    _params: PhantomData<P>,
+    _params: PhantomData<(P, CF)>,
 }
 
-impl<P, F> AffineVar<P, F>
+impl<P, F, CF> AffineVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
-    fn new(x: F, y: F, infinity: Boolean<<P::BaseField as Field>::BasePrimeField>) -> Self {
+    fn new(x: F, y: F, infinity: Boolean<CF>) -> Self {
         Self {
             x,
             y,
@@ -91,17 +98,16 @@ where
     }
 }
 
-impl<P, F> ToConstraintFieldGadget<<P::BaseField as Field>::BasePrimeField> for AffineVar<P, F>
+impl<P, F, CF> ToConstraintFieldGadget<CF> for AffineVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
-    F: ToConstraintFieldGadget<<P::BaseField as Field>::BasePrimeField>,
+    F: ToConstraintFieldGadget<CF>,
 {
-    fn to_constraint_field(
-        &self,
-    ) -> Result<Vec<FpVar<<P::BaseField as Field>::BasePrimeField>>, SynthesisError> {
-        let mut res = Vec::<FpVar<<P::BaseField as Field>::BasePrimeField>>::new();
+    fn to_constraint_field(&self) -> Result<Vec<FpVar<CF>>, SynthesisError> {
+        let mut res = Vec::<FpVar<CF>>::new();
 
         res.extend_from_slice(&self.x.to_constraint_field()?);
         res.extend_from_slice(&self.y.to_constraint_field()?);
@@ -110,15 +116,16 @@ where
     }
 }
 
-impl<P, F> R1CSVar<<P::BaseField as Field>::BasePrimeField> for ProjectiveVar<P, F>
+impl<P, F, CF> R1CSVar<CF> for ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
     type Value = SWProjective<P>;
 
-    fn cs(&self) -> ConstraintSystemRef<<P::BaseField as Field>::BasePrimeField> {
+    fn cs(&self) -> ConstraintSystemRef<CF> {
         self.x.cs().or(self.y.cs()).or(self.z.cs())
     }
 
@@ -133,8 +140,7 @@ where
     }
 }
 
-impl<P: SWModelParameters, F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>>
-    ProjectiveVar<P, F>
+impl<P: SWModelParameters, F: FieldVar<P::BaseField, CF>, CF: PrimeField> ProjectiveVar<P, F, CF>
 where
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
@@ -150,7 +156,7 @@ where
 
     /// Convert this point into affine form.
     #[tracing::instrument(target = "r1cs")]
-    pub fn to_affine(&self) -> Result<AffineVar<P, F>, SynthesisError> {
+    pub fn to_affine(&self) -> Result<AffineVar<P, F, CF>, SynthesisError> {
         let cs = self.cs();
         let mode = if self.is_constant() {
             let point = self.value()?.into_affine();
@@ -192,7 +198,7 @@ where
     /// is a constant or is a public input).
     #[tracing::instrument(target = "r1cs", skip(cs, f))]
     pub fn new_variable_omit_on_curve_check(
-        cs: impl Into<Namespace<<P::BaseField as Field>::BasePrimeField>>,
+        cs: impl Into<Namespace<CF>>,
         f: impl FnOnce() -> Result<SWProjective<P>, SynthesisError>,
         mode: AllocationMode,
     ) -> Result<Self, SynthesisError> {
@@ -211,7 +217,7 @@ where
                 } else {
                     (Ok(ge.x), Ok(ge.y), Ok(P::BaseField::one()))
                 }
-            }
+            },
             _ => (
                 Err(SynthesisError::AssignmentMissing),
                 Err(SynthesisError::AssignmentMissing),
@@ -227,11 +233,11 @@ where
     }
 }
 
-impl<P, F> CurveVar<SWProjective<P>, <P::BaseField as Field>::BasePrimeField>
-    for ProjectiveVar<P, F>
+impl<P, F, CF> CurveVar<SWProjective<P>, CF> for ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
     fn constant(g: SWProjective<P>) -> Self {
@@ -243,13 +249,13 @@ where
         Self::new(F::zero(), F::one(), F::zero())
     }
 
-    fn is_zero(&self) -> Result<Boolean<<P::BaseField as Field>::BasePrimeField>, SynthesisError> {
+    fn is_zero(&self) -> Result<Boolean<CF>, SynthesisError> {
         self.z.is_zero()
     }
 
     #[tracing::instrument(target = "r1cs", skip(cs, f))]
     fn new_variable_omit_prime_order_check(
-        cs: impl Into<Namespace<<P::BaseField as Field>::BasePrimeField>>,
+        cs: impl Into<Namespace<CF>>,
         f: impl FnOnce() -> Result<SWProjective<P>, SynthesisError>,
         mode: AllocationMode,
     ) -> Result<Self, SynthesisError> {
@@ -323,7 +329,7 @@ where
         let xy2 = (&self.x * &self.y).double()?; // 4, 5
         let xz2 = (&self.x * &self.z).double()?; // 6, 7
 
-        let axz2 = mul_by_coeff_a::<P, F>(&xz2); // 8
+        let axz2 = mul_by_coeff_a::<P, F, CF>(&xz2); // 8
 
         let bzz3_part = &axz2 + &zz * three_b; // 9, 10
         let yy_m_bzz3 = &yy - &bzz3_part; // 11
@@ -332,8 +338,8 @@ where
         let x_frag = yy_m_bzz3 * &xy2; // 14
 
         let bxz3 = xz2 * three_b; // 15
-        let azz = mul_by_coeff_a::<P, F>(&zz); // 16
-        let b3_xz_pairs = mul_by_coeff_a::<P, F>(&(&xx - &azz)) + &bxz3; // 15, 16, 17, 18, 19
+        let azz = mul_by_coeff_a::<P, F, CF>(&zz); // 16
+        let b3_xz_pairs = mul_by_coeff_a::<P, F, CF>(&(&xx - &azz)) + &bxz3; // 15, 16, 17, 18, 19
         let xx3_p_azz = (xx.double()? + &xx + &azz) * &b3_xz_pairs; // 23, 24, 25
 
         let y = y_frag + &xx3_p_azz; // 26, 27
@@ -352,12 +358,7 @@ where
     }
 }
 
-fn mul_by_coeff_a<
-    P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
->(
-    f: &F,
-) -> F
+fn mul_by_coeff_a<P: SWModelParameters, F: FieldVar<P::BaseField, CF>, CF: PrimeField>(f: &F) -> F
 where
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
@@ -369,13 +370,13 @@ where
 }
 
 impl_bounded_ops!(
-    ProjectiveVar<P, F>,
+    ProjectiveVar<P, F, CF>,
     SWProjective<P>,
     Add,
     add,
     AddAssign,
     add_assign,
-    |this: &'a ProjectiveVar<P, F>, other: &'a ProjectiveVar<P, F>| {
+    |this: &'a ProjectiveVar<P, F, CF>, other: &'a ProjectiveVar<P, F, CF>| {
         // Complete addition formula from Renes-Costello-Batina 2015
         // Algorithm 1
         // (https://eprint.iacr.org/2015/1060).
@@ -392,18 +393,18 @@ impl_bounded_ops!(
         let xz_pairs = ((&this.x + &this.z) * &(&other.x + &other.z)) - (&xx + &zz); // 9, 10, 11, 12, 13
         let yz_pairs = ((&this.y + &this.z) * &(&other.y + &other.z)) - (&yy + &zz); // 14, 15, 16, 17, 18
 
-        let axz = mul_by_coeff_a::<P, F>(&xz_pairs); // 19
+        let axz = mul_by_coeff_a::<P, F, CF>(&xz_pairs); // 19
 
         let bzz3_part = &axz + &zz * three_b; // 20, 21
 
         let yy_m_bzz3 = &yy - &bzz3_part; // 22
         let yy_p_bzz3 = &yy + &bzz3_part; // 23
 
-        let azz = mul_by_coeff_a::<P, F>(&zz);
+        let azz = mul_by_coeff_a::<P, F, CF>(&zz);
         let xx3_p_azz = xx.double().unwrap() + &xx + &azz; // 25, 26, 27, 29
 
         let bxz3 = &xz_pairs * three_b; // 28
-        let b3_xz_pairs = mul_by_coeff_a::<P, F>(&(&xx - &azz)) + &bxz3; // 30, 31, 32
+        let b3_xz_pairs = mul_by_coeff_a::<P, F, CF>(&(&xx - &azz)) + &bxz3; // 30, 31, 32
 
         let x = (&yy_m_bzz3 * &xy_pairs) - &yz_pairs * &b3_xz_pairs; // 35, 39, 40
         let y = (&yy_p_bzz3 * &yy_m_bzz3) + &xx3_p_azz * b3_xz_pairs; // 24, 36, 37, 38
@@ -411,52 +412,57 @@ impl_bounded_ops!(
 
         ProjectiveVar::new(x, y, z)
     },
-    |this: &'a ProjectiveVar<P, F>, other: SWProjective<P>| {
+    |this: &'a ProjectiveVar<P, F, CF>, other: SWProjective<P>| {
         this + ProjectiveVar::constant(other)
     },
-    (F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>, P: SWModelParameters),
+    (F: FieldVar<P::BaseField, CF>, P: SWModelParameters, CF: PrimeField),
     for <'b> &'b F: FieldOpsBounds<'b, P::BaseField, F>,
 );
 
 impl_bounded_ops!(
-    ProjectiveVar<P, F>,
+    ProjectiveVar<P, F, CF>,
     SWProjective<P>,
     Sub,
     sub,
     SubAssign,
     sub_assign,
-    |this: &'a ProjectiveVar<P, F>, other: &'a ProjectiveVar<P, F>| this + other.negate().unwrap(),
-    |this: &'a ProjectiveVar<P, F>, other: SWProjective<P>| this - ProjectiveVar::constant(other),
-    (F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>, P: SWModelParameters),
+    |this: &'a ProjectiveVar<P, F, CF>, other: &'a ProjectiveVar<P, F, CF>| this + other.negate().unwrap(),
+    |this: &'a ProjectiveVar<P, F, CF>, other: SWProjective<P>| this - ProjectiveVar::constant(other),
+    (F: FieldVar<P::BaseField, CF>, P: SWModelParameters, CF: PrimeField),
     for <'b> &'b F: FieldOpsBounds<'b, P::BaseField, F>
 );
 
-impl<'a, P, F> GroupOpsBounds<'a, SWProjective<P>, ProjectiveVar<P, F>> for ProjectiveVar<P, F>
+impl<'a, P, F, CF> GroupOpsBounds<'a, SWProjective<P>, ProjectiveVar<P, F, CF>>
+    for ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'b> &'b F: FieldOpsBounds<'b, P::BaseField, F>,
 {
 }
 
-impl<'a, P, F> GroupOpsBounds<'a, SWProjective<P>, ProjectiveVar<P, F>> for &'a ProjectiveVar<P, F>
+impl<'a, P, F, CF> GroupOpsBounds<'a, SWProjective<P>, ProjectiveVar<P, F, CF>>
+    for &'a ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'b> &'b F: FieldOpsBounds<'b, P::BaseField, F>,
 {
 }
 
-impl<P, F> CondSelectGadget<<P::BaseField as Field>::BasePrimeField> for ProjectiveVar<P, F>
+impl<P, F, CF> CondSelectGadget<CF> for ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
     #[inline]
     #[tracing::instrument(target = "r1cs")]
     fn conditionally_select(
-        cond: &Boolean<<P::BaseField as Field>::BasePrimeField>,
+        cond: &Boolean<CF>,
         true_value: &Self,
         false_value: &Self,
     ) -> Result<Self, SynthesisError> {
@@ -468,17 +474,15 @@ where
     }
 }
 
-impl<P, F> EqGadget<<P::BaseField as Field>::BasePrimeField> for ProjectiveVar<P, F>
+impl<P, F, CF> EqGadget<CF> for ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
     #[tracing::instrument(target = "r1cs")]
-    fn is_eq(
-        &self,
-        other: &Self,
-    ) -> Result<Boolean<<P::BaseField as Field>::BasePrimeField>, SynthesisError> {
+    fn is_eq(&self, other: &Self) -> Result<Boolean<CF>, SynthesisError> {
         let x_equal = (&self.x * &other.z).is_eq(&(&other.x * &self.z))?;
         let y_equal = (&self.y * &other.z).is_eq(&(&other.y * &self.z))?;
         let coordinates_equal = x_equal.and(&y_equal)?;
@@ -491,7 +495,7 @@ where
     fn conditional_enforce_equal(
         &self,
         other: &Self,
-        condition: &Boolean<<P::BaseField as Field>::BasePrimeField>,
+        condition: &Boolean<CF>,
     ) -> Result<(), SynthesisError> {
         let x_equal = (&self.x * &other.z).is_eq(&(&other.x * &self.z))?;
         let y_equal = (&self.y * &other.z).is_eq(&(&other.y * &self.z))?;
@@ -508,7 +512,7 @@ where
     fn conditional_enforce_not_equal(
         &self,
         other: &Self,
-        condition: &Boolean<<P::BaseField as Field>::BasePrimeField>,
+        condition: &Boolean<CF>,
     ) -> Result<(), SynthesisError> {
         let is_equal = self.is_eq(other)?;
         is_equal
@@ -517,14 +521,15 @@ where
     }
 }
 
-impl<P, F> AllocVar<SWAffine<P>, <P::BaseField as Field>::BasePrimeField> for ProjectiveVar<P, F>
+impl<P, F, CF> AllocVar<SWAffine<P>, CF> for ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
     fn new_variable<T: Borrow<SWAffine<P>>>(
-        cs: impl Into<Namespace<<P::BaseField as Field>::BasePrimeField>>,
+        cs: impl Into<Namespace<CF>>,
         f: impl FnOnce() -> Result<T, SynthesisError>,
         mode: AllocationMode,
     ) -> Result<Self, SynthesisError> {
@@ -532,15 +537,15 @@ where
     }
 }
 
-impl<P, F> AllocVar<SWProjective<P>, <P::BaseField as Field>::BasePrimeField>
-    for ProjectiveVar<P, F>
+impl<P, F, CF> AllocVar<SWProjective<P>, CF> for ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
     fn new_variable<T: Borrow<SWProjective<P>>>(
-        cs: impl Into<Namespace<<P::BaseField as Field>::BasePrimeField>>,
+        cs: impl Into<Namespace<CF>>,
         f: impl FnOnce() -> Result<T, SynthesisError>,
         mode: AllocationMode,
     ) -> Result<Self, SynthesisError> {
@@ -628,7 +633,7 @@ where
                     ge.enforce_equal(&ge)?;
                     Ok(ge)
                 }
-            }
+            },
         }
     }
 }
@@ -644,16 +649,15 @@ fn div2(limbs: &mut [u64]) {
     }
 }
 
-impl<P, F> ToBitsGadget<<P::BaseField as Field>::BasePrimeField> for ProjectiveVar<P, F>
+impl<P, F, CF> ToBitsGadget<CF> for ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
     #[tracing::instrument(target = "r1cs")]
-    fn to_bits_le(
-        &self,
-    ) -> Result<Vec<Boolean<<P::BaseField as Field>::BasePrimeField>>, SynthesisError> {
+    fn to_bits_le(&self) -> Result<Vec<Boolean<CF>>, SynthesisError> {
         let g = self.to_affine()?;
         let mut bits = g.x.to_bits_le()?;
         let y_bits = g.y.to_bits_le()?;
@@ -663,9 +667,7 @@ where
     }
 
     #[tracing::instrument(target = "r1cs")]
-    fn to_non_unique_bits_le(
-        &self,
-    ) -> Result<Vec<Boolean<<P::BaseField as Field>::BasePrimeField>>, SynthesisError> {
+    fn to_non_unique_bits_le(&self) -> Result<Vec<Boolean<CF>>, SynthesisError> {
         let g = self.to_affine()?;
         let mut bits = g.x.to_non_unique_bits_le()?;
         let y_bits = g.y.to_non_unique_bits_le()?;
@@ -675,16 +677,15 @@ where
     }
 }
 
-impl<P, F> ToBytesGadget<<P::BaseField as Field>::BasePrimeField> for ProjectiveVar<P, F>
+impl<P, F, CF> ToBytesGadget<CF> for ProjectiveVar<P, F, CF>
 where
     P: SWModelParameters,
-    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>,
+    CF: PrimeField,
+    F: FieldVar<P::BaseField, CF>,
     for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
 {
     #[tracing::instrument(target = "r1cs")]
-    fn to_bytes(
-        &self,
-    ) -> Result<Vec<UInt8<<P::BaseField as Field>::BasePrimeField>>, SynthesisError> {
+    fn to_bytes(&self) -> Result<Vec<UInt8<CF>>, SynthesisError> {
         let g = self.to_affine()?;
         let mut bytes = g.x.to_bytes()?;
         let y_bytes = g.y.to_bytes()?;
@@ -695,9 +696,7 @@ where
     }
 
     #[tracing::instrument(target = "r1cs")]
-    fn to_non_unique_bytes(
-        &self,
-    ) -> Result<Vec<UInt8<<P::BaseField as Field>::BasePrimeField>>, SynthesisError> {
+    fn to_non_unique_bytes(&self) -> Result<Vec<UInt8<CF>>, SynthesisError> {
         let g = self.to_affine()?;
         let mut bytes = g.x.to_non_unique_bytes()?;
         let y_bytes = g.y.to_non_unique_bytes()?;
@@ -0,0 +1,36 @@
+use algebra::bn254::Parameters;
+
+/// Specifies the constraints for computing a pairing in the BN254 bilinear
+/// group.
+pub type PairingVar = crate::pairing::bn::PairingVar<Parameters>;
+
+#[test]
+fn test() {
+    crate::pairing::tests::bilinearity_test::<algebra::Bn254, PairingVar>().unwrap()
+}
+
+#[test]
+fn prepared_g2_allocation_test() {
+    use super::{G1Var, G2PreparedVar};
+    use crate::{alloc::AllocVar, pairing::PairingVar as _, R1CSVar};
+    use algebra::{bn254::*, test_rng, PairingEngine, UniformRand};
+    use r1cs_core::ConstraintSystem;
+
+    let cs = ConstraintSystem::<Fq>::new_ref();
+    let mut rng = test_rng();
+    let a = G1Projective::rand(&mut rng);
+    let b = G2Projective::rand(&mut rng);
+
+    let a_prep =
+        PairingVar::prepare_g1(&G1Var::new_witness(cs.clone(), || Ok(a)).unwrap()).unwrap();
+    let b_prep = G2PreparedVar::new_witness(cs.clone(), || {
+        Ok(<Bn254 as PairingEngine>::G2Prepared::from(G2Affine::from(
+            b,
+        )))
+    })
+    .unwrap();
+    let result = PairingVar::pairing(a_prep, b_prep).unwrap();
+
+    assert_eq!(result.value().unwrap(), Bn254::pairing(a, b));
+    assert!(cs.is_satisfied().unwrap());
+}
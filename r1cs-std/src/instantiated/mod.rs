@@ -13,6 +13,9 @@ pub(crate) mod ed_on_cp6_782;
 #[cfg(feature = "ed_on_bw6_761")]
 pub mod ed_on_bw6_761;
 
+#[cfg(feature = "bn254")]
+pub mod bn254;
+
 #[cfg(feature = "ed_on_bn254")]
 pub mod ed_on_bn254;
 
@@ -56,6 +56,9 @@ mod instantiated;
 #[cfg(feature = "bls12_377")]
 pub use instantiated::bls12_377;
 
+#[cfg(feature = "bn254")]
+pub use instantiated::bn254;
+
 #[cfg(feature = "ed_on_bn254")]
 pub use instantiated::ed_on_bn254;
 
@@ -5,6 +5,8 @@ use r1cs_core::SynthesisError;
 
 /// This module implements pairings for BLS12 bilinear groups.
 pub mod bls12;
+/// This module implements pairings for BN bilinear groups.
+pub mod bn;
 /// This module implements pairings for MNT4 bilinear groups.
 pub mod mnt4;
 /// This module implements pairings for MNT6 bilinear groups.
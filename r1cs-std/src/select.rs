@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{prelude::*, Vec};
 use algebra::Field;
 use r1cs_core::SynthesisError;
 
@@ -70,3 +70,102 @@ where
         constants: &[Self::TableConstant],
     ) -> Result<Self, SynthesisError>;
 }
+
+/// Performs a lookup in a table of `2^k` constants using `k` bits.
+///
+/// This is implemented for every type that implements both
+/// `TwoBitLookupGadget` and `CondSelectGadget`: the table is split into
+/// windows of four entries which are looked up using the two least
+/// significant bits, and the results are then combined using a tree of
+/// conditional selections over the remaining bits. For `FpVar` this costs
+/// roughly `2^k / 2` constraints.
+pub trait LookupGadget<ConstraintF: Field>: TwoBitLookupGadget<ConstraintF>
+where
+    Self: Sized,
+{
+    /// Interprets the slice `bits` as a little-endian integer `b`, and then
+    /// outputs `constants[b]`.
+    ///
+    /// For example, if `bits == [1, 0, 1]`, and `constants == [0, 1, ...,
+    /// 7]`, this method should output a variable corresponding to `5`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `bits` is empty or if `constants.len() != 1 <<
+    /// bits.len()`.
+    fn lookup(
+        bits: &[Boolean<ConstraintF>],
+        constants: &[Self::TableConstant],
+    ) -> Result<Self, SynthesisError>;
+}
+
+impl<ConstraintF, T> LookupGadget<ConstraintF> for T
+where
+    ConstraintF: Field,
+    T: TwoBitLookupGadget<ConstraintF> + CondSelectGadget<ConstraintF>,
+    T::TableConstant: Clone,
+{
+    #[tracing::instrument(target = "r1cs", skip(constants))]
+    fn lookup(
+        bits: &[Boolean<ConstraintF>],
+        constants: &[Self::TableConstant],
+    ) -> Result<Self, SynthesisError> {
+        assert!(!bits.is_empty());
+        assert_eq!(constants.len(), 1 << bits.len());
+        if bits.len() == 1 {
+            // Pad the table so that the most significant bit is irrelevant.
+            let padded = [
+                constants[0].clone(),
+                constants[1].clone(),
+                constants[0].clone(),
+                constants[1].clone(),
+            ];
+            return Self::two_bit_lookup(&[bits[0].clone(), Boolean::FALSE], &padded);
+        }
+
+        let mut values = constants
+            .chunks(4)
+            .map(|window| Self::two_bit_lookup(&bits[..2], window))
+            .collect::<Result<Vec<_>, _>>()?;
+        for bit in &bits[2..] {
+            values = values
+                .chunks(2)
+                .map(|pair| bit.select(&pair[1], &pair[0]))
+                .collect::<Result<_, _>>()?;
+        }
+        debug_assert_eq!(values.len(), 1);
+        Ok(values.pop().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{alloc::AllocVar, fields::fp::FpVar, prelude::*};
+    use algebra::bls12_381::Fr;
+    use r1cs_core::ConstraintSystem;
+
+    #[test]
+    fn lookup() {
+        for num_bits in 1..6 {
+            let table = (0..(1u64 << num_bits))
+                .map(|i| Fr::from(3 * i + 7))
+                .collect::<Vec<_>>();
+            for index in 0..(1usize << num_bits) {
+                let cs = ConstraintSystem::<Fr>::new_ref();
+                let bits = (0..num_bits)
+                    .map(|i| Boolean::new_witness(cs.clone(), || Ok((index >> i) & 1 == 1)))
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+                let result = FpVar::lookup(&bits, &table).unwrap();
+                assert_eq!(result.value().unwrap(), table[index]);
+                assert!(cs.is_satisfied().unwrap());
+
+                let constant_bits = (0..num_bits)
+                    .map(|i| Boolean::constant((index >> i) & 1 == 1))
+                    .collect::<Vec<_>>();
+                let result = FpVar::lookup(&constant_bits, &table).unwrap();
+                assert_eq!(result.value().unwrap(), table[index]);
+            }
+        }
+    }
+}
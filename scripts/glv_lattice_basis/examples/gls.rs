@@ -0,0 +1,28 @@
+extern crate algebra;
+extern crate algebra_core;
+
+use algebra::{mnt4_298, mnt4_753, mnt6_298, mnt6_753};
+use algebra_core::{
+    curves::{mnt4::MNT4Parameters, mnt6::MNT6Parameters},
+    BigInteger1536, BigInteger320, BigInteger640, BigInteger768,
+};
+use glv_lattice_basis::*;
+
+fn main() {
+    println!("MNT4-298 G2:");
+    print_gls_params::<mnt4_298::g2::Parameters, BigInteger640, BigInteger320>(
+        mnt4_298::Parameters::TWIST,
+    );
+    println!("\nMNT6-298 G2:");
+    print_gls_params::<mnt6_298::g2::Parameters, BigInteger640, BigInteger320>(
+        mnt6_298::Parameters::TWIST,
+    );
+    println!("\nMNT4-753 G2:");
+    print_gls_params::<mnt4_753::g2::Parameters, BigInteger1536, BigInteger768>(
+        mnt4_753::Parameters::TWIST,
+    );
+    println!("\nMNT6-753 G2:");
+    print_gls_params::<mnt6_753::g2::Parameters, BigInteger1536, BigInteger768>(
+        mnt6_753::Parameters::TWIST,
+    );
+}
@@ -4,7 +4,10 @@ extern crate num_traits;
 
 mod arithmetic;
 
-use algebra_core::{BigInteger, Field, PrimeField, ProjectiveCurve};
+use algebra_core::{
+    curves::models::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+    AffineCurve, BigInteger, Field, PrimeField, ProjectiveCurve,
+};
 pub use arithmetic::*;
 use num_traits::Zero;
 use std::ops::Neg;
@@ -96,10 +99,64 @@ pub fn print_glv_params<G: ProjectiveCurve, WideBigInt: BigInteger, BaseFieldBig
     assert!(g.mul(lambda) == g_endo);
 
     println!("const OMEGA: Self::BaseField = {:?};", omega);
-    let n = <G::ScalarField as PrimeField>::modulus();
     println!("const LAMBDA: Self::ScalarField = {:?};", lambda);
 
-    let vecs = get_lattice_basis::<G::ScalarField>(n, lambda.into_repr());
+    print_lattice_params::<G::ScalarField, WideBigInt>(lambda);
+}
+
+/// Prints the GLS parameters for the endomorphism
+/// `psi((x, y)) = (PSI_COEFF_X * x^p, PSI_COEFF_Y * y^p)` of the twist `G`,
+/// where `twist` is the element such that `G` is defined by
+/// `y^2 = x^3 + a * twist^2 * x + b * twist^3`. This is the
+/// untwist-Frobenius-twist endomorphism, so `LAMBDA = +-p mod n`.
+pub fn print_gls_params<
+    P: SWModelParameters,
+    WideBigInt: BigInteger,
+    BaseFieldBigInt: BigInteger,
+>(
+    twist: P::BaseField,
+) {
+    // PSI_COEFF_X = twist^(1 - p), PSI_COEFF_Y = twist^(3 * (1 - p) / 2)
+    let mut p_minus_one = BaseFieldBigInt::from_slice(P::BaseField::characteristic());
+    p_minus_one.sub_noborrow(&BaseFieldBigInt::from(1));
+    let psi_coeff_x = twist.pow(p_minus_one).inverse().unwrap();
+    p_minus_one.div2();
+    let psi_coeff_y = twist.pow(p_minus_one).pow(&[3]).inverse().unwrap();
+
+    let g = GroupAffine::<P>::prime_subgroup_generator();
+    let mut x = g.x;
+    let mut y = g.y;
+    x.frobenius_map(1);
+    y.frobenius_map(1);
+    let g_endo = GroupAffine::<P>::new(x * &psi_coeff_x, y * &psi_coeff_y, false);
+    assert!(g_endo.is_on_curve());
+
+    // p mod n
+    let two_to_64 = P::ScalarField::from(1u64 << 32).square();
+    let p_mod_n = P::BaseField::characteristic()
+        .iter()
+        .rev()
+        .fold(P::ScalarField::zero(), |acc, limb| {
+            acc * &two_to_64 + &P::ScalarField::from(*limb)
+        });
+    let lambda = if g.mul(p_mod_n) == g_endo.into_projective() {
+        p_mod_n
+    } else {
+        assert!(g.mul(p_mod_n.neg()) == g_endo.into_projective());
+        p_mod_n.neg()
+    };
+
+    println!("const PSI_COEFF_X: Self::BaseField = {:?};", psi_coeff_x);
+    println!("const PSI_COEFF_Y: Self::BaseField = {:?};", psi_coeff_y);
+    println!("const LAMBDA: Self::ScalarField = {:?};", lambda);
+
+    print_lattice_params::<P::ScalarField, WideBigInt>(lambda);
+}
+
+fn print_lattice_params<F: PrimeField, WideBigInt: BigInteger>(lambda: F) {
+    let n = F::modulus();
+
+    let vecs = get_lattice_basis::<F>(n, lambda.into_repr());
 
     // We check that `(|B1| + 2) * (|B2| + 2) <  2n`
     // and `B_i^2 < 2n` e.g. `|B_i| < \sqrt{2n}$
@@ -112,7 +169,7 @@ pub fn print_glv_params<G: ProjectiveCurve, WideBigInt: BigInteger, BaseFieldBig
 
     let mut b1 = ((vecs.0).1).1;
     let mut b2 = ((vecs.1).1).1;
-    let two = <G::ScalarField as PrimeField>::BigInt::from(2);
+    let two = F::BigInt::from(2);
     let b1b1 = WideBigInt::mul_no_reduce(&b1.as_ref()[..], &b1.as_ref()[..]);
     let b2b2 = WideBigInt::mul_no_reduce(&b2.as_ref()[..], &b2.as_ref()[..]);
 
@@ -129,9 +186,9 @@ pub fn print_glv_params<G: ProjectiveCurve, WideBigInt: BigInteger, BaseFieldBig
 
         let mut t1_big = WideBigInt::from_slice(t1.as_ref());
         let n_big = WideBigInt::from_slice(n.as_ref());
-        t1_big.muln(<G::ScalarField as PrimeField>::BigInt::NUM_LIMBS as u32 * 64);
+        t1_big.muln(F::BigInt::NUM_LIMBS as u32 * 64);
         let (g1_big, _) = div_with_remainder::<WideBigInt>(t1_big, n_big);
-        let g1 = <G::ScalarField as PrimeField>::BigInt::from_slice(g1_big.as_ref());
+        let g1 = F::BigInt::from_slice(g1_big.as_ref());
 
         println!("/// |round(B{} * R / n)|", i + 1);
         println!(
@@ -148,21 +205,18 @@ pub fn print_glv_params<G: ProjectiveCurve, WideBigInt: BigInteger, BaseFieldBig
 
         debug_assert_eq!(
             recompose_integer(
-                G::ScalarField::from_repr(*s1).unwrap(),
+                F::from_repr(*s1).unwrap(),
                 if !flag {
-                    G::ScalarField::from_repr(*t1).unwrap()
+                    F::from_repr(*t1).unwrap()
                 } else {
-                    G::ScalarField::from_repr(*t1).unwrap().neg()
+                    F::from_repr(*t1).unwrap().neg()
                 },
                 lambda
             ),
-            G::ScalarField::zero()
+            F::zero()
         );
     }
-    println!(
-        "const R_BITS: u32 = {:?};",
-        <G::ScalarField as PrimeField>::BigInt::NUM_LIMBS * 64
-    );
+    println!("const R_BITS: u32 = {:?};", F::BigInt::NUM_LIMBS * 64);
 }
 
 // We work on arrays of size 3
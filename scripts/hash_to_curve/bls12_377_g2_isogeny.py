@@ -0,0 +1,468 @@
+#!/usr/bin/env python3
+"""Derives the simplified SWU parameters of BLS12-377 G2.
+
+G2 is the curve E: y^2 = x^3 + b over Fq2 = Fq[u] / (u^2 + 5), with b = 1 / u.
+The simplified SWU map needs a curve with a * b != 0, so it is evaluated on a
+curve E' and carried to E by an isogeny E' -> E.
+
+E has j-invariant 0, so its endomorphism ring is Z[w]. The discriminant of
+its Frobenius is -3 * f^2, and a rational l-isogeny from E to a curve with a
+non-zero j-invariant exists only for primes l that divide f. The smallest
+such prime is 23, so the isogeny has degree 23. This script:
+
+1. factors the 23-division polynomial of E to find the kernel polynomial of a
+   rational 23-isogeny E -> E', computed with Kohel's formulas;
+2. maps another rational subgroup of order 23 of E through that isogeny,
+   which gives the kernel of the dual isogeny E' -> E;
+3. composes the dual isogeny with the isomorphism onto E, and makes its
+   denominators monic;
+4. finds Z as in the draft's `find_z_sswu`;
+5. prints the `SWUParameters` constants for `algebra/src/bls12_377/curves/g2.rs`.
+
+It only needs Python 3. It runs in a few minutes, and it is deterministic:
+the random choices made while factoring are seeded.
+"""
+import random
+
+q = 0x01ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000001
+NR = q - 5  # u^2 = NR
+QQ = q * q  # the order of Fq2
+B = (0, 155198655607781456406391640216936120121836107652948796323930557600032281009004493664981332883744016074664192874906)
+
+# Elements of Fq2 are pairs (c0, c1) standing for c0 + c1 * u.
+
+
+def smul(s, t):
+    return ((s[0] * t[0] + NR * s[1] * t[1]) % q, (s[0] * t[1] + s[1] * t[0]) % q)
+
+
+def sadd(s, t):
+    return ((s[0] + t[0]) % q, (s[1] + t[1]) % q)
+
+
+def ssub(s, t):
+    return ((s[0] - t[0]) % q, (s[1] - t[1]) % q)
+
+
+def sneg(s):
+    return ((-s[0]) % q, (-s[1]) % q)
+
+
+def sinv(s):
+    n = pow((s[0] * s[0] - NR * s[1] * s[1]) % q, q - 2, q)
+    return (s[0] * n % q, -s[1] * n % q)
+
+
+def sc(k):
+    return (k % q, 0)
+
+
+def is_square(s):
+    n = (s[0] * s[0] - NR * s[1] * s[1]) % q
+    return n == 0 or pow(n, (q - 1) // 2, q) == 1
+
+
+# Polynomials over Fq2 are pairs of coefficient lists (ascending degree).
+# Products use Kronecker substitution, which is much faster than schoolbook
+# multiplication in Python.
+SLOT = 104  # bytes per coefficient slot
+
+
+def pack(coeffs):
+    return int.from_bytes(b"".join(x.to_bytes(SLOT, "little") for x in coeffs), "little")
+
+
+def unpack(n, length):
+    bs = n.to_bytes(SLOT * length, "little")
+    return [int.from_bytes(bs[i * SLOT:(i + 1) * SLOT], "little") % q for i in range(length)]
+
+
+def trim(p):
+    a, b = list(p[0]), list(p[1])
+    n = max(len(a), len(b))
+    a += [0] * (n - len(a))
+    b += [0] * (n - len(b))
+    while n and a[n - 1] == 0 and b[n - 1] == 0:
+        n -= 1
+    return (a[:n], b[:n])
+
+
+def deg(p):
+    return len(p[0]) - 1
+
+
+def coeff(p, i):
+    return (p[0][i], p[1][i]) if i < len(p[0]) else (0, 0)
+
+
+def lead(p):
+    return coeff(p, deg(p))
+
+
+def mul(p, r):
+    (a0, a1), (b0, b1) = p, r
+    if not a0 or not b0:
+        return ([], [])
+    n = len(a0) + len(b0) - 1
+    p00 = unpack(pack(a0) * pack(b0), n)
+    p11 = unpack(pack(a1) * pack(b1), n)
+    pm = unpack(pack([(x + y) % q for x, y in zip(a0, a1)]) * pack([(x + y) % q for x, y in zip(b0, b1)]), n)
+    c0 = [(x + NR * y) % q for x, y in zip(p00, p11)]
+    c1 = [(z - x - y) % q for x, y, z in zip(p00, p11, pm)]
+    return trim((c0, c1))
+
+
+def add(p, r):
+    n = max(len(p[0]), len(r[0]))
+    pad = lambda c: c + [0] * (n - len(c))
+    return trim((
+        [(x + y) % q for x, y in zip(pad(p[0]), pad(r[0]))],
+        [(x + y) % q for x, y in zip(pad(p[1]), pad(r[1]))],
+    ))
+
+
+def neg(p):
+    return ([(-x) % q for x in p[0]], [(-x) % q for x in p[1]])
+
+
+def sub(p, r):
+    return add(p, neg(r))
+
+
+def scale(p, s):
+    return trim((
+        [(s[0] * x + NR * s[1] * y) % q for x, y in zip(*p)],
+        [(s[0] * y + s[1] * x) % q for x, y in zip(*p)],
+    ))
+
+
+def const(s):
+    return trim(([s[0] % q], [s[1] % q]))
+
+
+def poly(coeffs):
+    return trim(([c[0] % q for c in coeffs], [c[1] % q for c in coeffs]))
+
+
+X = ([0, 1], [0, 0])
+ONE = ([1], [0])
+
+
+def monic(p):
+    return scale(p, sinv(lead(p)))
+
+
+def deriv(p):
+    return trim(([i * x % q for i, x in enumerate(p[0])][1:], [i * x % q for i, x in enumerate(p[1])][1:]))
+
+
+def evaluate(p, s):
+    r = (0, 0)
+    for i in range(deg(p), -1, -1):
+        r = sadd(smul(r, s), coeff(p, i))
+    return r
+
+
+def divmod_(a, b):
+    a, b = trim(a), trim(b)
+    n = deg(b)
+    if deg(a) < n:
+        return ([], []), a
+    inv = sinv(lead(b))
+    a0, a1 = list(a[0]), list(a[1])
+    quo0, quo1 = [0] * (deg(a) - n + 1), [0] * (deg(a) - n + 1)
+    for d in range(deg(a) - n, -1, -1):
+        c = smul((a0[d + n], a1[d + n]), inv)
+        quo0[d], quo1[d] = c
+        for i in range(n + 1):
+            t = smul(c, coeff(b, i))
+            a0[d + i] = (a0[d + i] - t[0]) % q
+            a1[d + i] = (a1[d + i] - t[1]) % q
+    return trim((quo0, quo1)), trim((a0[:n], a1[:n]))
+
+
+def gcd(a, b):
+    a, b = trim(a), trim(b)
+    while b[0]:
+        a, b = b, divmod_(a, b)[1]
+    return monic(a) if a[0] else a
+
+
+class Mod:
+    """Arithmetic modulo a polynomial, with Newton-iteration division."""
+
+    def __init__(self, m):
+        self.m = monic(trim(m))
+        self.n = deg(self.m)
+        rev_m = (self.m[0][::-1], self.m[1][::-1])
+        # The inverse of rev(m) modulo x^n.
+        g, k = const(sinv(coeff(rev_m, 0))), 1
+        while k < self.n:
+            k = min(2 * k, self.n)
+            e = sub(const(sc(2)), self.trunc(mul(self.trunc(rev_m, k), g), k))
+            g = self.trunc(mul(g, e), k)
+        self.rinv = g
+
+    @staticmethod
+    def trunc(p, k):
+        return trim((p[0][:k], p[1][:k]))
+
+    def red(self, a):
+        a = trim(a)
+        da = deg(a)
+        if da < self.n:
+            return a
+        k = da - self.n + 1
+        if k > self.n:
+            return divmod_(a, self.m)[1]
+        ra = self.trunc((a[0][::-1], a[1][::-1]), k)
+        quo = self.trunc(mul(ra, self.trunc(self.rinv, k)), k)
+        quo = (quo[0] + [0] * (k - len(quo[0])), quo[1] + [0] * (k - len(quo[1])))
+        quo = (quo[0][::-1], quo[1][::-1])
+        return self.trunc(sub(a, mul(quo, self.m)), self.n)
+
+    def mulmod(self, a, b):
+        return self.red(mul(a, b))
+
+    def powmod(self, a, e):
+        r, a = ONE, self.red(a)
+        for bit in bin(e)[2:]:
+            r = self.mulmod(r, r)
+            if bit == "1":
+                r = self.mulmod(r, a)
+        return r
+
+    def compose(self, a, h):
+        """a(h) modulo m."""
+        r = ([], [])
+        for i in range(deg(a), -1, -1):
+            r = add(self.mulmod(r, h) if r[0] else r, const(coeff(a, i)))
+        return r
+
+
+def random_poly(n):
+    return trim(([random.randrange(q) for _ in range(n)], [random.randrange(q) for _ in range(n)]))
+
+
+def equal_degree_factor(f, d):
+    """Returns one irreducible factor of f, all of whose irreducible factors
+    have degree d (Cantor-Zassenhaus, with the trace map for d > 1)."""
+    f = monic(f)
+    while deg(f) > d:
+        m = Mod(f)
+        xq = m.powmod(X, QQ)
+        while True:
+            a = random_poly(deg(f))
+            t, ai = a, a
+            for _ in range(d - 1):
+                ai = m.compose(ai, xq)
+                t = add(t, ai)
+            g = gcd(f, sub(m.powmod(t, (QQ - 1) // 2), ONE))
+            if 0 < deg(g) < deg(f):
+                h = divmod_(f, g)[0]
+                f = g if deg(g) <= deg(h) else h
+                break
+    return f
+
+
+def roots(f):
+    """Returns the roots of f in Fq2."""
+    f = monic(f)
+    f = gcd(f, sub(Mod(f).powmod(X, QQ), X))
+    out = []
+    while deg(f) > 0:
+        r = equal_degree_factor(f, 1)
+        out.append(sneg(coeff(r, 0)))
+        f = divmod_(f, r)[0]
+    return sorted(out)
+
+
+def division_polynomial(a, b, n):
+    """The n-th division polynomial of y^2 = x^3 + a x + b, divided by 2y when
+    n is even."""
+    g = poly([b, a, (0, 0), (1, 0)])
+    g2_16 = scale(mul(g, g), sc(16))
+    a2, a3 = smul(a, a), smul(smul(a, a), a)
+    ab, b2 = smul(a, b), smul(b, b)
+    f = {0: ([], []), 1: ONE, 2: ONE}
+    f[3] = poly([sneg(a2), smul(sc(12), b), smul(sc(6), a), (0, 0), (3, 0)])
+    f[4] = scale(poly([
+        sneg(sadd(smul(sc(8), b2), a3)), smul(sc(-4), ab), smul(sc(-5), a2),
+        smul(sc(20), b), smul(sc(5), a), (0, 0), (1, 0),
+    ]), sc(2))
+
+    def get(k):
+        if k not in f:
+            m = k // 2
+            if k % 2:
+                t1 = mul(get(m + 2), mul(get(m), mul(get(m), get(m))))
+                t2 = mul(get(m - 1), mul(get(m + 1), mul(get(m + 1), get(m + 1))))
+                if m % 2 == 0:
+                    t1 = mul(g2_16, t1)
+                else:
+                    t2 = mul(g2_16, t2)
+                f[k] = sub(t1, t2)
+            else:
+                f[k] = mul(get(m), sub(
+                    mul(get(m + 2), mul(get(m - 1), get(m - 1))),
+                    mul(get(m - 2), mul(get(m + 1), get(m + 1))),
+                ))
+        return f[k]
+
+    return get(n)
+
+
+def kohel(a, b, kernel):
+    """The normalized isogeny of odd degree with the given kernel polynomial
+    from y^2 = x^3 + a x + b. Returns the codomain coefficients and the map
+    (x, y) -> (x_num / x_den, y * y_num / y_den)."""
+    kernel = monic(kernel)
+    n = deg(kernel)
+    s1 = sneg(coeff(kernel, n - 1))
+    s2 = coeff(kernel, n - 2)
+    s3 = sneg(coeff(kernel, n - 3))
+    p2 = ssub(smul(s1, s1), smul(sc(2), s2))
+    p3 = sadd(ssub(smul(smul(s1, s1), s1), smul(sc(3), smul(s1, s2))), smul(sc(3), s3))
+    v = sadd(smul(sc(6), p2), smul(sc(2 * n), a))
+    w = sadd(sadd(smul(sc(10), p3), smul(sc(6), smul(a, s1))), smul(sc(4 * n), b))
+    codomain = (ssub(a, smul(sc(5), v)), ssub(b, smul(sc(7), w)))
+
+    # x_num / x_den = (2n + 1) x - 2 s1 - (6 x^2 + 2a) D' / D
+    #                 + 4 (x^3 + a x + b) (D'^2 - D D'') / D^2
+    g = poly([b, a, (0, 0), (1, 0)])
+    d1 = deriv(kernel)
+    d2 = deriv(d1)
+    lin = poly([smul(sc(-2), s1), sc(2 * n + 1)])
+    quad = poly([smul(sc(2), a), (0, 0), (6, 0)])
+    x_num = add(
+        sub(mul(lin, mul(kernel, kernel)), mul(quad, mul(d1, kernel))),
+        scale(mul(g, sub(mul(d1, d1), mul(kernel, d2))), sc(4)),
+    )
+    x_den = mul(kernel, kernel)
+    # The isogeny is normalized, so y_num / y_den is the derivative of
+    # x_num / x_den.
+    y_num = sub(mul(deriv(x_num), kernel), scale(mul(x_num, d1), sc(2)))
+    y_den = mul(x_den, kernel)
+    return codomain, (x_num, x_den, y_num, y_den)
+
+
+def minimal_polynomial(alpha, m):
+    """The minimal polynomial over Fq2 of alpha in Fq2[x] / m, where m is
+    irreducible."""
+    n = m.n
+    powers = [ONE]
+    for _ in range(n):
+        powers.append(m.mulmod(powers[-1], alpha))
+    rows = [[coeff(powers[k], i) for k in range(n)] + [sneg(coeff(powers[n], i))] for i in range(n)]
+    for col in range(n):
+        pivot = next(r for r in range(col, n) if rows[r][col] != (0, 0))
+        rows[col], rows[pivot] = rows[pivot], rows[col]
+        inv = sinv(rows[col][col])
+        rows[col] = [smul(x, inv) for x in rows[col]]
+        for r in range(n):
+            if r != col and rows[r][col] != (0, 0):
+                c = rows[r][col]
+                rows[r] = [ssub(x, smul(c, y)) for x, y in zip(rows[r], rows[col])]
+    return poly([rows[i][n] for i in range(n)] + [(1, 0)])
+
+
+def orbit_kernel(psi_z):
+    """Returns a kernel polynomial of degree 11 of E.
+
+    Since w acts on E by (x, y) -> (w x, y), the division polynomial is a
+    polynomial in z = x^3, whose irreducible factors of degree 11 each hold an
+    orbit of three kernels under w."""
+    r = equal_degree_factor(psi_z, 11)
+    r3 = poly([coeff(r, i // 3) if i % 3 == 0 else (0, 0) for i in range(3 * deg(r) + 1)])
+    return r, equal_degree_factor(r3, 11)
+
+
+def main():
+    random.seed(0)
+    zero = (0, 0)
+
+    psi = monic(division_polynomial(zero, B, 23))
+    assert deg(psi) == 264
+    assert all(coeff(psi, i) == zero for i in range(deg(psi) + 1) if i % 3)
+    psi_z = trim((psi[0][::3], psi[1][::3]))
+
+    # The isogeny E -> E'.
+    orbit, kernel = orbit_kernel(psi_z)
+    (a_iso, b_iso), (x_num, x_den, _, _) = kohel(zero, B, kernel)
+    assert a_iso != zero and b_iso != zero
+
+    # A kernel from another orbit, and its image on E', which is the kernel of
+    # the dual isogeny E' -> E.
+    _, other = orbit_kernel(divmod_(psi_z, orbit)[0])
+    m = Mod(other)
+    alpha = m.mulmod(x_num, m.powmod(m.red(x_den), QQ ** 11 - 2))
+    dual_kernel = minimal_polynomial(alpha, m)
+    (a_e, b_e), (x_num, x_den, y_num, y_den) = kohel(a_iso, b_iso, dual_kernel)
+    assert a_e == zero
+
+    # Compose with the isomorphism (x, y) -> (mu^2 x, mu^3 y) onto E.
+    mu = roots(poly([sneg(smul(B, sinv(b_e)))] + [zero] * 5 + [(1, 0)]))[0]
+    mu2 = smul(mu, mu)
+    x_scale = sinv(lead(x_den))
+    y_scale = sinv(lead(y_den))
+    x_num, x_den = scale(x_num, smul(mu2, x_scale)), scale(x_den, x_scale)
+    y_num, y_den = scale(y_num, smul(smul(mu2, mu), y_scale)), scale(y_den, y_scale)
+
+    # The image of every point of E' is on E:
+    # (y_num / y_den)^2 (x^3 + a' x + b') = (x_num / x_den)^3 + b.
+    g_iso = poly([b_iso, a_iso, zero, (1, 0)])
+    x_den3 = mul(x_den, mul(x_den, x_den))
+    lhs = mul(mul(y_num, y_num), mul(g_iso, x_den3))
+    rhs = mul(add(mul(x_num, mul(x_num, x_num)), scale(x_den3, B)), mul(y_den, y_den))
+    assert lhs == rhs
+
+    # find_z_sswu: try u, -u, u + 1, -(u + 1), ...
+    def is_good_z(z):
+        if is_square(z) or z == sc(-1):
+            return False
+        if roots(poly([ssub(b_iso, z), a_iso, zero, (1, 0)])):
+            return False
+        x = smul(b_iso, sinv(smul(z, a_iso)))
+        return is_square(evaluate(poly([b_iso, a_iso, zero, (1, 0)]), x))
+
+    ctr = 0
+    while True:
+        z = next((z for z in [(ctr, 1), sneg((ctr, 1))] if is_good_z(z)), None)
+        if z:
+            break
+        ctr += 1
+
+    r = pow(2, 384, q)
+
+    def fq(v):
+        m = v * r % q
+        limbs = ", ".join(str((m >> (64 * i)) % 2 ** 64) for i in range(6))
+        return f"field_new!(Fq, BigInteger384([{limbs}]))"
+
+    def fq2_const(name, s):
+        return (
+            f"    #[rustfmt::skip]\n    const {name}: Fq2 = field_new!(Fq2,\n"
+            f"        {fq(s[0])},\n        {fq(s[1])},\n    );\n"
+        )
+
+    def fq2_array(name, p):
+        out = f"    #[rustfmt::skip]\n    const {name}: &'static [Self::BaseField] = &[\n"
+        for i in range(deg(p) + 1):
+            c = coeff(p, i)
+            out += f"        field_new!(Fq2,\n            {fq(c[0])},\n            {fq(c[1])},\n        ),\n"
+        return out + "    ];\n"
+
+    print(f"    /// ISOGENOUS_COEFF_A = [\n    /// {a_iso[0]},\n    /// {a_iso[1]}]")
+    print(fq2_const("ISOGENOUS_COEFF_A", a_iso))
+    print(f"    /// ISOGENOUS_COEFF_B = [\n    /// {b_iso[0]},\n    /// {b_iso[1]}]")
+    print(fq2_const("ISOGENOUS_COEFF_B", b_iso))
+    print(f"    /// Z = [{z[0]}, {z[1]}]")
+    print(fq2_const("Z", z))
+    print(fq2_array("ISOGENY_X_NUM", x_num))
+    print(fq2_array("ISOGENY_X_DEN", x_den))
+    print(fq2_array("ISOGENY_Y_NUM", y_num))
+    print(fq2_array("ISOGENY_Y_DEN", y_den), end="")
+
+
+if __name__ == "__main__":
+    main()
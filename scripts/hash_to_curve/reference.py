@@ -0,0 +1,272 @@
+#!/usr/bin/env python3
+"""A reference implementation of the `_XMD:SHA-256_SSWU_RO_` hash-to-curve
+suites, written directly from the IETF hash-to-curve draft and independent of
+`algebra_core::hash_to_curve`.
+
+It first checks itself against the draft's BLS12381G2 test vectors, then
+prints the BLS12-377 G1 and G2 vectors used in
+`algebra/src/bls12_377/curves/tests.rs`. The SWU parameters are read from the
+Rust sources, so this also checks the constants in the tree.
+
+It only needs Python 3; run it from the root of the repository.
+"""
+import hashlib
+import re
+
+
+def expand_message_xmd(msg, dst, len_in_bytes):
+    ell = (len_in_bytes + 31) // 32
+    assert ell <= 255 and len(dst) <= 255
+    dst_prime = dst + bytes([len(dst)])
+    b_0 = hashlib.sha256(bytes(64) + msg + len_in_bytes.to_bytes(2, "big") + b"\0" + dst_prime).digest()
+    b_i = hashlib.sha256(b_0 + b"\1" + dst_prime).digest()
+    uniform_bytes = b_i
+    for i in range(2, ell + 1):
+        b_i = hashlib.sha256(bytes(x ^ y for x, y in zip(b_0, b_i)) + bytes([i]) + dst_prime).digest()
+        uniform_bytes += b_i
+    return uniform_bytes[:len_in_bytes]
+
+
+class Field:
+    """Fp (m = 1) or Fp2 = Fp[u] / (u^2 - nr) (m = 2). Elements are pairs
+    (c0, c1) standing for c0 + c1 * u, with c1 = 0 for Fp."""
+
+    def __init__(self, p, m, nr=None):
+        self.p, self.m, self.nr = p, m, nr
+
+    def mul(self, a, b):
+        p, nr = self.p, self.nr or 0
+        return ((a[0] * b[0] + nr * a[1] * b[1]) % p, (a[0] * b[1] + a[1] * b[0]) % p)
+
+    def add(self, a, b):
+        return ((a[0] + b[0]) % self.p, (a[1] + b[1]) % self.p)
+
+    def sub(self, a, b):
+        return ((a[0] - b[0]) % self.p, (a[1] - b[1]) % self.p)
+
+    def neg(self, a):
+        return ((-a[0]) % self.p, (-a[1]) % self.p)
+
+    def norm(self, a):
+        return (a[0] * a[0] - (self.nr or 0) * a[1] * a[1]) % self.p
+
+    def inv0(self, a):
+        n = self.norm(a)
+        if n == 0:
+            return (0, 0)
+        t = pow(n, self.p - 2, self.p)
+        return (a[0] * t % self.p, -a[1] * t % self.p)
+
+    def is_square(self, a):
+        n = self.norm(a) if self.m == 2 else a[0]
+        return n == 0 or pow(n, (self.p - 1) // 2, self.p) == 1
+
+    def sqrt_fp(self, a):
+        # Tonelli-Shanks.
+        p = self.p
+        a %= p
+        if a == 0:
+            return 0
+        if pow(a, (p - 1) // 2, p) != 1:
+            return None
+        s, t = 0, p - 1
+        while t % 2 == 0:
+            s, t = s + 1, t // 2
+        z = 2
+        while pow(z, (p - 1) // 2, p) == 1:
+            z += 1
+        m, c, x, b = s, pow(z, t, p), pow(a, (t + 1) // 2, p), pow(a, t, p)
+        while b != 1:
+            i, b2 = 0, b
+            while b2 != 1:
+                i, b2 = i + 1, b2 * b2 % p
+            e = pow(c, 1 << (m - i - 1), p)
+            x, c, b, m = x * e % p, e * e % p, b * e * e % p, i
+        return x
+
+    def sqrt(self, a):
+        p = self.p
+        if self.m == 1:
+            return (self.sqrt_fp(a[0]), 0)
+        if a[1] == 0:
+            r = self.sqrt_fp(a[0])
+            if r is not None:
+                return (r, 0)
+            return (0, self.sqrt_fp(a[0] * pow(self.nr, p - 2, p)))
+        n = self.sqrt_fp(self.norm(a))
+        half = pow(2, p - 2, p)
+        x0 = self.sqrt_fp((a[0] + n) * half)
+        if x0 is None:
+            x0 = self.sqrt_fp((a[0] - n) * half)
+        r = (x0, a[1] * pow(2 * x0, p - 2, p) % p)
+        assert self.mul(r, r) == a
+        return r
+
+    def sgn0(self, a):
+        return (a[0] % 2) | ((a[0] == 0) & (a[1] % 2))
+
+    def eval(self, coeffs, x):
+        r = (0, 0)
+        for c in reversed(coeffs):
+            r = self.add(self.mul(r, x), c)
+        return r
+
+
+class Suite:
+    def __init__(self, field, b, iso_a, iso_b, z, x_num, x_den, y_num, y_den, h_eff, r):
+        self.F, self.b, self.r = field, b, r
+        self.iso_a, self.iso_b, self.z = iso_a, iso_b, z
+        self.x_num, self.x_den, self.y_num, self.y_den = x_num, x_den, y_num, y_den
+        self.h_eff = h_eff
+        self.L = (field.p.bit_length() + 128 + 7) // 8
+
+    def hash_to_field(self, msg, dst, count):
+        m, L = self.F.m, self.L
+        uniform_bytes = expand_message_xmd(msg, dst, count * m * L)
+        elements = []
+        for i in range(count):
+            e = [int.from_bytes(uniform_bytes[(i * m + j) * L:(i * m + j + 1) * L], "big") % self.F.p for j in range(m)]
+            elements.append((e[0], e[1] if m == 2 else 0))
+        return elements
+
+    def map_to_curve_simple_swu(self, u):
+        F, a, b, z = self.F, self.iso_a, self.iso_b, self.z
+        zu2 = F.mul(z, F.mul(u, u))
+        tv1 = F.inv0(F.add(F.mul(zu2, zu2), zu2))
+        if tv1 == (0, 0):
+            x1 = F.mul(b, F.inv0(F.mul(z, a)))
+        else:
+            x1 = F.mul(F.neg(F.mul(b, F.inv0(a))), F.add((1, 0), tv1))
+        g = lambda x: F.add(F.mul(F.add(F.mul(x, x), a), x), b)
+        if F.is_square(g(x1)):
+            x, y = x1, F.sqrt(g(x1))
+        else:
+            x = F.mul(zu2, x1)
+            y = F.sqrt(g(x))
+        if F.sgn0(u) != F.sgn0(y):
+            y = F.neg(y)
+        return x, y
+
+    def iso_map(self, point):
+        F = self.F
+        x, y = point
+        x_den, y_den = F.eval(self.x_den, x), F.eval(self.y_den, x)
+        if x_den == (0, 0) or y_den == (0, 0):
+            return None
+        x_out = F.mul(F.eval(self.x_num, x), F.inv0(x_den))
+        y_out = F.mul(y, F.mul(F.eval(self.y_num, x), F.inv0(y_den)))
+        assert F.mul(y_out, y_out) == F.add(F.mul(F.mul(x_out, x_out), x_out), self.b)
+        return x_out, y_out
+
+    def add(self, p1, p2):
+        F = self.F
+        if p1 is None:
+            return p2
+        if p2 is None:
+            return p1
+        if p1[0] == p2[0]:
+            if F.add(p1[1], p2[1]) == (0, 0):
+                return None
+            lam = F.mul(F.mul((3, 0), F.mul(p1[0], p1[0])), F.inv0(F.mul((2, 0), p1[1])))
+        else:
+            lam = F.mul(F.sub(p2[1], p1[1]), F.inv0(F.sub(p2[0], p1[0])))
+        x = F.sub(F.sub(F.mul(lam, lam), p1[0]), p2[0])
+        return x, F.sub(F.mul(lam, F.sub(p1[0], x)), p1[1])
+
+    def mul(self, point, k):
+        result = None
+        for bit in bin(k)[2:]:
+            result = self.add(result, result)
+            if bit == "1":
+                result = self.add(result, point)
+        return result
+
+    def hash_to_curve(self, msg, dst):
+        u0, u1 = self.hash_to_field(msg, dst, 2)
+        q0 = self.iso_map(self.map_to_curve_simple_swu(u0))
+        q1 = self.iso_map(self.map_to_curve_simple_swu(u1))
+        point = self.mul(self.add(q0, q1), self.h_eff)
+        assert self.mul(point, self.r) is None
+        return point
+
+
+def rust_constants(path, field, name):
+    """Reads a field constant or array from the `SWUParameters`
+    implementation in `path`, converting it out of Montgomery form."""
+    src = open(path).read()
+    body = src[src.index("impl SWUParameters"):]
+    start = body.index(f"const {name}:")
+    end = body.index(";\n", start)
+    p = field.p
+    r_inv = pow(2 ** 384, -1, p)
+    values = []
+    for limbs in re.findall(r"BigInteger384\(\[([^\]]*)\]\)", body[start:end]):
+        limbs = [int(l, 0) for l in limbs.split(",") if l.strip()]
+        values.append(sum(l << (64 * i) for i, l in enumerate(limbs)) * r_inv % p)
+    if field.m == 2:
+        return [(values[i], values[i + 1]) for i in range(0, len(values), 2)]
+    return [(v, 0) for v in values]
+
+
+def rust_suite(path, field, b, h_eff, r):
+    c = lambda name: rust_constants(path, field, name)
+    return Suite(
+        field, b,
+        c("ISOGENOUS_COEFF_A")[0], c("ISOGENOUS_COEFF_B")[0], c("Z")[0],
+        c("ISOGENY_X_NUM"), c("ISOGENY_X_DEN"), c("ISOGENY_Y_NUM"), c("ISOGENY_Y_DEN"),
+        h_eff, r,
+    )
+
+
+def main():
+    # BLS12-381 G2, against the test vectors of the draft.
+    p = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab
+    r = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
+    h_eff = 0xbc69f08f2ee75b3584c6a0ea91b352888e2a8e9145ad7689986ff031508ffe1329c2f178731db956d82bf015d1212b02ec0ec69d7477c1ae954cbc06689f6a359894c0adebbf6b4e8020005aaa95551
+    suite = rust_suite("algebra/src/bls12_381/curves/g2.rs", Field(p, 2, p - 1), (4, 4), h_eff, r)
+    dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_"
+    expected = {
+        b"": (
+            (193548053368451749411421515628510806626565736652086807419354395577367693778571452628423727082668900187036482254730,
+             891930009643099423308102777951250899694559203647724988361022851024990473423938537113948850338098230396747396259901),
+            (771717272055834152378281705972671257005357145478800908373659404991537354153455452961747174765859335819766715637138,
+             2810310118582126634041133454180705304393079139103252956502404531123692847658283858246402311867775854528543237781718),
+        ),
+        b"abc": (
+            (424958340463073975547762735517193206833255107941790909009827635556634414746056077714431786321247871628515967727334,
+             3018679803970127877262826393814472528557413504329194740495363852840690589001358162447917674089074634504498585239512),
+            (3621308185128395459888995526527127556614768604472132176060423302734876099689739385100475320409412954617897892887112,
+             102447784096837908713257069727879782642075240724579670654226801345708452018676587771714457671432122751958633012502),
+        ),
+    }
+    for msg, point in expected.items():
+        assert suite.hash_to_curve(msg, dst) == point, "BLS12-381 G2 test vector mismatch"
+
+    # BLS12-377, whose vectors are printed for the Rust tests.
+    q = 0x01ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000001
+    r = 0x12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001
+    # G1: the 2-isogeny (x, y) -> ((x^2 - 2x - 3) / (4(x - 2)), y(x^2 - 4x + 7) / (8(x - 2)^2))
+    # from y^2 = x^3 - 15x + 22, with Z = -11.
+    inv = lambda v: pow(v, q - 2, q)
+    g1 = Suite(
+        Field(q, 1), (1, 0), (q - 15, 0), (22, 0), (q - 11, 0),
+        [(-3 * inv(4) % q, 0), (-2 * inv(4) % q, 0), (inv(4), 0)],
+        [(q - 2, 0), (1, 0)],
+        [(7 * inv(8) % q, 0), (-4 * inv(8) % q, 0), (inv(8), 0)],
+        [(4, 0), (q - 4, 0), (1, 0)],
+        0x170b5d44300000000000000000000000, r,
+    )
+    h2 = 7923214915284317143930293550643874566881017850177945424769256759165301436616933228209277966774092486467289478618404761412630691835764674559376407658497
+    b2 = (0, 155198655607781456406391640216936120121836107652948796323930557600032281009004493664981332883744016074664192874906)
+    g2 = rust_suite("algebra/src/bls12_377/curves/g2.rs", Field(q, 2, q - 5), b2, h2, r)
+    for name, suite in [("G1", g1), ("G2", g2)]:
+        dst = f"ZEXE-REGRESSION-BLS12377{name}_XMD:SHA-256_SSWU_RO_".encode()
+        print(dst.decode())
+        for msg in [b"", b"abc"]:
+            x, y = suite.hash_to_curve(msg, dst)
+            coords = [x[0], y[0]] if name == "G1" else [x[0], x[1], y[0], y[1]]
+            print(f"  {msg!r}: " + ", ".join(str(c) for c in coords))
+
+
+if __name__ == "__main__":
+    main()